@@ -0,0 +1,35 @@
+use super::Field;
+
+/// A binary narrow-sense BCH code over GF(2^m).
+///
+/// The generator polynomial is the least common multiple of the minimal polynomials of `α, α^2, ..., α^(2t)`,
+/// where `α` is a root of the field polynomial.
+/// Codes with `CODEWORD_BITS < 2^m - 1` are shortened by virtually prepending zero bits to the data.
+pub trait BchCode: Default {
+    /// The field GF(2^m), e.g. `BinaryField<0x1002D, 65536>` for x^16+x^5+x^3+x^2+1.
+    type Field: Field;
+
+    /// The number of bits in a codeword `n`.
+    const CODEWORD_BITS: usize;
+
+    /// The number of data bits `k` in a codeword.
+    /// `n - k` must equal the degree of the generator polynomial.
+    const DATA_BITS: usize;
+
+    /// The number of bit errors `t` that can be corrected.
+    const CORRECTABLE_ERRORS: usize;
+}
+
+pub trait BchCodeExt<C: BchCode> {
+    /// The number of parity bits `n - k`.
+    fn parity_bits() -> usize {
+        C::CODEWORD_BITS - C::DATA_BITS
+    }
+
+    /// The number of virtual zero bits that are prepended to shortened codewords.
+    fn shortening() -> usize {
+        C::Field::ORDER - 1 - C::CODEWORD_BITS
+    }
+}
+
+impl<C: BchCode> BchCodeExt<C> for C {}
@@ -0,0 +1,234 @@
+use core::marker::PhantomData;
+
+use bitvec::prelude::*;
+use heapless::Vec;
+
+use super::{
+    field::{Arithmetic, MAX_ERRORS},
+    BchCode,
+};
+
+/// BCH hard decision decoder.
+///
+/// The decoder computes the syndromes, finds the error locator polynomial with the Berlekamp-Massey algorithm
+/// and locates the errors with a Chien search.
+/// A codeword with at most `CORRECTABLE_ERRORS` bit errors is corrected.
+pub struct BchDecoder<C: BchCode> {
+    _code: PhantomData<C>,
+}
+
+impl<C: BchCode> BchDecoder<C> {
+    pub fn new() -> Self {
+        Self { _code: PhantomData }
+    }
+
+    /// Correct a codeword in place.
+    ///
+    /// Returns the number of corrected bits,
+    /// or `None` if the codeword is uncorrectable in which case it is left unchanged.
+    pub fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize> {
+        assert_eq!(C::CODEWORD_BITS, codeword.len());
+        let t = C::CORRECTABLE_ERRORS;
+        let nn = Arithmetic::<C>::NN;
+        let last = C::CODEWORD_BITS - 1;
+
+        // Evaluate the received polynomial at α^j for the odd j,
+        // where the first bit is the coefficient of the highest degree
+        let mut syndromes = [0u16; 2 * MAX_ERRORS];
+        let syndromes = &mut syndromes[..2 * t];
+        for position in codeword.iter_ones() {
+            let degree = last - position;
+            for j in (1..2 * t).step_by(2) {
+                syndromes[j - 1] ^= Arithmetic::<C>::exp((j * degree) % nn);
+            }
+        }
+        if syndromes.iter().all(|syndrome| *syndrome == 0) {
+            return Some(0);
+        }
+
+        // The syndromes of the even powers are the squares S_2j = S_j^2 for a binary code
+        for j in (2..=2 * t).step_by(2) {
+            syndromes[j - 1] = Arithmetic::<C>::mul(syndromes[j / 2 - 1], syndromes[j / 2 - 1]);
+        }
+
+        // Berlekamp-Massey, where `lambda` is the locator and `b` is the correction polynomial
+        let mut lambda = [0u16; 2 * MAX_ERRORS + 1];
+        let mut b = [0u16; 2 * MAX_ERRORS + 1];
+        lambda[0] = 1;
+        b[0] = 1;
+        let mut degree = 0;
+        let mut shift = 1;
+        let mut previous = 1;
+        for r in 0..2 * t {
+            let mut discrepancy = syndromes[r];
+            for i in 1..=degree {
+                discrepancy ^= Arithmetic::<C>::mul(lambda[i], syndromes[r - i]);
+            }
+
+            if discrepancy == 0 {
+                shift += 1;
+                continue;
+            }
+
+            // Λ(x) = Λ(x) - Δ/Δ' x^shift B(x)
+            let scale = Arithmetic::<C>::exp(
+                Arithmetic::<C>::log(discrepancy) + nn - Arithmetic::<C>::log(previous),
+            );
+            let current = lambda;
+            for i in shift..=2 * t {
+                lambda[i] ^= Arithmetic::<C>::mul(scale, b[i - shift]);
+            }
+
+            if 2 * degree <= r {
+                degree = r + 1 - degree;
+                b = current;
+                previous = discrepancy;
+                shift = 1;
+            } else {
+                shift += 1;
+            }
+        }
+        if degree > t {
+            return None;
+        }
+
+        // Chien search for the roots α^-d of the locator, where d is the degree of an error
+        let mut register = [0; MAX_ERRORS + 1];
+        for (register, lambda) in register.iter_mut().zip(&lambda[..=degree]) {
+            *register = Arithmetic::<C>::log(*lambda);
+        }
+        let mut positions = Vec::<usize, MAX_ERRORS>::new();
+        for error_degree in 0..C::CODEWORD_BITS {
+            let mut sum = 0;
+            for (j, register) in register[..=degree].iter_mut().enumerate() {
+                if *register != nn {
+                    sum ^= Arithmetic::<C>::exp(*register);
+                    *register = (*register + nn - j) % nn;
+                }
+            }
+            if sum == 0 {
+                positions.push(last - error_degree).unwrap();
+                if positions.len() == degree {
+                    break;
+                }
+            }
+        }
+
+        // The locator must have distinct roots within the (shortened) codeword
+        if positions.len() != degree {
+            return None;
+        }
+
+        for position in positions.iter() {
+            let bit = codeword[*position];
+            codeword.set(*position, !bit);
+        }
+
+        Some(degree)
+    }
+}
+
+impl<C: BchCode> Default for BchDecoder<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        bch::{field::tests::Bch15_5, BchCodeExt, BchEncoder},
+        catalog,
+    };
+
+    use super::*;
+
+    fn encode<C: BchCode>() -> std::vec::Vec<u8> {
+        let mut codeword = vec![0; C::CODEWORD_BITS.div_ceil(8)];
+        let bits = codeword.view_bits_mut::<Msb0>();
+        for index in 0..C::DATA_BITS {
+            bits.set(index, (index * 37 + index / 5) % 7 < 3);
+        }
+        let mut parity = [0u8; 32];
+        let parity = &mut parity.view_bits_mut()[..C::parity_bits()];
+        BchEncoder::<C>::new().encode(&bits[..C::DATA_BITS], parity);
+        bits[C::DATA_BITS..C::CODEWORD_BITS].copy_from_bitslice(parity);
+        codeword
+    }
+
+    #[test]
+    fn can_decode_without_errors() {
+        // Given
+        let codeword = encode::<catalog::DVB_S2_BCH_SHORT_1_2>();
+        let mut received = codeword.clone();
+        let decoder = BchDecoder::<catalog::DVB_S2_BCH_SHORT_1_2>::new();
+
+        // When
+        let corrected = decoder.decode(&mut received.view_bits_mut()[..7200]);
+
+        // Then
+        assert_eq!(Some(0), corrected);
+        assert_eq!(codeword, received);
+    }
+
+    #[test]
+    fn can_correct_errors() {
+        // Given
+        let codeword = encode::<Bch15_5>();
+        let decoder = BchDecoder::<Bch15_5>::new();
+
+        for first in 0..15 {
+            for second in first + 1..15 {
+                let mut received = codeword.clone();
+                for position in [first, second, (second + 4) % 15] {
+                    received[position / 8] ^= 0x80 >> (position % 8);
+                }
+
+                // When
+                let corrected = decoder.decode(&mut received.view_bits_mut()[..15]);
+
+                // Then
+                assert!(corrected.is_some());
+                assert_eq!(codeword, received);
+            }
+        }
+    }
+
+    #[test]
+    fn can_correct_shortened_errors() {
+        // Given
+        let codeword = encode::<catalog::DVB_S2_BCH_NORMAL_8_9>();
+        let mut received = codeword.clone();
+        for position in [0, 1, 1000, 20000, 40000, 57471, 57472, 57599] {
+            received[position / 8] ^= 0x80 >> (position % 8);
+        }
+        let decoder = BchDecoder::<catalog::DVB_S2_BCH_NORMAL_8_9>::new();
+
+        // When
+        let corrected = decoder.decode(&mut received.view_bits_mut()[..57600]);
+
+        // Then
+        assert_eq!(Some(8), corrected);
+        assert_eq!(codeword, received);
+        assert_eq!(65535 - 57600, catalog::DVB_S2_BCH_NORMAL_8_9::shortening());
+    }
+
+    #[test]
+    fn can_detect_uncorrectable() {
+        // Given
+        let codeword = encode::<catalog::DVB_S2_BCH_SHORT_1_2>();
+        let mut received = codeword.clone();
+        for position in (0..7200).step_by(500) {
+            received[position / 8] ^= 0x80 >> (position % 8);
+        }
+        let corrupted = received.clone();
+        let decoder = BchDecoder::<catalog::DVB_S2_BCH_SHORT_1_2>::new();
+
+        // When
+        let corrected = decoder.decode(&mut received.view_bits_mut()[..7200]);
+
+        // Then
+        assert_eq!(None, corrected);
+        assert_eq!(corrupted, received);
+    }
+}
@@ -0,0 +1,85 @@
+use core::marker::PhantomData;
+
+use bitvec::prelude::*;
+
+use crate::BitView;
+
+use super::{
+    field::{Arithmetic, GENERATOR_WORDS},
+    BchCode, BchCodeExt,
+};
+
+/// Systematic BCH encoder.
+///
+/// The codeword is the data bits followed by the parity bits,
+/// where the first data bit is the coefficient of the highest degree.
+pub struct BchEncoder<C: BchCode> {
+    _code: PhantomData<C>,
+}
+
+impl<C: BchCode> BchEncoder<C> {
+    pub fn new() -> Self {
+        Self { _code: PhantomData }
+    }
+
+    /// Compute the `n - k` parity bits for the `k` data bits.
+    pub fn encode<S: BitView>(&self, data: S, parity: &mut BitSlice<u8, Msb0>) {
+        assert_eq!(C::DATA_BITS, data.len2());
+        assert_eq!(C::parity_bits(), parity.len());
+        let generator = Arithmetic::<C>::GENERATOR;
+        let parity_bits = C::parity_bits();
+        let top = parity_bits - 1;
+
+        // Divide the data polynomial by the generator polynomial using a feedback shift register,
+        // where bit `i` of the register is the coefficient of x^i of the remainder
+        let mut register = [0u64; GENERATOR_WORDS];
+        for index in 0..C::DATA_BITS {
+            let feedback = data.get(index) ^ (register[top / 64] & (1 << (top % 64)) != 0);
+            for word in (1..GENERATOR_WORDS).rev() {
+                register[word] = (register[word] << 1) | (register[word - 1] >> 63);
+            }
+            register[0] <<= 1;
+            if parity_bits < 64 * GENERATOR_WORDS {
+                register[parity_bits / 64] &= !(1 << (parity_bits % 64));
+            }
+            if feedback {
+                for (register, generator) in register.iter_mut().zip(generator) {
+                    *register ^= generator;
+                }
+            }
+        }
+
+        for (index, mut bit) in parity.iter_mut().enumerate() {
+            let degree = top - index;
+            *bit = register[degree / 64] & (1 << (degree % 64)) != 0;
+        }
+    }
+}
+
+impl<C: BchCode> Default for BchEncoder<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bch::field::tests::Bch15_7;
+
+    use super::*;
+
+    #[test]
+    fn can_encode() {
+        // Given
+        let encoder = BchEncoder::<Bch15_7>::new();
+        let data = bits![u8, Msb0; 0, 0, 0, 0, 0, 0, 1];
+        let mut parity = bitarr![u8, Msb0; 0; 8];
+
+        // When
+        encoder.encode(data, &mut parity[..8]);
+
+        // Then
+        // The codeword of the data polynomial 1 is the generator polynomial x^8+x^7+x^6+x^4+1
+        assert_eq!(bits![u8, Msb0; 1, 1, 0, 1, 0, 0, 0, 1], parity[..8]);
+    }
+}
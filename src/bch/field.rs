@@ -0,0 +1,248 @@
+use core::marker::PhantomData;
+
+use super::BchCode;
+
+/// The maximum degree `m` of the field polynomial.
+const MAX_DEGREE: usize = 16;
+
+/// The maximum number of correctable errors `t`.
+pub(crate) const MAX_ERRORS: usize = 16;
+
+/// The number of words in the generator polynomial excluding its leading term.
+pub(crate) const GENERATOR_WORDS: usize = (MAX_DEGREE * MAX_ERRORS).div_ceil(64);
+
+/// The finite field GF(2^m) with its tables computed at compile time.
+pub trait Field {
+    /// The primitive polynomial that defines the field, including the x^m term.
+    const POLYNOMIAL: usize;
+
+    /// The number of elements `2^m`.
+    const ORDER: usize;
+
+    /// The polynomial form `α^i` for each exponent `i < 2^m - 1`.
+    const EXP: &'static [u16];
+
+    /// The logarithm for each element, where the logarithm of zero is `2^m - 1`.
+    const LOG: &'static [u16];
+}
+
+/// The field defined by the primitive polynomial `POLYNOMIAL` with `ORDER = 2^m` elements.
+///
+/// The tables are shared between all codes with the same field.
+pub struct BinaryField<const POLYNOMIAL: usize, const ORDER: usize>;
+
+impl<const POLYNOMIAL: usize, const ORDER: usize> BinaryField<POLYNOMIAL, ORDER> {
+    const TABLES: ([u16; ORDER], [u16; ORDER]) = Self::create();
+
+    const fn create() -> ([u16; ORDER], [u16; ORDER]) {
+        assert!(ORDER.is_power_of_two() && ORDER >= 4 && ORDER <= 1 << MAX_DEGREE);
+        assert!(POLYNOMIAL >= ORDER && POLYNOMIAL < 2 * ORDER);
+        let nn = ORDER - 1;
+        let mut exp = [0; ORDER];
+        let mut log = [nn as u16; ORDER];
+
+        let mut element = 1;
+        let mut i = 0;
+        while i < nn {
+            // The polynomial must be primitive, so α generates all non-zero elements
+            assert!(
+                log[element] == nn as u16,
+                "The field polynomial is not primitive"
+            );
+            exp[i] = element as u16;
+            log[element] = i as u16;
+            element <<= 1;
+            if element & ORDER != 0 {
+                element ^= POLYNOMIAL;
+            }
+            i += 1;
+        }
+        exp[nn] = exp[0];
+
+        (exp, log)
+    }
+}
+
+impl<const POLYNOMIAL: usize, const ORDER: usize> Field for BinaryField<POLYNOMIAL, ORDER> {
+    const POLYNOMIAL: usize = POLYNOMIAL;
+    const ORDER: usize = ORDER;
+    const EXP: &'static [u16] = &Self::TABLES.0;
+    const LOG: &'static [u16] = &Self::TABLES.1;
+}
+
+/// The field arithmetic and the generator polynomial for a code.
+pub(crate) struct Arithmetic<C: BchCode> {
+    _code: PhantomData<C>,
+}
+
+impl<C: BchCode> Arithmetic<C> {
+    /// The number of non-zero field elements `2^m - 1`, which is also the logarithm of zero.
+    pub(crate) const NN: usize = C::Field::ORDER - 1;
+
+    /// The generator polynomial coefficients excluding the leading term, where bit `i` is the coefficient of x^i.
+    pub(crate) const GENERATOR: [u64; GENERATOR_WORDS] = Self::create_generator();
+
+    const fn create_generator() -> [u64; GENERATOR_WORDS] {
+        let nn = Self::NN;
+        let t = C::CORRECTABLE_ERRORS;
+        assert!(t > 0 && t <= MAX_ERRORS);
+        assert!(C::DATA_BITS > 0 && C::DATA_BITS < C::CODEWORD_BITS);
+        assert!(C::CODEWORD_BITS <= nn);
+
+        // Coefficients of the binary generator polynomial, lowest degree first
+        let mut generator = [false; MAX_DEGREE * MAX_ERRORS + 1];
+        generator[0] = true;
+        let mut degree = 0;
+
+        // Multiply by the minimal polynomial of each cyclotomic coset containing an odd power α^i, i < 2t
+        let mut i = 1;
+        while i < 2 * t {
+            // The coset is already included if it has a smaller representative
+            let mut representative = i;
+            let mut conjugate = (2 * i) % nn;
+            while conjugate != i {
+                if conjugate < representative {
+                    representative = conjugate;
+                }
+                conjugate = (2 * conjugate) % nn;
+            }
+
+            if representative == i {
+                // The minimal polynomial is the product of (x + α^j) for the conjugates j, in polynomial form
+                let mut minimal = [0u16; MAX_DEGREE + 1];
+                minimal[0] = 1;
+                let mut minimal_degree = 0;
+                let mut conjugate = i;
+                loop {
+                    let root = conjugate;
+                    minimal_degree += 1;
+                    let mut j = minimal_degree;
+                    while j > 0 {
+                        minimal[j] = minimal[j - 1] ^ Self::mul_exp(minimal[j], root);
+                        j -= 1;
+                    }
+                    minimal[0] = Self::mul_exp(minimal[0], root);
+
+                    conjugate = (2 * conjugate) % nn;
+                    if conjugate == i {
+                        break;
+                    }
+                }
+
+                // The coefficients of the minimal polynomial are binary
+                let mut product = [false; MAX_DEGREE * MAX_ERRORS + 1];
+                let mut j = 0;
+                while j <= minimal_degree {
+                    assert!(minimal[j] <= 1);
+                    if minimal[j] == 1 {
+                        let mut k = 0;
+                        while k <= degree {
+                            product[j + k] ^= generator[k];
+                            k += 1;
+                        }
+                    }
+                    j += 1;
+                }
+                generator = product;
+                degree += minimal_degree;
+            }
+            i += 2;
+        }
+
+        assert!(
+            degree == C::CODEWORD_BITS - C::DATA_BITS,
+            "The number of parity bits must equal the generator polynomial degree"
+        );
+
+        let mut words = [0; GENERATOR_WORDS];
+        let mut i = 0;
+        while i < degree {
+            if generator[i] {
+                words[i / 64] |= 1 << (i % 64);
+            }
+            i += 1;
+        }
+        words
+    }
+
+    /// Multiply `x` in polynomial form by `α^i`.
+    const fn mul_exp(x: u16, i: usize) -> u16 {
+        if x == 0 {
+            0
+        } else {
+            C::Field::EXP[(C::Field::LOG[x as usize] as usize + i) % Self::NN]
+        }
+    }
+
+    /// Get `α^i`.
+    pub(crate) fn exp(i: usize) -> u16 {
+        C::Field::EXP[i % Self::NN]
+    }
+
+    /// Get the logarithm of `x`, or `2^m - 1` if `x` is zero.
+    pub(crate) fn log(x: u16) -> usize {
+        C::Field::LOG[x as usize] as usize
+    }
+
+    /// Multiply two elements in polynomial form.
+    pub(crate) fn mul(x: u16, y: u16) -> u16 {
+        if x == 0 || y == 0 {
+            0
+        } else {
+            Self::exp(Self::log(x) + Self::log(y))
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// The (15,7) double error correcting code.
+    #[derive(Default)]
+    pub(crate) struct Bch15_7;
+
+    impl BchCode for Bch15_7 {
+        type Field = BinaryField<0x13, 16>;
+        const CODEWORD_BITS: usize = 15;
+        const DATA_BITS: usize = 7;
+        const CORRECTABLE_ERRORS: usize = 2;
+    }
+
+    /// The (15,5) triple error correcting code.
+    #[derive(Default)]
+    pub(crate) struct Bch15_5;
+
+    impl BchCode for Bch15_5 {
+        type Field = BinaryField<0x13, 16>;
+        const CODEWORD_BITS: usize = 15;
+        const DATA_BITS: usize = 5;
+        const CORRECTABLE_ERRORS: usize = 3;
+    }
+
+    #[test]
+    fn can_create_tables() {
+        // Given
+        type F = BinaryField<0x13, 16>;
+
+        // When
+        let exp = F::EXP;
+        let log = F::LOG;
+
+        // Then
+        // α^4 = α + 1 for x^4+x+1
+        assert_eq!([1, 2, 4, 8, 3, 6, 12, 11], exp[..8]);
+        for x in 1..16u16 {
+            assert_eq!(x, exp[log[x as usize] as usize]);
+        }
+        assert_eq!(15, log[0]);
+    }
+
+    #[test]
+    fn can_create_generator() {
+        // g(x) = x^8+x^7+x^6+x^4+1
+        assert_eq!(0b1101_0001, Arithmetic::<Bch15_7>::GENERATOR[0]);
+        // g(x) = x^10+x^8+x^5+x^4+x^2+x+1
+        assert_eq!(0b01_0011_0111, Arithmetic::<Bch15_5>::GENERATOR[0]);
+    }
+}
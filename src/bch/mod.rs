@@ -0,0 +1,9 @@
+mod code;
+mod decoder;
+mod encoder;
+mod field;
+
+pub use code::{BchCode, BchCodeExt};
+pub use decoder::BchDecoder;
+pub use encoder::BchEncoder;
+pub use field::{BinaryField, Field};
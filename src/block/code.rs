@@ -0,0 +1,21 @@
+use bitvec::prelude::*;
+
+use crate::BitView;
+
+/// A systematic binary block code with a hard decision decoder, e.g. a component code of a product code.
+pub trait BlockCode {
+    /// Get the number of bits `n` in a codeword.
+    fn codeword_bits(&self) -> usize;
+
+    /// Get the number of data bits `k`.
+    fn data_bits(&self) -> usize;
+
+    /// Encode the `k` data bits into the `n` codeword bits, where the data bits are first.
+    fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>);
+
+    /// Correct a codeword in place.
+    ///
+    /// Returns the number of corrected bits,
+    /// or `None` if the codeword is uncorrectable in which case it is left unchanged.
+    fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize>;
+}
@@ -0,0 +1,230 @@
+use bitvec::prelude::*;
+
+use crate::{BitView, Llr};
+
+use super::{BlockCode, LinearBlockCode};
+
+/// The generator polynomial x^11+x^10+x^6+x^5+x^4+x^2+1 of the cyclic (23,12) Golay code.
+const GENERATOR: u32 = 0xC75;
+
+/// The number of bits of the cyclic (23,12) Golay code.
+const CYCLIC_BITS: usize = 23;
+
+/// The extended (24,12) Golay code.
+///
+/// The codeword is the 12 data bits followed by the 11 parity bits of the systematic cyclic (23,12) code
+/// and the overall parity bit. The code has minimum distance 8, so it corrects three errors and detects four.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Golay;
+
+impl Golay {
+    /// The number of bits `n` in a codeword.
+    pub const CODEWORD_BITS: usize = 24;
+
+    /// The number of data bits `k`.
+    pub const DATA_BITS: usize = 12;
+
+    /// The equivalent linear block code with the codewords of the unit data vectors as basis.
+    pub const LINEAR: LinearBlockCode = LinearBlockCode::new(24, &Self::create_basis());
+
+    /// The error pattern of weight at most three for each syndrome of the perfect cyclic (23,12) code.
+    const ERRORS: [u32; 2048] = Self::create_errors();
+
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Encode the 12 data bits into the 24 codeword bits.
+    pub fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        Self::LINEAR.encode(data, codeword)
+    }
+
+    /// Correct up to three errors in place using the syndrome.
+    ///
+    /// Returns the number of corrected bits,
+    /// or `None` if four errors are detected in which case the codeword is left unchanged.
+    pub fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize> {
+        assert_eq!(Self::CODEWORD_BITS, codeword.len());
+        let received = codeword.load_be::<u32>();
+
+        // Correct the cyclic code, and count the overall parity bit as an error if it does not match
+        let cyclic = received >> 1;
+        let errors = Self::ERRORS[Self::syndrome(cyclic) as usize];
+        let corrected = cyclic ^ errors;
+        let parity = corrected.count_ones() % 2;
+        let corrected = (corrected << 1) | parity;
+
+        let distance = (received ^ corrected).count_ones() as usize;
+        if distance > 3 {
+            return None;
+        }
+
+        codeword.store_be(corrected);
+        Some(distance)
+    }
+
+    /// Decode the llrs of the 24 codeword bits into the 12 data bits by correlating with all codewords.
+    ///
+    /// Returns the correlation between the llrs and the decided codeword.
+    pub fn decode_llrs(&self, llrs: &[Llr], data: &mut BitSlice<u8, Msb0>) -> i32 {
+        Self::LINEAR.decode_llrs(llrs, data)
+    }
+
+    /// Get the remainder of a 23 bit word divided by the generator polynomial.
+    const fn syndrome(word: u32) -> u32 {
+        let mut remainder = word;
+        let mut degree = CYCLIC_BITS - 1;
+        while degree >= 11 {
+            if remainder & (1 << degree) != 0 {
+                remainder ^= GENERATOR << (degree - 11);
+            }
+            degree -= 1;
+        }
+        remainder
+    }
+
+    const fn create_basis() -> [u32; 12] {
+        let mut basis = [0; 12];
+        let mut index = 0;
+        while index < 12 {
+            let data = 1 << (11 - index);
+            let cyclic = (data << 11) | Self::syndrome(data << 11);
+            basis[index] = (cyclic << 1) | (cyclic.count_ones() % 2);
+            index += 1;
+        }
+        basis
+    }
+
+    const fn create_errors() -> [u32; 2048] {
+        let mut errors = [u32::MAX; 2048];
+        errors[0] = 0;
+
+        // The code is perfect, so each syndrome has exactly one error pattern of weight at most three
+        let mut first = 0;
+        while first < CYCLIC_BITS {
+            let mut second = first;
+            while second < CYCLIC_BITS {
+                let mut third = second;
+                while third < CYCLIC_BITS {
+                    let pattern = (1 << first) | (1 << second) | (1 << third);
+                    let syndrome = Self::syndrome(pattern) as usize;
+                    assert!(errors[syndrome] == u32::MAX || errors[syndrome] == pattern);
+                    errors[syndrome] = pattern;
+                    third += 1;
+                }
+                second += 1;
+            }
+            first += 1;
+        }
+        errors
+    }
+}
+
+impl BlockCode for Golay {
+    fn codeword_bits(&self) -> usize {
+        Self::CODEWORD_BITS
+    }
+
+    fn data_bits(&self) -> usize {
+        Self::DATA_BITS
+    }
+
+    fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        Golay::encode(self, data, codeword)
+    }
+
+    fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize> {
+        Golay::decode(self, codeword)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LlrMul;
+
+    use super::*;
+
+    fn source() -> &'static BitSlice<u8, Msb0> {
+        bits![static u8, Msb0; 1, 0, 0, 1, 1, 1, 0, 1, 0, 0, 0, 1]
+    }
+
+    #[test]
+    fn has_minimum_distance_eight() {
+        // Given
+        let code = Golay::LINEAR;
+        let mut data = bitarr![u8, Msb0; 0; 12];
+
+        for message in 1..4096u16 {
+            data[..12].store_be(message);
+
+            // When
+            let codeword = code.codeword(&data[..12]);
+
+            // Then
+            assert!(codeword.count_ones() >= 8);
+        }
+    }
+
+    #[test]
+    fn can_correct_three_errors() {
+        // Given
+        let golay = Golay::new();
+        let mut codeword = bitarr![u8, Msb0; 0; 24];
+        golay.encode(source(), &mut codeword[..24]);
+
+        for positions in [[0, 1, 2], [3, 12, 23], [7, 15, 22], [11, 12, 13]] {
+            let mut received = codeword;
+            for position in positions {
+                let bit = received[position];
+                received.set(position, !bit);
+            }
+
+            // When
+            let corrected = golay.decode(&mut received[..24]);
+
+            // Then
+            assert!(corrected.is_some());
+            assert_eq!(codeword, received);
+        }
+    }
+
+    #[test]
+    fn can_detect_four_errors() {
+        // Given
+        let golay = Golay::new();
+        let mut codeword = bitarr![u8, Msb0; 0; 24];
+        golay.encode(source(), &mut codeword[..24]);
+        for position in [2, 9, 17, 23] {
+            let bit = codeword[position];
+            codeword.set(position, !bit);
+        }
+        let received = codeword;
+
+        // When
+        let corrected = golay.decode(&mut codeword[..24]);
+
+        // Then
+        assert_eq!(None, corrected);
+        assert_eq!(received, codeword);
+    }
+
+    #[test]
+    fn can_decode_llrs() {
+        // Given
+        let golay = Golay::new();
+        let mut codeword = bitarr![u8, Msb0; 0; 24];
+        golay.encode(source(), &mut codeword[..24]);
+        let mut llrs: Vec<Llr> = codeword[..24].iter().map(|bit| bit.mul(10)).collect();
+        // Five weak errors exceed the hard decision capability
+        for position in [0, 4, 8, 15, 20] {
+            llrs[position] = -llrs[position] / 5;
+        }
+        let mut data = bitarr![u8, Msb0; 0; 12];
+
+        // When
+        golay.decode_llrs(&llrs, &mut data[..12]);
+
+        // Then
+        assert_eq!(source(), data[..12]);
+    }
+}
@@ -0,0 +1,253 @@
+use bitvec::prelude::*;
+
+use crate::{BitView, Llr};
+
+use super::{BlockCode, LinearBlockCode};
+
+/// A systematic Hamming code with `r` parity bits, optionally extended with an overall parity bit.
+///
+/// The codeword is the `2^r - r - 1` data bits followed by the `r` parity bits and the overall parity bit if extended.
+/// The parity check column of the parity bit `i` is `2^(r - 1 - i)`, and the columns of the data bits are the remaining non-zero `r` bit numbers in increasing order.
+/// The code corrects a single error, and the extended code also detects double errors.
+#[derive(Clone, Copy, Debug)]
+pub struct Hamming {
+    parity_bits: usize,
+    extended: bool,
+}
+
+impl Hamming {
+    /// Create a new Hamming code
+    ///
+    /// # Arguments
+    ///
+    /// * `parity_bits` - The number of parity bits `r` between 2 and 6, excluding the overall parity bit.
+    /// * `extended` - Whether the overall parity bit is appended.
+    pub const fn new(parity_bits: usize, extended: bool) -> Self {
+        assert!(parity_bits >= 2 && parity_bits <= 6);
+        Self {
+            parity_bits,
+            extended,
+        }
+    }
+
+    /// Get the number of bits `n` in a codeword.
+    pub const fn codeword_bits(&self) -> usize {
+        (1 << self.parity_bits) - 1 + self.extended as usize
+    }
+
+    /// Get the number of data bits `k`.
+    pub const fn data_bits(&self) -> usize {
+        (1 << self.parity_bits) - 1 - self.parity_bits
+    }
+
+    /// Encode the `k` data bits into the `n` codeword bits.
+    pub fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        let k = self.data_bits();
+        assert_eq!(k, data.len2());
+        assert_eq!(self.codeword_bits(), codeword.len());
+
+        let mut syndrome = 0;
+        for index in 0..k {
+            let bit = data.get(index);
+            codeword.set(index, bit);
+            if bit {
+                syndrome ^= Self::data_column(index);
+            }
+        }
+        for index in 0..self.parity_bits {
+            codeword.set(k + index, syndrome & self.parity_column(index) != 0);
+        }
+        if self.extended {
+            let parity = codeword[..k + self.parity_bits].count_ones() % 2 == 1;
+            codeword.set(k + self.parity_bits, parity);
+        }
+    }
+
+    /// Correct a single error in place using the syndrome.
+    ///
+    /// Returns the number of corrected bits,
+    /// or `None` if a double error is detected by the extended code in which case the codeword is left unchanged.
+    pub fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize> {
+        let k = self.data_bits();
+        let n = self.codeword_bits();
+        assert_eq!(n, codeword.len());
+
+        let mut syndrome = 0;
+        for index in codeword[..k + self.parity_bits].iter_ones() {
+            syndrome ^= if index < k {
+                Self::data_column(index)
+            } else {
+                self.parity_column(index - k)
+            };
+        }
+        let odd = codeword.count_ones() % 2 == 1;
+
+        let position = if syndrome == 0 {
+            if !(self.extended && odd) {
+                return Some(0);
+            }
+            // The overall parity bit is in error
+            n - 1
+        } else if self.extended && !odd {
+            // A double error does not change the overall parity
+            return None;
+        } else {
+            self.position(syndrome)
+        };
+
+        let bit = codeword[position];
+        codeword.set(position, !bit);
+        Some(1)
+    }
+
+    /// Decode the llrs of the `n` codeword bits into the `k` data bits by correlating with all codewords.
+    /// The number of data bits must be at most [`super::MAX_DATA_BITS`], i.e. `r` must be at most 4.
+    ///
+    /// Returns the correlation between the llrs and the decided codeword.
+    pub fn decode_llrs(&self, llrs: &[Llr], data: &mut BitSlice<u8, Msb0>) -> i32 {
+        self.linear().decode_llrs(llrs, data)
+    }
+
+    /// Get the equivalent linear block code with the codewords of the unit data vectors as basis.
+    pub fn linear(&self) -> LinearBlockCode {
+        let k = self.data_bits();
+        let n = self.codeword_bits();
+        let mut basis = [0; super::MAX_DATA_BITS];
+        assert!(k <= basis.len());
+        for (index, basis) in basis[..k].iter_mut().enumerate() {
+            let mut data = bitarr![u8, Msb0; 0; 26];
+            data.set(index, true);
+            let mut codeword = [0u8; 4];
+            self.encode(&data[..k], &mut codeword.view_bits_mut()[..n]);
+            *basis = u32::from_be_bytes(codeword) >> (32 - n);
+        }
+        LinearBlockCode::new(n, &basis[..k])
+    }
+
+    /// Get the parity check column of data bit `index`, i.e. the `index`'th number that is not a power of two.
+    fn data_column(index: usize) -> usize {
+        (3..)
+            .filter(|column: &usize| !column.is_power_of_two())
+            .nth(index)
+            .unwrap()
+    }
+
+    fn parity_column(&self, index: usize) -> usize {
+        1 << (self.parity_bits - 1 - index)
+    }
+
+    /// Get the codeword position of the bit with a parity check column.
+    fn position(&self, syndrome: usize) -> usize {
+        let log2 = syndrome.ilog2() as usize;
+        if syndrome.is_power_of_two() {
+            self.data_bits() + self.parity_bits - 1 - log2
+        } else {
+            syndrome - log2 - 2
+        }
+    }
+}
+
+impl BlockCode for Hamming {
+    fn codeword_bits(&self) -> usize {
+        Hamming::codeword_bits(self)
+    }
+
+    fn data_bits(&self) -> usize {
+        Hamming::data_bits(self)
+    }
+
+    fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        Hamming::encode(self, data, codeword)
+    }
+
+    fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize> {
+        Hamming::decode(self, codeword)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LlrMul;
+
+    use super::*;
+
+    #[test]
+    fn can_encode() {
+        // Given
+        let code = Hamming::new(3, false);
+        let mut codeword = bitarr![u8, Msb0; 0; 7];
+
+        // When
+        code.encode(bits![u8, Msb0; 1, 0, 1, 1], &mut codeword[..7]);
+
+        // Then
+        // The syndrome of the data is 3 ^ 6 ^ 7 = 2
+        assert_eq!(bits![u8, Msb0; 1, 0, 1, 1, 0, 1, 0], codeword[..7]);
+    }
+
+    #[test]
+    fn can_correct_single_error() {
+        for extended in [false, true] {
+            // Given
+            let code = Hamming::new(4, extended);
+            let n = code.codeword_bits();
+            let mut codeword = bitarr![u8, Msb0; 0; 16];
+            code.encode(
+                bits![u8, Msb0; 1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 1],
+                &mut codeword[..n],
+            );
+
+            for position in 0..n {
+                let mut received = codeword;
+                let bit = received[position];
+                received.set(position, !bit);
+
+                // When
+                let corrected = code.decode(&mut received[..n]);
+
+                // Then
+                assert_eq!(Some(1), corrected);
+                assert_eq!(codeword, received);
+            }
+        }
+    }
+
+    #[test]
+    fn can_detect_double_error() {
+        // Given
+        let code = Hamming::new(3, true);
+        let mut codeword = bitarr![u8, Msb0; 0; 8];
+        code.encode(bits![u8, Msb0; 0, 1, 1, 0], &mut codeword[..8]);
+        for position in [1, 6] {
+            let bit = codeword[position];
+            codeword.set(position, !bit);
+        }
+        let received = codeword;
+
+        // When
+        let corrected = code.decode(&mut codeword[..8]);
+
+        // Then
+        assert_eq!(None, corrected);
+        assert_eq!(received, codeword);
+    }
+
+    #[test]
+    fn can_decode_llrs() {
+        // Given
+        let code = Hamming::new(3, true);
+        let mut codeword = bitarr![u8, Msb0; 0; 8];
+        code.encode(bits![u8, Msb0; 1, 0, 0, 1], &mut codeword[..8]);
+        let mut llrs: Vec<Llr> = codeword[..8].iter().map(|bit| bit.mul(8)).collect();
+        // Two weak errors are corrected by the soft decoder
+        llrs[0] = -2;
+        llrs[5] = -llrs[5] / 4;
+        let mut data = bitarr![u8, Msb0; 0; 4];
+
+        // When
+        code.decode_llrs(&llrs, &mut data[..4]);
+
+        // Then
+        assert_eq!(bits![u8, Msb0; 1, 0, 0, 1], data[..4]);
+    }
+}
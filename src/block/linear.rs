@@ -0,0 +1,177 @@
+use bitvec::prelude::*;
+
+use crate::{BitView, Llr, LlrMul};
+
+/// The maximum number of data bits of a [`LinearBlockCode`].
+pub const MAX_DATA_BITS: usize = 16;
+
+/// A short binary linear block code defined by its basis sequences, with a soft maximum likelihood decoder.
+///
+/// The codeword is the sum of the basis sequences for which the data bit is set,
+/// e.g. the 3GPP (32,O) and (20,O) Reed-Muller codes for UCI with the basis sequences from TS 36.212 §5.2.
+/// Each basis sequence is an `n` bit number where the msb is the first codeword bit.
+#[derive(Clone, Copy, Debug)]
+pub struct LinearBlockCode {
+    codeword_bits: usize,
+    data_bits: usize,
+    basis: [u32; MAX_DATA_BITS],
+}
+
+impl LinearBlockCode {
+    /// Create a new linear block code
+    ///
+    /// # Arguments
+    ///
+    /// * `codeword_bits` - The number of bits `n` in a codeword, at most 32.
+    /// * `basis` - The basis sequence for each data bit, where the first basis sequence belongs to the first data bit.
+    pub const fn new(codeword_bits: usize, basis: &[u32]) -> Self {
+        assert!(codeword_bits > 0 && codeword_bits <= 32);
+        assert!(!basis.is_empty() && basis.len() <= MAX_DATA_BITS);
+        let mut copy = [0; MAX_DATA_BITS];
+        let mut i = 0;
+        while i < basis.len() {
+            assert!(codeword_bits == 32 || basis[i] >> codeword_bits == 0);
+            copy[i] = basis[i];
+            i += 1;
+        }
+        Self {
+            codeword_bits,
+            data_bits: basis.len(),
+            basis: copy,
+        }
+    }
+
+    /// Get the number of bits `n` in a codeword.
+    pub const fn codeword_bits(&self) -> usize {
+        self.codeword_bits
+    }
+
+    /// Get the number of data bits `k`.
+    pub const fn data_bits(&self) -> usize {
+        self.data_bits
+    }
+
+    /// Get the code with only the first `data_bits` basis sequences,
+    /// e.g. the 3GPP (32,O) code for `O` data bits.
+    pub const fn truncated(&self, data_bits: usize) -> Self {
+        assert!(data_bits > 0 && data_bits <= self.data_bits);
+        Self { data_bits, ..*self }
+    }
+
+    /// Get the codeword for the data bits as an `n` bit number where the msb is the first codeword bit.
+    pub fn codeword<S: BitView>(&self, data: S) -> u32 {
+        assert_eq!(self.data_bits, data.len2());
+        self.basis[..self.data_bits]
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| data.get(*index))
+            .fold(0, |codeword, (_, basis)| codeword ^ basis)
+    }
+
+    /// Encode the `k` data bits into the `n` codeword bits.
+    pub fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        assert_eq!(self.codeword_bits, codeword.len());
+        let value = self.codeword(data);
+        for (index, mut bit) in codeword.iter_mut().enumerate() {
+            *bit = value & (1 << (self.codeword_bits - 1 - index)) != 0;
+        }
+    }
+
+    /// Decode the llrs of the `n` codeword bits into the `k` data bits by correlating with all codewords.
+    ///
+    /// Returns the correlation between the llrs and the decided codeword,
+    /// which can be compared with a threshold to detect that nothing was transmitted.
+    pub fn decode_llrs(&self, llrs: &[Llr], data: &mut BitSlice<u8, Msb0>) -> i32 {
+        assert_eq!(self.codeword_bits, llrs.len());
+        assert_eq!(self.data_bits, data.len());
+        let k = self.data_bits;
+
+        // Visit the codewords in gray code order, so that each codeword differs from the previous by a single basis sequence
+        let mut codeword = 0;
+        let mut best = (i32::MIN, 0);
+        for step in 0..1usize << k {
+            if step > 0 {
+                codeword ^= self.basis[k - 1 - step.trailing_zeros() as usize];
+            }
+            let correlation = self.correlate(codeword, llrs);
+            if correlation > best.0 {
+                best = (correlation, step ^ (step >> 1));
+            }
+        }
+
+        let (correlation, message) = best;
+        for (index, mut bit) in data.iter_mut().enumerate() {
+            *bit = message & (1 << (k - 1 - index)) != 0;
+        }
+        correlation
+    }
+
+    /// Get the correlation between the llrs and a codeword.
+    pub(crate) fn correlate(&self, codeword: u32, llrs: &[Llr]) -> i32 {
+        llrs.iter()
+            .enumerate()
+            .map(|(index, llr)| {
+                let bit = codeword & (1 << (self.codeword_bits - 1 - index)) != 0;
+                bit.mul(*llr) as i32
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog;
+
+    use super::*;
+
+    #[test]
+    fn can_encode_cfi() {
+        // Given
+        let code = catalog::LTE_CFI;
+        let mut codeword = [0u8; 4];
+
+        // When
+        code.encode(bits![u8, Msb0; 1, 1], codeword.view_bits_mut());
+
+        // Then
+        // CFI 3 is <1, 1, 0, 1, 1, 0, ..., 1, 1>
+        assert_eq!([0xDB, 0x6D, 0xB6, 0xDB], codeword);
+    }
+
+    #[test]
+    fn can_decode_llrs() {
+        // Given
+        let code = LinearBlockCode::new(12, &[0xF00, 0x0F0, 0x00F, 0xCCC, 0xAAA]);
+        let codeword = code.codeword(bits![u8, Msb0; 1, 0, 1, 1, 0]);
+        let mut llrs: Vec<Llr> = (0..12)
+            .map(|index| (codeword & (1 << (11 - index)) != 0).mul(10))
+            .collect();
+        llrs[2] = -llrs[2];
+        llrs[7] = -llrs[7] / 5;
+        let mut data = bitarr![u8, Msb0; 0; 5];
+
+        // When
+        let correlation = code.decode_llrs(&llrs, &mut data[..5]);
+
+        // Then
+        assert_eq!(bits![u8, Msb0; 1, 0, 1, 1, 0], data[..5]);
+        assert_eq!(10 * 10 - 10 - 2, correlation);
+    }
+
+    #[test]
+    fn can_truncate() {
+        // Given
+        let code = catalog::LTE_REED_MULLER_32;
+
+        // When
+        let truncated = code.truncated(3);
+
+        // Then
+        assert_eq!(11, code.data_bits());
+        assert_eq!(3, truncated.data_bits());
+        assert_eq!(
+            code.codeword(bits![u8, Msb0; 1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0]),
+            truncated.codeword(bits![u8, Msb0; 1, 0, 1])
+        );
+    }
+}
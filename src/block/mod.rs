@@ -0,0 +1,15 @@
+mod code;
+mod golay;
+mod hamming;
+mod linear;
+mod parity;
+mod reedmuller;
+mod shortened;
+
+pub use code::BlockCode;
+pub use golay::Golay;
+pub use hamming::Hamming;
+pub use linear::{LinearBlockCode, MAX_DATA_BITS};
+pub use parity::ParityCheck;
+pub use reedmuller::{ReedMuller, MAX_LOG2};
+pub use shortened::Shortened;
@@ -0,0 +1,94 @@
+use bitvec::prelude::*;
+
+use crate::BitView;
+
+use super::BlockCode;
+
+/// A single parity check `(n, n - 1)` code.
+///
+/// The codeword is the `n - 1` data bits followed by their even parity bit.
+/// The code has minimum distance 2, so it detects a single error but cannot correct it.
+#[derive(Clone, Copy, Debug)]
+pub struct ParityCheck {
+    codeword_bits: usize,
+}
+
+impl ParityCheck {
+    /// Create a new parity check code with `codeword_bits` bits `n`, at least 2.
+    pub const fn new(codeword_bits: usize) -> Self {
+        assert!(codeword_bits >= 2);
+        Self { codeword_bits }
+    }
+
+    /// Get the number of bits `n` in a codeword.
+    pub const fn codeword_bits(&self) -> usize {
+        self.codeword_bits
+    }
+
+    /// Get the number of data bits `k`.
+    pub const fn data_bits(&self) -> usize {
+        self.codeword_bits - 1
+    }
+
+    /// Encode the `n - 1` data bits into the `n` codeword bits.
+    pub fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        let k = self.data_bits();
+        assert_eq!(k, data.len2());
+        assert_eq!(self.codeword_bits, codeword.len());
+
+        let mut parity = false;
+        for index in 0..k {
+            let bit = data.get(index);
+            codeword.set(index, bit);
+            parity ^= bit;
+        }
+        codeword.set(k, parity);
+    }
+
+    /// Check the parity of a codeword.
+    ///
+    /// Returns `Some(0)` if the parity is even, or `None` if an error is detected.
+    pub fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize> {
+        assert_eq!(self.codeword_bits, codeword.len());
+        (codeword.count_ones() % 2 == 0).then_some(0)
+    }
+}
+
+impl BlockCode for ParityCheck {
+    fn codeword_bits(&self) -> usize {
+        ParityCheck::codeword_bits(self)
+    }
+
+    fn data_bits(&self) -> usize {
+        ParityCheck::data_bits(self)
+    }
+
+    fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        ParityCheck::encode(self, data, codeword)
+    }
+
+    fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize> {
+        ParityCheck::decode(self, codeword)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_encode_and_detect_error() {
+        // Given
+        let code = ParityCheck::new(8);
+        let mut codeword = bitarr![u8, Msb0; 0; 8];
+
+        // When
+        code.encode(bits![u8, Msb0; 1, 0, 1, 1, 0, 0, 1], &mut codeword[..8]);
+
+        // Then
+        assert_eq!(bits![u8, Msb0; 1, 0, 1, 1, 0, 0, 1, 0], codeword[..8]);
+        assert_eq!(Some(0), code.decode(&mut codeword[..8]));
+        codeword.set(3, false);
+        assert_eq!(None, code.decode(&mut codeword[..8]));
+    }
+}
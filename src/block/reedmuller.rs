@@ -0,0 +1,147 @@
+use bitvec::prelude::*;
+
+use crate::{BitView, Llr};
+
+/// The maximum `m` of a [`ReedMuller`] code.
+pub const MAX_LOG2: usize = 10;
+
+/// The first order Reed-Muller code RM(1,m) with a soft maximum likelihood decoder using the fast Hadamard transform.
+///
+/// The codeword has `2^m` bits and there are `m + 1` data bits `u_0, ..., u_m`.
+/// Codeword bit `i` is `u_0 + u_1 i_(m-1) + ... + u_m i_0`, where `i_j` is bit `j` of `i`.
+/// The code has minimum distance `2^(m-1)`.
+#[derive(Clone, Copy, Debug)]
+pub struct ReedMuller {
+    log2: usize,
+}
+
+impl ReedMuller {
+    /// Create a new first order Reed-Muller code with `2^log2` bits.
+    pub const fn new(log2: usize) -> Self {
+        assert!(log2 > 0 && log2 <= MAX_LOG2);
+        Self { log2 }
+    }
+
+    /// Get the number of bits `n` in a codeword.
+    pub const fn codeword_bits(&self) -> usize {
+        1 << self.log2
+    }
+
+    /// Get the number of data bits `k`.
+    pub const fn data_bits(&self) -> usize {
+        self.log2 + 1
+    }
+
+    /// Encode the `m + 1` data bits into the `2^m` codeword bits.
+    pub fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        assert_eq!(self.data_bits(), data.len2());
+        assert_eq!(self.codeword_bits(), codeword.len());
+
+        let constant = data.get(0);
+        let linear =
+            (1..=self.log2).fold(0, |linear, index| (linear << 1) | data.get(index) as usize);
+        for (index, mut bit) in codeword.iter_mut().enumerate() {
+            *bit = constant ^ ((index & linear).count_ones() % 2 == 1);
+        }
+    }
+
+    /// Decode the llrs of the `2^m` codeword bits into the `m + 1` data bits.
+    ///
+    /// The correlations with all codewords are computed with the fast Hadamard transform.
+    /// Returns the correlation between the llrs and the decided codeword.
+    pub fn decode_llrs(&self, llrs: &[Llr], data: &mut BitSlice<u8, Msb0>) -> i32 {
+        let n = self.codeword_bits();
+        assert_eq!(n, llrs.len());
+        assert_eq!(self.data_bits(), data.len());
+
+        // The transform of -llr, i.e. positive for a zero bit,
+        // gives the correlation with the codeword with u_0 = 0 for each linear part
+        let mut spectrum = [0i32; 1 << MAX_LOG2];
+        let spectrum = &mut spectrum[..n];
+        for (value, llr) in spectrum.iter_mut().zip(llrs) {
+            *value = -(*llr as i32);
+        }
+        let mut half = 1;
+        while half < n {
+            for block in spectrum.chunks_exact_mut(2 * half) {
+                let (left, right) = block.split_at_mut(half);
+                for (a, b) in left.iter_mut().zip(right.iter_mut()) {
+                    (*a, *b) = (*a + *b, *a - *b);
+                }
+            }
+            half *= 2;
+        }
+
+        // The codeword with u_0 = 1 has the negated correlation
+        let (linear, correlation) = spectrum
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, value)| value.abs())
+            .unwrap();
+        data.set(0, *correlation < 0);
+        for index in 1..=self.log2 {
+            data.set(index, linear & (1 << (self.log2 - index)) != 0);
+        }
+        correlation.abs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::LlrMul;
+
+    use super::*;
+
+    #[test]
+    fn can_encode() {
+        // Given
+        let code = ReedMuller::new(3);
+        let mut codeword = bitarr![u8, Msb0; 0; 8];
+
+        // When
+        code.encode(bits![u8, Msb0; 1, 0, 1, 1], &mut codeword[..8]);
+
+        // Then
+        // 1 + i_1 + i_0
+        assert_eq!(bits![u8, Msb0; 1, 0, 0, 1, 1, 0, 0, 1], codeword[..8]);
+    }
+
+    #[test]
+    fn can_decode_llrs() {
+        // Given
+        let code = ReedMuller::new(5);
+        let data = bits![u8, Msb0; 0, 1, 1, 0, 1, 0];
+        let mut codeword = bitarr![u8, Msb0; 0; 32];
+        code.encode(data, &mut codeword[..32]);
+        let mut llrs: Vec<Llr> = codeword[..32].iter().map(|bit| bit.mul(10)).collect();
+        // 7 errors are corrected since the minimum distance is 16
+        for position in (0..32).step_by(5) {
+            llrs[position] = -llrs[position];
+        }
+        let mut decoded = bitarr![u8, Msb0; 0; 6];
+
+        // When
+        let correlation = code.decode_llrs(&llrs, &mut decoded[..6]);
+
+        // Then
+        assert_eq!(data, decoded[..6]);
+        assert_eq!(10 * (32 - 2 * 7), correlation);
+    }
+
+    #[test]
+    fn can_decode_complemented_codeword() {
+        // Given
+        let code = ReedMuller::new(4);
+        let data = bits![u8, Msb0; 1, 0, 0, 1, 1];
+        let mut codeword = bitarr![u8, Msb0; 0; 16];
+        code.encode(data, &mut codeword[..16]);
+        let llrs: Vec<Llr> = codeword[..16].iter().map(|bit| bit.mul(3)).collect();
+        let mut decoded = bitarr![u8, Msb0; 0; 5];
+
+        // When
+        code.decode_llrs(&llrs, &mut decoded[..5]);
+
+        // Then
+        assert_eq!(data, decoded[..5]);
+    }
+}
@@ -0,0 +1,142 @@
+use bitvec::prelude::*;
+
+use crate::BitView;
+
+use super::BlockCode;
+
+/// The maximum number of bits in a codeword of the code that is shortened.
+const MAX_CODEWORD_BITS: usize = 64;
+
+type Word = BitArr!(for MAX_CODEWORD_BITS, in u8, Msb0);
+
+/// A systematic block code shortened by `s` bits to a `(n - s, k - s)` code.
+///
+/// The first `s` data bits are zero and are not transmitted,
+/// e.g. to fit the component codes of a product code to the block size.
+#[derive(Clone, Copy, Debug)]
+pub struct Shortened<B: BlockCode> {
+    code: B,
+    shortened_bits: usize,
+}
+
+impl<B: BlockCode> Shortened<B> {
+    /// Create a new shortened code
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code that is shortened with at most 64 bits.
+    /// * `shortened_bits` - The number of leading data bits `s` that are zero, less than `k`.
+    pub fn new(code: B, shortened_bits: usize) -> Self {
+        assert!(code.codeword_bits() <= MAX_CODEWORD_BITS);
+        assert!(shortened_bits < code.data_bits());
+        Self {
+            code,
+            shortened_bits,
+        }
+    }
+
+    /// Get the code that is shortened.
+    pub fn code(&self) -> &B {
+        &self.code
+    }
+}
+
+impl<B: BlockCode> BlockCode for Shortened<B> {
+    fn codeword_bits(&self) -> usize {
+        self.code.codeword_bits() - self.shortened_bits
+    }
+
+    fn data_bits(&self) -> usize {
+        self.code.data_bits() - self.shortened_bits
+    }
+
+    fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        let (s, k, n) = (
+            self.shortened_bits,
+            self.code.data_bits(),
+            self.code.codeword_bits(),
+        );
+        assert_eq!(k - s, data.len2());
+        assert_eq!(n - s, codeword.len());
+
+        let mut source = Word::ZERO;
+        for index in 0..k - s {
+            source.set(s + index, data.get(index));
+        }
+        let mut encoded = Word::ZERO;
+        self.code.encode(&source[..k], &mut encoded[..n]);
+        codeword.copy_from_bitslice(&encoded[s..n]);
+    }
+
+    /// Correct a codeword in place with the decoder of the code that is shortened.
+    ///
+    /// Returns the number of corrected bits,
+    /// or `None` if the codeword is uncorrectable or the correction is in the shortened bits,
+    /// in which case it is left unchanged.
+    fn decode(&self, codeword: &mut BitSlice<u8, Msb0>) -> Option<usize> {
+        let (s, n) = (self.shortened_bits, self.code.codeword_bits());
+        assert_eq!(n - s, codeword.len());
+
+        let mut word = Word::ZERO;
+        word[s..n].copy_from_bitslice(codeword);
+        let corrected = self.code.decode(&mut word[..n])?;
+        if word[..s].any() {
+            return None;
+        }
+        codeword.copy_from_bitslice(&word[s..n]);
+        Some(corrected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::block::Hamming;
+
+    use super::*;
+
+    #[test]
+    fn can_encode_and_correct() {
+        // Given
+        // The (64,57) extended Hamming code shortened to (60,53)
+        let code = Shortened::new(Hamming::new(6, true), 4);
+        let data: Vec<bool> = (0..53).map(|index| index % 3 == 1).collect();
+        let mut codeword = bitarr![u8, Msb0; 0; 60];
+        code.encode(data.as_slice(), &mut codeword[..60]);
+
+        for position in 0..60 {
+            let mut received = codeword;
+            let bit = received[position];
+            received.set(position, !bit);
+
+            // When
+            let corrected = code.decode(&mut received[..60]);
+
+            // Then
+            assert_eq!(Some(1), corrected);
+            assert_eq!(codeword, received);
+        }
+        assert_eq!(
+            data,
+            codeword[..53].iter().map(|bit| *bit).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rejects_correction_in_shortened_bits() {
+        // Given
+        // A double error that the unshortened code miscorrects to a codeword with the first data bit set
+        let code = Shortened::new(Hamming::new(3, false), 1);
+        let mut codeword = bitarr![u8, Msb0; 0; 6];
+        // The columns of the data bits 1 and 2 are 5 and 6, where 5 ^ 6 = 3 is the column of data bit 0
+        codeword.set(0, true);
+        codeword.set(1, true);
+        let received = codeword;
+
+        // When
+        let corrected = code.decode(&mut codeword[..6]);
+
+        // Then
+        assert_eq!(None, corrected);
+        assert_eq!(received, codeword);
+    }
+}
@@ -1,4 +1,12 @@
-use crate::{convolutional::ConvolutionalCode, turbo::TurboCode};
+use crate::{
+    bch::{BchCode, BinaryField},
+    block::LinearBlockCode,
+    convolutional::ConvolutionalCode,
+    crc::Crc,
+    ratematching::{PuncturingMatrix, TailPuncturing},
+    reedsolomon::ReedSolomonCode,
+    turbo::TurboCode,
+};
 
 /// The abrantes code defined by the transfer polynomial G = [1, (1+x^2)/(1+x+x^2)]
 /// http://paginas.fe.up.pt/~sam/textos/From%20BCJR%20to%20turbo.pdf
@@ -54,3 +62,891 @@ impl ConvolutionalCode for MIOTY {
 
     const FEEDBACK: usize = 0;
 }
+
+/// The LTE tail-biting convolutional code per 3GPP TS 36.212 §5.1.3.1
+/// with the generator polynomials G0 = 133, G1 = 171 and G2 = 165 (octal).
+/// Use [`ConvolutionalEncoder::new_tail_biting`](crate::convolutional::ConvolutionalEncoder::new_tail_biting) for encoding.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct LTE_TBCC;
+
+impl ConvolutionalCode for LTE_TBCC {
+    const CONSTRAINT_LENGTH: usize = 7;
+
+    const GENERATORS: &'static [usize] = &[
+        0o133, // d(0)
+        0o171, // d(1)
+        0o165, // d(2)
+    ];
+
+    const FEEDBACK: usize = 0;
+}
+
+/// The CCSDS rate 1/2 K=7 convolutional code per CCSDS 131.0-B §3.3
+/// with the generator polynomials G1 = 171 and G2 = 133 (octal).
+/// The G1 symbol is output first, and the G2 symbol is inverted.
+#[derive(Default)]
+pub struct CCSDS;
+
+impl ConvolutionalCode for CCSDS {
+    const CONSTRAINT_LENGTH: usize = 7;
+
+    const GENERATORS: &'static [usize] = &[
+        0o171, // G1 (C1)
+        0o133, // G2 (C2)
+    ];
+
+    const FEEDBACK: usize = 0;
+
+    const INVERTED_OUTPUTS: usize = 0b10;
+}
+
+/// The CCSDS K=7 convolutional code used as the base for the punctured codes per CCSDS 131.0-B §3.4.
+/// The code is identical to [`CCSDS`] except that the G2 symbol is _not_ inverted.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct CCSDS_PUNCTURED;
+
+impl ConvolutionalCode for CCSDS_PUNCTURED {
+    const CONSTRAINT_LENGTH: usize = 7;
+
+    const GENERATORS: &'static [usize] = CCSDS::GENERATORS;
+
+    const FEEDBACK: usize = 0;
+}
+
+/// The CCSDS rate 2/3 puncturing of [`CCSDS_PUNCTURED`].
+/// C1: 1 0, C2: 1 1 (1 = transmitted), i.e. the transmitted sequence is C1(1) C2(1) C2(2).
+pub const CCSDS_RATE_2_3: PuncturingMatrix = PuncturingMatrix::new(2, &[0b10, 0b00]);
+
+/// The CCSDS rate 3/4 puncturing of [`CCSDS_PUNCTURED`].
+/// C1: 1 0 1, C2: 1 1 0 (1 = transmitted), i.e. the transmitted sequence is C1(1) C2(1) C2(2) C1(3).
+pub const CCSDS_RATE_3_4: PuncturingMatrix = PuncturingMatrix::new(3, &[0b010, 0b100]);
+
+/// The CCSDS rate 5/6 puncturing of [`CCSDS_PUNCTURED`].
+/// C1: 1 0 1 0 1, C2: 1 1 0 1 0 (1 = transmitted),
+/// i.e. the transmitted sequence is C1(1) C2(1) C2(2) C1(3) C2(4) C1(5).
+pub const CCSDS_RATE_5_6: PuncturingMatrix = PuncturingMatrix::new(5, &[0b01010, 0b10100]);
+
+/// The CCSDS rate 7/8 puncturing of [`CCSDS_PUNCTURED`].
+/// C1: 1 0 0 0 1 0 1, C2: 1 1 1 1 0 1 0 (1 = transmitted),
+/// i.e. the transmitted sequence is C1(1) C2(1) C2(2) C2(3) C2(4) C1(5) C2(6) C1(7).
+pub const CCSDS_RATE_7_8: PuncturingMatrix = PuncturingMatrix::new(7, &[0b0101110, 0b1010000]);
+
+/// A memory 4 rate 1/4 mother code for rate-compatible punctured convolutional codes,
+/// with the generator polynomials 23, 35, 27 and 33 (octal).
+/// The first two generators form the best rate 1/2 code with constraint length 5.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct RCPC_K5;
+
+impl ConvolutionalCode for RCPC_K5 {
+    const CONSTRAINT_LENGTH: usize = 5;
+
+    const GENERATORS: &'static [usize] = &[0o23, 0o35, 0o27, 0o33];
+
+    const FEEDBACK: usize = 0;
+}
+
+/// The rate-compatible puncturing tables of [`RCPC_K5`] with period 8 for the rates 8/9, 8/10, ..., 8/32,
+/// ordered by decreasing rate for use with [`RcpcCode`](crate::convolutional::rcpc::RcpcCode).
+///
+/// The tables are the result of a search with
+/// [`punctured_free_distance`](crate::convolutional::analysis::punctured_free_distance).
+/// The search starts with the rate 8/9 table with the largest free distance and the fewest paths at that distance,
+/// and adds one bit at a time with the same criterion, first from the rate 1/2 code and then from the third and fourth generator.
+/// The free distances are 3, 3, 4, 4, 5, 5, 6, 7, 7, 7, 8, 8, 9, 9, 10, 11, 11, 11, 12, 13, 13, 13, 14 and 15.
+pub const RCPC_K5_TABLES: &[PuncturingMatrix] = &[
+    PuncturingMatrix::new(8, &[0b11101000, 0b00010011, 0b11111111, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b11101000, 0b00010010, 0b11111111, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b10101000, 0b00010010, 0b11111111, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b10101000, 0b00000010, 0b11111111, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00101000, 0b00000010, 0b11111111, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00100000, 0b00000010, 0b11111111, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000010, 0b11111111, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b11111111, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b11111110, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b11110110, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b11010110, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b11010010, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b11000010, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b01000010, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b01000000, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b11111111]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b11111110]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b11111010]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b11101010]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b10101010]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b10101000]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b10100000]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b10000000]),
+    PuncturingMatrix::new(8, &[0b00000000, 0b00000000, 0b00000000, 0b00000000]),
+];
+
+/// The CDMA2000 turbo code per 3GPP2 C.S0002 §2.1.3.1.4.2 defined by the transfer polynomial
+/// G = [1, (1+x+x^3)/(1+x^2+x^3), (1+x+x^2+x^3)/(1+x^2+x^3)].
+/// The first parity output `Y0` is identical to the parity output of the [`UMTS`] code.
+/// Use the [`Cdma2000Interleaver`](crate::interleaver::cdma2000::Cdma2000Interleaver) as the turbo interleaver.
+///
+/// The turbo decoder only supports a single parity output per constituent code,
+/// so the rate 1/2 and 1/3 codes can be decoded using the [`UMTS`] trellis as they do not transmit `Y1` and `Y'1`.
+#[derive(Default)]
+pub struct CDMA2000;
+
+impl ConvolutionalCode for CDMA2000 {
+    const CONSTRAINT_LENGTH: usize = 4;
+
+    const GENERATORS: &'static [usize] = &[
+        0b1011, // Systematic part (X)
+        0b1101, // First encoded part (Y0)
+        0b1111, // Second encoded part (Y1)
+    ];
+
+    const FEEDBACK: usize = 0b1011;
+}
+
+impl TurboCode for CDMA2000 {
+    type ConstituentEncoderCode = CDMA2000;
+    const TERMINATE_FIRST: bool = true;
+    const TERMINATE_SECOND: bool = true;
+}
+
+/// The CDMA2000 rate 1/2 puncturing of the data bit periods of [`CDMA2000`] per C.S0002 Table 2.1.3.1.4.2.2-1.
+/// X: 11, Y0: 10, Y1: 00, Y'0: 01, Y'1: 00 (1 = transmitted).
+/// The streams are ordered as the turbo encoder output bits, where X' is never transmitted.
+pub const CDMA2000_RATE_1_2: PuncturingMatrix =
+    PuncturingMatrix::new(2, &[0b00, 0b10, 0b11, 0b01, 0b11]);
+
+/// The CDMA2000 rate 1/3 puncturing of the data bit periods of [`CDMA2000`] per C.S0002 Table 2.1.3.1.4.2.2-1.
+/// X: 11, Y0: 11, Y1: 00, Y'0: 11, Y'1: 00 (1 = transmitted).
+pub const CDMA2000_RATE_1_3: PuncturingMatrix =
+    PuncturingMatrix::new(2, &[0b00, 0b00, 0b11, 0b00, 0b11]);
+
+/// The CDMA2000 rate 1/4 puncturing of the data bit periods of [`CDMA2000`] per C.S0002 Table 2.1.3.1.4.2.2-1.
+/// X: 11, Y0: 11, Y1: 10, Y'0: 01, Y'1: 11 (1 = transmitted).
+pub const CDMA2000_RATE_1_4: PuncturingMatrix =
+    PuncturingMatrix::new(2, &[0b00, 0b00, 0b10, 0b01, 0b00]);
+
+/// The CDMA2000 rate 1/5 puncturing of the data bit periods of [`CDMA2000`] per C.S0002 Table 2.1.3.1.4.2.2-1.
+/// All of X, Y0, Y1, Y'0 and Y'1 are transmitted.
+pub const CDMA2000_RATE_1_5: PuncturingMatrix =
+    PuncturingMatrix::new(2, &[0b00, 0b00, 0b00, 0b00, 0b00]);
+
+/// The CDMA2000 rate 1/2 puncturing of the tail bit periods of [`CDMA2000`] per C.S0002 Table 2.1.3.1.4.2.2-2.
+///
+/// The streams are the X, Y0 and Y1 bits of each termination output,
+/// where the three tail bit periods of the first encoder are transmitted before those of the second encoder.
+/// The tail bit periods are repeated or punctured to give the same rate as the data bit periods.
+/// X: 1, Y0: 1, Y1: 0 (number of transmissions).
+pub const CDMA2000_RATE_1_2_TAIL: TailPuncturing = TailPuncturing::new(&[1, 1, 0]);
+
+/// The CDMA2000 rate 1/3 puncturing of the tail bit periods of [`CDMA2000`] per C.S0002 Table 2.1.3.1.4.2.2-2.
+/// X: 2, Y0: 1, Y1: 0 (number of transmissions).
+pub const CDMA2000_RATE_1_3_TAIL: TailPuncturing = TailPuncturing::new(&[2, 1, 0]);
+
+/// The CDMA2000 rate 1/4 puncturing of the tail bit periods of [`CDMA2000`] per C.S0002 Table 2.1.3.1.4.2.2-2.
+/// X: 2, Y0: 1, Y1: 1 (number of transmissions).
+pub const CDMA2000_RATE_1_4_TAIL: TailPuncturing = TailPuncturing::new(&[2, 1, 1]);
+
+/// The CDMA2000 rate 1/5 puncturing of the tail bit periods of [`CDMA2000`] per C.S0002 Table 2.1.3.1.4.2.2-2.
+/// X: 3, Y0: 1, Y1: 1 (number of transmissions).
+pub const CDMA2000_RATE_1_5_TAIL: TailPuncturing = TailPuncturing::new(&[3, 1, 1]);
+
+/// The CCSDS Reed-Solomon (255,223) code per CCSDS 131.0-B §4 in the conventional basis,
+/// with the field polynomial x^8+x^7+x^2+x+1 and the generator polynomial
+/// g(x) = (x - α^(11·112))(x - α^(11·113))...(x - α^(11·143)).
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct CCSDS_RS_255_223;
+
+impl ReedSolomonCode for CCSDS_RS_255_223 {
+    const FIELD_POLYNOMIAL: usize = 0x187;
+    const FIRST_ROOT: usize = 112;
+    const ROOT_SPACING: usize = 11;
+    const PARITY_LEN: usize = 32;
+    const CODEWORD_LEN: usize = 255;
+}
+
+/// The CCSDS Reed-Solomon (255,223) code with the symbols in the dual basis representation used on the channel.
+/// The code is identical to [`CCSDS_RS_255_223`] except for the symbol representation.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct CCSDS_RS_255_223_DUAL_BASIS;
+
+impl ReedSolomonCode for CCSDS_RS_255_223_DUAL_BASIS {
+    const FIELD_POLYNOMIAL: usize = CCSDS_RS_255_223::FIELD_POLYNOMIAL;
+    const FIRST_ROOT: usize = CCSDS_RS_255_223::FIRST_ROOT;
+    const ROOT_SPACING: usize = CCSDS_RS_255_223::ROOT_SPACING;
+    const PARITY_LEN: usize = CCSDS_RS_255_223::PARITY_LEN;
+    const CODEWORD_LEN: usize = CCSDS_RS_255_223::CODEWORD_LEN;
+    const DUAL_BASIS: bool = true;
+}
+
+/// The DVB Reed-Solomon (204,188) code per EN 300 421 §4.4.2, shortened from the (255,239) code,
+/// with the field polynomial x^8+x^4+x^3+x^2+1 and the generator polynomial
+/// g(x) = (x + λ^0)(x + λ^1)...(x + λ^15) where λ = 0x02.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_RS_204_188;
+
+impl ReedSolomonCode for DVB_RS_204_188 {
+    const FIELD_POLYNOMIAL: usize = 0x11D;
+    const FIRST_ROOT: usize = 0;
+    const ROOT_SPACING: usize = 1;
+    const PARITY_LEN: usize = 16;
+    const CODEWORD_LEN: usize = 204;
+}
+
+/// The DVB-S2 normal FECFRAME BCH field per EN 302 307 §5.3.1 with the polynomial x^16+x^5+x^3+x^2+1.
+#[allow(non_camel_case_types)]
+pub type DVB_S2_BCH_NORMAL_FIELD = BinaryField<0x1002D, 65536>;
+
+/// The DVB-S2 short FECFRAME BCH field per EN 302 307 §5.3.1 with the polynomial x^14+x^5+x^3+x+1.
+#[allow(non_camel_case_types)]
+pub type DVB_S2_BCH_SHORT_FIELD = BinaryField<0x402B, 16384>;
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 1/4 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_1_4;
+
+impl BchCode for DVB_S2_BCH_NORMAL_1_4 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 16200;
+    const DATA_BITS: usize = 16008;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 1/3 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_1_3;
+
+impl BchCode for DVB_S2_BCH_NORMAL_1_3 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 21600;
+    const DATA_BITS: usize = 21408;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 2/5 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_2_5;
+
+impl BchCode for DVB_S2_BCH_NORMAL_2_5 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 25920;
+    const DATA_BITS: usize = 25728;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 1/2 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_1_2;
+
+impl BchCode for DVB_S2_BCH_NORMAL_1_2 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 32400;
+    const DATA_BITS: usize = 32208;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 3/5 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_3_5;
+
+impl BchCode for DVB_S2_BCH_NORMAL_3_5 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 38880;
+    const DATA_BITS: usize = 38688;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 2/3 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_2_3;
+
+impl BchCode for DVB_S2_BCH_NORMAL_2_3 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 43200;
+    const DATA_BITS: usize = 43040;
+    const CORRECTABLE_ERRORS: usize = 10;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 3/4 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_3_4;
+
+impl BchCode for DVB_S2_BCH_NORMAL_3_4 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 48600;
+    const DATA_BITS: usize = 48408;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 4/5 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_4_5;
+
+impl BchCode for DVB_S2_BCH_NORMAL_4_5 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 51840;
+    const DATA_BITS: usize = 51648;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 5/6 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_5_6;
+
+impl BchCode for DVB_S2_BCH_NORMAL_5_6 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 54000;
+    const DATA_BITS: usize = 53840;
+    const CORRECTABLE_ERRORS: usize = 10;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 8/9 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_8_9;
+
+impl BchCode for DVB_S2_BCH_NORMAL_8_9 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 57600;
+    const DATA_BITS: usize = 57472;
+    const CORRECTABLE_ERRORS: usize = 8;
+}
+
+/// The DVB-S2 normal FECFRAME BCH code for the LDPC code rate 9/10 per EN 302 307 Table 5a.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_NORMAL_9_10;
+
+impl BchCode for DVB_S2_BCH_NORMAL_9_10 {
+    type Field = DVB_S2_BCH_NORMAL_FIELD;
+    const CODEWORD_BITS: usize = 58320;
+    const DATA_BITS: usize = 58192;
+    const CORRECTABLE_ERRORS: usize = 8;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 1/4 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_1_4;
+
+impl BchCode for DVB_S2_BCH_SHORT_1_4 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 3240;
+    const DATA_BITS: usize = 3072;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 1/3 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_1_3;
+
+impl BchCode for DVB_S2_BCH_SHORT_1_3 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 5400;
+    const DATA_BITS: usize = 5232;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 2/5 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_2_5;
+
+impl BchCode for DVB_S2_BCH_SHORT_2_5 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 6480;
+    const DATA_BITS: usize = 6312;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 1/2 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_1_2;
+
+impl BchCode for DVB_S2_BCH_SHORT_1_2 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 7200;
+    const DATA_BITS: usize = 7032;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 3/5 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_3_5;
+
+impl BchCode for DVB_S2_BCH_SHORT_3_5 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 9720;
+    const DATA_BITS: usize = 9552;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 2/3 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_2_3;
+
+impl BchCode for DVB_S2_BCH_SHORT_2_3 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 10800;
+    const DATA_BITS: usize = 10632;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 3/4 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_3_4;
+
+impl BchCode for DVB_S2_BCH_SHORT_3_4 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 11880;
+    const DATA_BITS: usize = 11712;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 4/5 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_4_5;
+
+impl BchCode for DVB_S2_BCH_SHORT_4_5 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 12600;
+    const DATA_BITS: usize = 12432;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 5/6 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_5_6;
+
+impl BchCode for DVB_S2_BCH_SHORT_5_6 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 13320;
+    const DATA_BITS: usize = 13152;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The DVB-S2 short FECFRAME BCH code for the LDPC code rate 8/9 per EN 302 307 Table 5b.
+#[derive(Default)]
+#[allow(non_camel_case_types)]
+pub struct DVB_S2_BCH_SHORT_8_9;
+
+impl BchCode for DVB_S2_BCH_SHORT_8_9 {
+    type Field = DVB_S2_BCH_SHORT_FIELD;
+    const CODEWORD_BITS: usize = 14400;
+    const DATA_BITS: usize = 14232;
+    const CORRECTABLE_ERRORS: usize = 12;
+}
+
+/// The LTE control format indicator (32,2) code per TS 36.212 §5.3.4.
+/// The data bits are the CFI as a two bit number, where CFI 1, 2 and 3 have the codewords
+/// <0, 1, 1, 0, 1, 1, ...>, <1, 0, 1, 1, 0, 1, ...> and <1, 1, 0, 1, 1, 0, ...>, and the all zero codeword is reserved.
+pub const LTE_CFI: LinearBlockCode = LinearBlockCode::new(32, &[0xB6DB6DB6, 0x6DB6DB6D]);
+
+/// The LTE (32,O) code per TS 36.212 Table 5.2.2.6.4-1 for up to 11 data bits,
+/// where the code for `O` data bits is [`LinearBlockCode::truncated`] to the first `O` basis sequences.
+pub const LTE_REED_MULLER_32: LinearBlockCode = LinearBlockCode::new(
+    32,
+    &[
+        0xFFFFFFFF, 0xCC95A5D2, 0x5A7089BE, 0x39CC64B6, 0x07C3E38E, 0x003FF07E, 0x2671B8CE,
+        0x0DAF22D6, 0x371843BE, 0x62ED85B2, 0xFFFF0F42,
+    ],
+);
+
+/// The LTE (20,A) code per TS 36.212 Table 5.2.3.3-1 for up to 13 data bits,
+/// where the code for `A` data bits is [`LinearBlockCode::truncated`] to the first `A` basis sequences.
+pub const LTE_REED_MULLER_20: LinearBlockCode = LinearBlockCode::new(
+    20,
+    &[
+        0xFFFFF, 0xCC95A, 0x5A708, 0x39CC6, 0x07C3E, 0x003FF, 0x2671B, 0x0DAF2, 0x37184, 0x62ED8,
+        0xFFFF0, 0xFFFCC, 0x3FFFC,
+    ],
+);
+
+/// The NR CRC24A transport block crc per 38.212 §5.1 with the polynomial
+/// D^24+D^23+D^18+D^17+D^14+D^11+D^10+D^7+D^6+D^5+D^4+D^3+D+1.
+pub const NR_CRC24A: Crc = Crc::new(24, 0x864CFB);
+
+/// The NR CRC24B code block crc per 38.212 §5.1 with the polynomial D^24+D^23+D^6+D^5+D+1.
+pub const NR_CRC24B: Crc = Crc::new(24, 0x800063);
+
+/// The NR CRC24C polar crc per 38.212 §5.1 with the polynomial
+/// D^24+D^23+D^21+D^20+D^17+D^15+D^13+D^12+D^8+D^4+D^2+D+1.
+pub const NR_CRC24C: Crc = Crc::new(24, 0xB2B117);
+
+/// The NR CRC16 per 38.212 §5.1 with the polynomial D^16+D^12+D^5+1.
+pub const NR_CRC16: Crc = Crc::new(16, 0x1021);
+
+/// The NR CRC11 uplink control information crc per 38.212 §5.1 with the polynomial D^11+D^10+D^9+D^5+1.
+pub const NR_CRC11: Crc = Crc::new(11, 0x621);
+
+/// The NR CRC6 uplink control information crc per 38.212 §5.1 with the polynomial D^6+D^5+1.
+pub const NR_CRC6: Crc = Crc::new(6, 0x21);
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        convolutional::{
+            bcjr::BcjrSymbol, viterbi::ViterbiDecoder, ConvolutionalCodeExt, ConvolutionalEncoder,
+            EncoderOutput,
+        },
+        interleaver::cdma2000::Cdma2000Interleaver,
+        ratematching::{MatrixDepuncturer, MatrixPuncturer},
+        turbo::{umts::UmtsTurboDecoder, TurboEncoder, TurboEncoderOutputWriter, TurboSymbol},
+        Llr, LlrMul,
+    };
+
+    use super::*;
+
+    #[test]
+    fn ccsds_impulse_response() {
+        // Given
+        let mut encoder = ConvolutionalEncoder::<CCSDS>::default();
+        let mut output = Vec::new();
+
+        // When
+        output.push(encoder.get_output(true));
+        for _ in 0..CCSDS::mem() {
+            output.push(encoder.get_termination_output());
+        }
+
+        // Then
+        // The impulse response is the generator polynomials msb first, G1 = 1111001, G2 = 1011011,
+        // where G2 is inverted as ~G2 = 0100100
+        let g1: Vec<usize> = output.iter().map(|x| x & 1).collect();
+        let g2: Vec<usize> = output.iter().map(|x| (x >> 1) & 1).collect();
+        assert_eq!([1, 1, 1, 1, 0, 0, 1].to_vec(), g1);
+        assert_eq!([0, 1, 0, 0, 1, 0, 0].to_vec(), g2);
+    }
+
+    #[test]
+    fn ccsds_all_zero_input_gives_alternating_symbols() {
+        // Given
+        let mut encoder = ConvolutionalEncoder::<CCSDS>::default();
+
+        // When
+        let output: Vec<EncoderOutput> = (0..8).map(|_| encoder.get_output(false)).collect();
+
+        // Then
+        // The transmitted symbol sequence G1 G2 G1 G2 ... is 0 1 0 1 ...
+        assert_eq!([0b10; 8].to_vec(), output);
+    }
+
+    #[test]
+    fn can_encode_ccsds_sequence() {
+        // Given
+        let source = [1, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 0];
+        let mut encoder = ConvolutionalEncoder::<CCSDS>::default();
+        let mut outputs: Vec<EncoderOutput> = source
+            .iter()
+            .map(|bit| encoder.get_output(*bit == 1))
+            .collect();
+
+        // When
+        for _ in 0..CCSDS::mem() {
+            outputs.push(encoder.get_termination_output());
+        }
+
+        // Then
+        // The symbols G1(1) ~G2(1) G1(2) ~G2(2) ... computed by hand from G1 = 171 and G2 = 133 (octal)
+        // for the source 0xB53C followed by six zero tail bits
+        let symbols: Vec<usize> = outputs
+            .iter()
+            .flat_map(|output| [output & 1, (output >> 1) & 1])
+            .collect();
+        assert_eq!(
+            [
+                1, 0, 1, 1, 0, 1, 1, 1, 0, 0, 1, 1, 1, 1, 0, 0, 1, 0, 0, 1, 0, 0, 1, 1, 1, 0, 1, 1,
+                1, 1, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 1, 0, 1
+            ]
+            .to_vec(),
+            symbols
+        );
+    }
+
+    #[test]
+    fn ccsds_punctured_rates() {
+        assert_rate(2, 3, CCSDS_RATE_2_3);
+        assert_rate(3, 4, CCSDS_RATE_3_4);
+        assert_rate(5, 6, CCSDS_RATE_5_6);
+        assert_rate(7, 8, CCSDS_RATE_7_8);
+    }
+
+    fn assert_rate(k: u8, n: u8, matrix: PuncturingMatrix) {
+        let rate = matrix.rate();
+        assert_eq!(k, rate.k);
+        assert_eq!(n, rate.n);
+    }
+
+    #[test]
+    fn ccsds_rate_3_4_impulse_response() {
+        // Given
+        let matrix = CCSDS_RATE_3_4;
+        let mut encoder = ConvolutionalEncoder::<CCSDS_PUNCTURED>::default();
+        let mut transmitted = Vec::new();
+
+        // When
+        for index in 0..6 {
+            let output = encoder.get_output(index == 0);
+            for stream in 0..matrix.streams() {
+                if !matrix.is_punctured(stream, index) {
+                    transmitted.push((output >> stream) & 1);
+                }
+            }
+        }
+
+        // Then
+        // C1(1) C2(1) C2(2) C1(3) | C1(4) C2(4) C2(5) C1(6) from G1 = 1111001 and G2 = 1011011
+        assert_eq!([1, 1, 0, 1, 1, 1, 0, 0].to_vec(), transmitted);
+    }
+
+    #[test]
+    fn can_encode_ccsds_rate_3_4_sequence() {
+        // Given
+        let source = [1, 0, 1, 1, 0, 1, 0, 1, 0, 0, 1, 1, 1, 1, 0, 0];
+        let mut encoder = ConvolutionalEncoder::<CCSDS_PUNCTURED>::default();
+        let mut puncturer = MatrixPuncturer::new(CCSDS_RATE_3_4);
+        let mut transmitted = Vec::new();
+
+        // When
+        for bit in source {
+            puncturer.puncture(encoder.get_output(bit == 1), |bit| {
+                transmitted.push(bit as usize)
+            });
+        }
+
+        // Then
+        // The sequence C1(1) C2(1) C2(2) C1(3) ... computed by hand from G1 = 171 and G2 = 133 (octal)
+        // for the source 0xB53C, where the G2 symbol is not inverted
+        assert_eq!(
+            [1, 1, 0, 0, 1, 0, 1, 1, 1, 0, 1, 1, 0, 0, 1, 1, 1, 1, 0, 1, 0, 1].to_vec(),
+            transmitted
+        );
+    }
+
+    #[test]
+    fn can_decode_ccsds_with_viterbi() {
+        // Given
+        let decoder = ViterbiDecoder::<CCSDS, 64, 38>::new(true);
+        let source: Vec<bool> = (0..32).map(|i| (i * 5 + i / 4) % 3 == 1).collect();
+        let mut encoder = ConvolutionalEncoder::<CCSDS>::default();
+        let mut outputs: Vec<EncoderOutput> =
+            source.iter().map(|bit| encoder.get_output(*bit)).collect();
+        for _ in 0..CCSDS::mem() {
+            outputs.push(encoder.get_termination_output());
+        }
+        let mut input = Vec::new();
+        for output in outputs {
+            input.push((output & 1 != 0).mul(8));
+            input.push((output & 2 != 0).mul(8));
+        }
+        input[7] = -input[7];
+        input[40] = -input[40];
+        let mut output = [false; 38];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        assert_eq!(source, output[..32]);
+    }
+
+    #[test]
+    fn cdma2000_punctured_rates() {
+        assert_rate(2, 4, CDMA2000_RATE_1_2);
+        assert_rate(2, 6, CDMA2000_RATE_1_3);
+        assert_rate(2, 8, CDMA2000_RATE_1_4);
+        assert_rate(2, 10, CDMA2000_RATE_1_5);
+    }
+
+    #[test]
+    fn lte_reed_muller_minimum_distance() {
+        // Given
+        let codes = [
+            (
+                LTE_REED_MULLER_32,
+                [32, 16, 16, 16, 16, 16, 12, 12, 12, 12, 10, 0, 0].as_slice(),
+            ),
+            (
+                LTE_REED_MULLER_20,
+                [20, 10, 8, 8, 8, 8, 6, 6, 6, 6, 4, 4, 4].as_slice(),
+            ),
+        ];
+
+        for (code, expected) in codes {
+            // When
+            let distances: Vec<u32> = (1..=code.data_bits())
+                .map(|data_bits| {
+                    let code = code.truncated(data_bits);
+                    (1u32..1 << data_bits)
+                        .map(|message| {
+                            let data: Vec<bool> =
+                                (0..data_bits).map(|bit| message >> bit & 1 != 0).collect();
+                            code.codeword(data.as_slice()).count_ones()
+                        })
+                        .min()
+                        .unwrap()
+                })
+                .collect();
+
+            // Then
+            assert_eq!(expected[..code.data_bits()], distances);
+        }
+    }
+
+    #[test]
+    fn cdma2000_tail_rates() {
+        // The tail bit periods have the same rate as the data bit periods
+        assert_eq!(2, CDMA2000_RATE_1_2_TAIL.transmitted_per_output());
+        assert_eq!(3, CDMA2000_RATE_1_3_TAIL.transmitted_per_output());
+        assert_eq!(4, CDMA2000_RATE_1_4_TAIL.transmitted_per_output());
+        assert_eq!(5, CDMA2000_RATE_1_5_TAIL.transmitted_per_output());
+    }
+
+    #[test]
+    fn can_encode_cdma2000_rate_1_4_frame() {
+        // Given
+        let source: Vec<bool> = (0..378).map(|i| (i * 7 + i / 3) % 4 == 1).collect();
+        let interleaver = Cdma2000Interleaver::new(378);
+        let encoder = TurboEncoder::<CDMA2000>::default();
+        let mut writer = Writer::default();
+
+        // When
+        encoder.encode(&source[..], &interleaver, &mut writer);
+        let transmitted = transmit(&writer, CDMA2000_RATE_1_4, CDMA2000_RATE_1_4_TAIL);
+
+        // Then
+        // The frame holds (N + 6) * 4 symbols, starting with X Y0 Y1 Y'0 for the first data bit period
+        // and X Y0 Y'0 Y'1 for the second
+        assert_eq!((378 + 6) * 4, transmitted.len());
+        let output = writer.outputs[0];
+        let expected: Vec<bool> = [0, 1, 2, 3]
+            .iter()
+            .map(|bit| output & (1 << bit) != 0)
+            .collect();
+        assert_eq!(expected, transmitted[..4]);
+        let output = writer.outputs[1];
+        let expected: Vec<bool> = [0, 1, 3, 4]
+            .iter()
+            .map(|bit| output & (1 << bit) != 0)
+            .collect();
+        assert_eq!(expected, transmitted[4..8]);
+
+        // The first tail bit period of the second encoder follows those of the first encoder
+        let tail = writer
+            .terminations
+            .iter()
+            .find(|(index, _)| *index == 1)
+            .unwrap()
+            .1;
+        let expected = [tail & 1 != 0, tail & 1 != 0, tail & 2 != 0, tail & 4 != 0];
+        assert_eq!(expected, transmitted[378 * 4 + 12..378 * 4 + 16]);
+    }
+
+    #[test]
+    fn can_decode_cdma2000_rate_1_3() {
+        // Given
+        let source: Vec<bool> = (0..378).map(|i| (i * 7 + i / 3) % 4 == 1).collect();
+        let interleaver = Cdma2000Interleaver::new(378);
+        let encoder = TurboEncoder::<CDMA2000>::default();
+        let mut writer = Writer::default();
+        encoder.encode(&source[..], &interleaver, &mut writer);
+        let mut transmitted: Vec<Llr> =
+            transmit(&writer, CDMA2000_RATE_1_3, CDMA2000_RATE_1_3_TAIL)
+                .iter()
+                .map(|bit| bit.mul(4))
+                .collect();
+        for index in (1..transmitted.len()).step_by(13) {
+            transmitted[index] = -transmitted[index];
+        }
+
+        // When
+        let mut depuncturer = MatrixDepuncturer::new(CDMA2000_RATE_1_3);
+        let mut received = [0; 5 * 378];
+        let consumed = depuncturer.depuncture(&transmitted, &mut received);
+        let input: Vec<TurboSymbol> = received
+            .chunks_exact(5)
+            .map(|llrs| TurboSymbol::new(llrs[0], llrs[1], llrs[3]))
+            .collect();
+        let mut terminations = transmitted[consumed..]
+            .chunks_exact(CDMA2000_RATE_1_3_TAIL.transmitted_per_output())
+            .map(|input| {
+                let mut llrs = [0; 3];
+                CDMA2000_RATE_1_3_TAIL.depuncture(input, &mut llrs);
+                BcjrSymbol::new(llrs[0], llrs[1])
+            });
+        let first_termination: Vec<BcjrSymbol> = terminations.by_ref().take(3).collect();
+        let second_termination: Vec<BcjrSymbol> = terminations.collect();
+        let decoder = UmtsTurboDecoder::<UMTS, 378, 381, 381, 381>::default();
+        let mut decoding = decoder.decode(
+            &input,
+            &interleaver,
+            &first_termination,
+            &second_termination,
+        );
+        for _ in 0..4 {
+            decoding.run_decode_iteration();
+        }
+
+        // Then
+        assert_eq!(3 * 378, consumed);
+        assert_eq!(3, second_termination.len());
+        for (llr, bit) in decoding.get_result().iter().zip(source) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+
+    /// Puncture the data and tail bit periods of a CDMA2000 frame in transmission order.
+    fn transmit(writer: &Writer, data: PuncturingMatrix, tail: TailPuncturing) -> Vec<bool> {
+        let mut puncturer = MatrixPuncturer::new(data);
+        let mut transmitted = Vec::new();
+        for output in writer.outputs.iter() {
+            puncturer.puncture(*output, |bit| transmitted.push(bit));
+        }
+        for encoder_index in 0..2 {
+            for (_, output) in writer
+                .terminations
+                .iter()
+                .filter(|(index, _)| *index == encoder_index)
+            {
+                tail.puncture(*output, |bit| transmitted.push(bit));
+            }
+        }
+        transmitted
+    }
+
+    #[derive(Default)]
+    struct Writer {
+        outputs: Vec<EncoderOutput>,
+        terminations: Vec<(usize, EncoderOutput)>,
+    }
+
+    impl TurboEncoderOutputWriter for Writer {
+        fn write_output(&mut self, output: EncoderOutput) {
+            self.outputs.push(output);
+        }
+
+        fn write_termination_output(&mut self, encoder_index: usize, output: EncoderOutput) {
+            self.terminations.push((encoder_index, output));
+        }
+    }
+}
@@ -0,0 +1,355 @@
+//! Distance properties of convolutional codes.
+//!
+//! The analysis considers the paths that diverge from the zero state and remerge with it later.
+//! The inverted outputs of a code do not change the distances, so they are ignored.
+
+use crate::ratematching::PuncturingMatrix;
+
+use super::{code::CodeState, trellis, ConvolutionalCode, ConvolutionalCodeExt};
+
+/// The maximum output weight of the paths that are enumerated by [`distance_spectrum`].
+const MAX_WEIGHT: usize = 64;
+
+/// A term of the distance spectrum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SpectrumTerm {
+    /// The output weight `d` of the paths.
+    pub distance: usize,
+    /// The number of paths `a_d` with the output weight.
+    pub multiplicity: u64,
+    /// The total input weight `c_d` of the paths with the output weight.
+    pub information_weight: u64,
+}
+
+/// Get the free distance of a code, i.e. the minimum output weight of a path that diverges from and remerges with the zero state.
+///
+/// `STATES` must be the number of states of the code.
+pub fn free_distance<C: ConvolutionalCode, const STATES: usize>() -> usize {
+    assert_eq!(STATES, trellis::state_count(C::mem()));
+
+    // The minimum weight to reach each state without passing through the zero state
+    let mut weights = [usize::MAX; STATES];
+    let first = C::get_next_state(0, true);
+    weights[first] = branch_weight::<C>(0, true);
+
+    let mut free_distance = usize::MAX;
+    for _ in 0..STATES {
+        let mut next = weights;
+        for (state, weight) in weights.iter().enumerate().skip(1) {
+            if *weight == usize::MAX {
+                continue;
+            }
+            for input in [false, true] {
+                let next_state = C::get_next_state(state, input);
+                let next_weight = weight + branch_weight::<C>(state, input);
+                if next_state == 0 {
+                    free_distance = free_distance.min(next_weight);
+                } else if next_weight < next[next_state] {
+                    next[next_state] = next_weight;
+                }
+            }
+        }
+        weights = next;
+    }
+
+    free_distance
+}
+
+/// Get the free distance of a code punctured with `matrix`,
+/// i.e. the minimum weight of the transmitted bits of a path that diverges from and remerges with the zero state,
+/// where the path may diverge at any position in the puncturing period.
+///
+/// `STATES` must be the number of states of the code.
+/// Panics if the punctured code is catastrophic.
+pub fn punctured_free_distance<C: ConvolutionalCode, const STATES: usize>(
+    matrix: &PuncturingMatrix,
+) -> usize {
+    assert_eq!(STATES, trellis::state_count(C::mem()));
+    assert_eq!(C::rate().n as usize, matrix.streams());
+
+    let mut free_distance = usize::MAX;
+    for start in 0..matrix.period {
+        // The minimum weight to reach each state after the same number of steps without passing through the zero state
+        let mut weights = [usize::MAX; STATES];
+        let first = C::get_next_state(0, true);
+        weights[first] = punctured_branch_weight::<C>(matrix, start, 0, true);
+
+        // A path that is lighter than the free distance visits each state and phase at most once
+        // between transmitted bits in a non-catastrophic code
+        let mut index = start + 1;
+        while weights.iter().any(|weight| *weight < free_distance) {
+            assert!(
+                index - start <= (MAX_WEIGHT + 1) * STATES * matrix.period,
+                "The code is catastrophic"
+            );
+
+            let mut next = [usize::MAX; STATES];
+            for (state, weight) in weights.iter().enumerate().skip(1) {
+                if *weight >= free_distance {
+                    continue;
+                }
+                for input in [false, true] {
+                    let next_state = C::get_next_state(state, input);
+                    let next_weight =
+                        weight + punctured_branch_weight::<C>(matrix, index, state, input);
+                    if next_state == 0 {
+                        free_distance = free_distance.min(next_weight);
+                    } else if next_weight < next[next_state] {
+                        next[next_state] = next_weight;
+                    }
+                }
+            }
+            weights = next;
+            index += 1;
+        }
+    }
+
+    free_distance
+}
+
+/// Compute the first terms of the distance spectrum of a code, starting at the free distance.
+///
+/// `STATES` must be the number of states of the code.
+/// The terms are written to `spectrum`, including the terms with no paths.
+/// Panics if the code is catastrophic, i.e. if there is a zero weight path that never remerges with the zero state.
+pub fn distance_spectrum<C: ConvolutionalCode, const STATES: usize>(spectrum: &mut [SpectrumTerm]) {
+    let free_distance = free_distance::<C, STATES>();
+    let max_weight = free_distance + spectrum.len() - 1;
+    assert!(max_weight <= MAX_WEIGHT);
+
+    for (index, term) in spectrum.iter_mut().enumerate() {
+        *term = SpectrumTerm {
+            distance: free_distance + index,
+            ..Default::default()
+        };
+    }
+
+    // The number of paths and their total input weight for each state and output weight,
+    // for the paths that have not yet remerged with the zero state
+    let mut paths = [[(0u64, 0u64); MAX_WEIGHT + 1]; STATES];
+    let first = C::get_next_state(0, true);
+    paths[first][branch_weight::<C>(0, true)] = (1, 1);
+
+    // A path with the maximum weight cannot visit any state more than `max_weight + 1` times in a non-catastrophic code
+    for _ in 0..(max_weight + 1) * STATES {
+        let mut next = [[(0u64, 0u64); MAX_WEIGHT + 1]; STATES];
+        let mut active = false;
+        for (state, weights) in paths.iter().enumerate().skip(1) {
+            for (weight, (count, information)) in weights[..=max_weight].iter().enumerate() {
+                if *count == 0 {
+                    continue;
+                }
+                for input in [false, true] {
+                    let next_state = C::get_next_state(state, input);
+                    let next_weight = weight + branch_weight::<C>(state, input);
+                    if next_weight > max_weight {
+                        continue;
+                    }
+                    let next_information = information + input as u64 * count;
+                    if next_state == 0 {
+                        let term = &mut spectrum[next_weight - free_distance];
+                        term.multiplicity += count;
+                        term.information_weight += next_information;
+                    } else {
+                        let (next_count, next_total) = &mut next[next_state][next_weight];
+                        *next_count += count;
+                        *next_total += next_information;
+                        active = true;
+                    }
+                }
+            }
+        }
+
+        if !active {
+            return;
+        }
+        paths = next;
+    }
+
+    panic!("The code is catastrophic");
+}
+
+/// Get whether a feed-forward code is catastrophic,
+/// i.e. whether the generator polynomials have a common factor other than a power of `D`.
+///
+/// A finite number of channel errors may then cause an infinite number of decoding errors.
+pub fn is_catastrophic<C: ConvolutionalCode>() -> bool {
+    assert_eq!(0, C::FEEDBACK, "Only feed-forward codes are supported");
+
+    // The msb is the D^0 tap, so the gcd is computed for the reciprocal polynomials,
+    // which have the reciprocal gcd up to a power of D
+    let divisor = C::GENERATORS
+        .iter()
+        .fold(0, |divisor, generator| gcd(divisor, *generator));
+    !divisor.is_power_of_two()
+}
+
+/// Get the union bound on the bit error rate for soft decision decoding with BPSK on the AWGN channel,
+/// i.e. `Σ c_d Q(sqrt(2 d R Eb/N0))` for the terms of the distance spectrum.
+pub fn union_bound_ber<C: ConvolutionalCode>(spectrum: &[SpectrumTerm], ebn0_db: f64) -> f64 {
+    let rate = 1.0 / C::rate().n as f64;
+    let ebn0 = libm::pow(10.0, ebn0_db / 10.0);
+    spectrum
+        .iter()
+        .map(|term| {
+            let argument = libm::sqrt(2.0 * term.distance as f64 * rate * ebn0);
+            term.information_weight as f64 * q(argument)
+        })
+        .sum()
+}
+
+/// Get the output weight of a branch.
+fn branch_weight<C: ConvolutionalCode>(state: CodeState, input: bool) -> usize {
+    (C::get_output(state, input) ^ C::INVERTED_OUTPUTS).count_ones() as usize
+}
+
+/// Get the weight of the transmitted output bits of a branch at encoder output `index`.
+fn punctured_branch_weight<C: ConvolutionalCode>(
+    matrix: &PuncturingMatrix,
+    index: usize,
+    state: CodeState,
+    input: bool,
+) -> usize {
+    let output = C::get_output(state, input) ^ C::INVERTED_OUTPUTS;
+    (0..matrix.streams())
+        .filter(|stream| output & (1 << stream) != 0 && !matrix.is_punctured(*stream, index))
+        .count()
+}
+
+/// Get the greatest common divisor of two polynomials over GF(2).
+fn gcd(a: usize, b: usize) -> usize {
+    let (mut a, mut b) = (a, b);
+    while b != 0 {
+        // Reduce a modulo b
+        let degree = usize::BITS - 1 - b.leading_zeros();
+        while a != 0 && usize::BITS - 1 - a.leading_zeros() >= degree {
+            a ^= b << (usize::BITS - 1 - a.leading_zeros() - degree);
+        }
+        (a, b) = (b, a);
+    }
+    a
+}
+
+/// The Gaussian tail function.
+fn q(x: f64) -> f64 {
+    0.5 * libm::erfc(x / core::f64::consts::SQRT_2)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Catastrophic;
+
+    impl ConvolutionalCode for Catastrophic {
+        const CONSTRAINT_LENGTH: usize = 3;
+
+        // 1 + D and 1 + D^2 = (1 + D)^2
+        const GENERATORS: &'static [usize] = &[0b110, 0b101];
+
+        const FEEDBACK: usize = 0;
+    }
+
+    #[derive(Default)]
+    struct CatastrophicWithoutInputTap;
+
+    impl ConvolutionalCode for CatastrophicWithoutInputTap {
+        const CONSTRAINT_LENGTH: usize = 3;
+
+        // 1 + D and D + D^2 = D (1 + D)
+        const GENERATORS: &'static [usize] = &[0b110, 0b011];
+
+        const FEEDBACK: usize = 0;
+    }
+
+    #[test]
+    fn can_get_free_distance() {
+        assert_eq!(5, free_distance::<catalog::ABRANTES, 4>());
+        assert_eq!(6, free_distance::<catalog::UMTS, 8>());
+        assert_eq!(10, free_distance::<catalog::CCSDS, 64>());
+        assert_eq!(15, free_distance::<catalog::LTE_TBCC, 64>());
+    }
+
+    #[test]
+    fn can_get_punctured_free_distance() {
+        // The free distances of the punctured K=7 codes per EN 300 421 Table 2
+        type Code = catalog::CCSDS_PUNCTURED;
+        assert_eq!(
+            6,
+            punctured_free_distance::<Code, 64>(&catalog::CCSDS_RATE_2_3)
+        );
+        assert_eq!(
+            5,
+            punctured_free_distance::<Code, 64>(&catalog::CCSDS_RATE_3_4)
+        );
+        assert_eq!(
+            4,
+            punctured_free_distance::<Code, 64>(&catalog::CCSDS_RATE_5_6)
+        );
+        assert_eq!(
+            3,
+            punctured_free_distance::<Code, 64>(&catalog::CCSDS_RATE_7_8)
+        );
+        assert_eq!(
+            10,
+            punctured_free_distance::<Code, 64>(&PuncturingMatrix::new(1, &[0, 0]))
+        );
+    }
+
+    #[test]
+    fn can_get_distance_spectrum() {
+        // Given
+        let mut spectrum = [SpectrumTerm::default(); 5];
+
+        // When
+        distance_spectrum::<catalog::CCSDS, 64>(&mut spectrum);
+
+        // Then
+        assert_eq!([10, 11, 12, 13, 14], spectrum.map(|term| term.distance));
+        assert_eq!([11, 0, 38, 0, 193], spectrum.map(|term| term.multiplicity));
+        assert_eq!(
+            [36, 0, 211, 0, 1404],
+            spectrum.map(|term| term.information_weight)
+        );
+    }
+
+    #[test]
+    fn recursive_code_has_feed_forward_multiplicities() {
+        // Given
+        let mut spectrum = [SpectrumTerm::default(); 3];
+
+        // When
+        distance_spectrum::<catalog::ABRANTES, 4>(&mut spectrum);
+
+        // Then
+        // The codewords are those of the feed-forward (7, 5) code with T(D) = D^5 / (1 - 2D)
+        assert_eq!([1, 2, 4], spectrum.map(|term| term.multiplicity));
+    }
+
+    #[test]
+    fn can_detect_catastrophic() {
+        assert!(is_catastrophic::<Catastrophic>());
+        assert!(is_catastrophic::<CatastrophicWithoutInputTap>());
+        assert!(!is_catastrophic::<catalog::CCSDS>());
+        assert!(!is_catastrophic::<catalog::LTE_TBCC>());
+    }
+
+    #[test]
+    fn can_get_union_bound() {
+        // Given
+        let mut spectrum = [SpectrumTerm::default(); 5];
+        distance_spectrum::<catalog::CCSDS, 64>(&mut spectrum);
+
+        // When
+        let low = union_bound_ber::<catalog::CCSDS>(&spectrum, 4.0);
+        let high = union_bound_ber::<catalog::CCSDS>(&spectrum, 6.0);
+
+        // Then
+        // The first term is 36 Q(sqrt(10 Eb/N0)), i.e. 36 Q(5) at 4 dB, and the remaining terms add less than the first term
+        assert!(low > 36.0 * 2.8e-7 && low < 36.0 * 3.0e-7 * 2.0);
+        assert!(high < low / 100.0);
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    convolutional::{ConvolutionalCode, ConvolutionalCodeExt},
+    convolutional::{ConvolutionalCode, ConvolutionalCodeExt, SisoDecoder, Termination},
     Llr,
 };
 use core::{fmt::Debug, marker::PhantomData};
@@ -11,6 +11,8 @@ pub struct BcjrDecoder<C: ConvolutionalCode, S: BcjrState, const MAX_TRELLIS_BIT
     _code: PhantomData<C>,
     _state: PhantomData<S>,
     pub terminated: bool,
+    /// Whether the trellis is tail-biting, in which case `terminated` is ignored.
+    pub tail_biting: bool,
 }
 
 impl<C, S, const MAX_TRELLIS_BITS: usize> BcjrDecoder<C, S, MAX_TRELLIS_BITS>
@@ -23,6 +25,29 @@ where
             _code: PhantomData,
             _state: PhantomData,
             terminated,
+            tail_biting: false,
+        }
+    }
+
+    /// Create a new decoder for a tail-biting trellis.
+    ///
+    /// The initial forward and backward state metrics are estimated by
+    /// wrapping around the block once before the actual decoding.
+    pub const fn new_tail_biting() -> Self {
+        Self {
+            _code: PhantomData,
+            _state: PhantomData,
+            terminated: false,
+            tail_biting: true,
+        }
+    }
+
+    /// Get the start and end states of the trellis.
+    pub const fn termination(&self) -> Termination {
+        if self.tail_biting {
+            Termination::TailBiting
+        } else {
+            Termination::new(self.terminated)
         }
     }
 
@@ -30,13 +55,15 @@ where
     pub fn decode(&self, input: &[BcjrSymbol], output: &mut [Llr]) {
         assert!(output.len() >= input.len());
         assert!(
-            input.len() >= (1 + self.terminated as usize) * C::mem(),
+            input.len() >= self.termination().min_steps(C::mem()),
             "The input is not long enough to open and possibly close the trellis"
         );
 
         let gamma = self.compute_gamma(input);
         let alpha = self.forward_path(&gamma);
-        self.backward_path(gamma, alpha, output);
+        self.backward_path(gamma, alpha, |index, g, a, b| {
+            output[index] = S::get_aposteriori(g, a, b);
+        });
     }
 
     /// Compute the inner product of possible transmitted symbols and their received value.
@@ -75,6 +102,24 @@ where
         let symbol_count = gamma.len();
         let mut index = 0;
 
+        if self.termination() == Termination::TailBiting {
+            // Find the initial metrics by wrapping around the trellis
+            let mut a = S::default();
+            for g in gamma {
+                a = a.get_next_alpha(*g);
+                a = a.get_all_scaled();
+            }
+            alpha.push(a).unwrap();
+
+            for g in &gamma[..symbol_count - 1] {
+                a = a.get_next_alpha(*g);
+                a = a.get_all_scaled();
+                alpha.push(a).unwrap();
+            }
+
+            return alpha;
+        }
+
         let mut a = S::default();
         a = a.get_valid_scaled(index, symbol_count);
         alpha.push(a).unwrap();
@@ -88,7 +133,7 @@ where
             index += 1;
         }
 
-        if self.terminated {
+        if self.termination().is_terminated() {
             // Trellis is terminated
             while index < symbol_count - C::mem() {
                 let g = gamma[index - 1];
@@ -126,14 +171,35 @@ where
         &self,
         gamma: Vec<u32, MAX_TRELLIS_BITS>,
         alpha: Vec<S, MAX_TRELLIS_BITS>,
-        lapp: &mut [Llr],
+        mut emit: impl FnMut(usize, u32, S, S),
     ) {
         let symbol_count = gamma.len();
         let mut index = symbol_count;
 
         let mut b = S::default();
 
-        if self.terminated {
+        if self.termination() == Termination::TailBiting {
+            // Find the final metrics by wrapping around the trellis
+            for g in gamma.iter().rev() {
+                b = b.get_previous_beta(*g);
+                b = b.get_all_scaled();
+            }
+
+            for index in (0..symbol_count).rev() {
+                let g = gamma[index];
+                let a = alpha[index];
+
+                // Emit LLR
+                emit(index, g, a, b);
+
+                b = b.get_previous_beta(g);
+                b = b.get_all_scaled();
+            }
+
+            return;
+        }
+
+        if self.termination().is_terminated() {
             b = b.get_valid_scaled(index, symbol_count);
             index -= 1;
 
@@ -142,7 +208,7 @@ where
                 let a = alpha[index];
 
                 // Emit LLR
-                lapp[index] = S::get_aposteriori(g, a, b);
+                emit(index, g, a, b);
 
                 b = b.get_previous_beta(g);
                 b = b.get_valid_scaled(index, symbol_count);
@@ -157,7 +223,7 @@ where
             let a = alpha[index];
 
             // Emit LLR
-            lapp[index] = S::get_aposteriori(g, a, b);
+            emit(index, g, a, b);
 
             b = b.get_previous_beta(g);
             b = b.get_all_scaled();
@@ -169,7 +235,7 @@ where
             let a = alpha[index];
 
             // Emit LLR
-            lapp[index] = S::get_aposteriori(g, a, b);
+            emit(index, g, a, b);
 
             b = b.get_previous_beta(g);
             b = b.get_valid_scaled(index, symbol_count);
@@ -182,11 +248,53 @@ where
             let a = alpha[index];
 
             // Emit LLR
-            lapp[index] = S::get_aposteriori(g, a, b);
+            emit(index, g, a, b);
         }
     }
 }
 
+impl<C, S, const MAX_TRELLIS_BITS: usize> BcjrDecoder<C, S, MAX_TRELLIS_BITS>
+where
+    C: ConvolutionalCode,
+    S: BcjrParityState,
+{
+    /// Soft decode a block of symbols and also emit the a-posteriori llr for each parity bit.
+    pub fn decode_with_parity(
+        &self,
+        input: &[BcjrSymbol],
+        output: &mut [Llr],
+        parity_output: &mut [Llr],
+    ) {
+        assert!(output.len() >= input.len());
+        assert!(parity_output.len() >= input.len());
+        assert!(
+            input.len() >= self.termination().min_steps(C::mem()),
+            "The input is not long enough to open and possibly close the trellis"
+        );
+
+        let gamma = self.compute_gamma(input);
+        let alpha = self.forward_path(&gamma);
+        self.backward_path(gamma, alpha, |index, g, a, b| {
+            output[index] = S::get_aposteriori(g, a, b);
+            parity_output[index] = S::get_parity_aposteriori(g, a, b);
+        });
+    }
+}
+
+impl<C, S, const MAX_TRELLIS_BITS: usize> SisoDecoder for BcjrDecoder<C, S, MAX_TRELLIS_BITS>
+where
+    C: ConvolutionalCode,
+    S: BcjrState,
+{
+    fn new(terminated: bool) -> Self {
+        BcjrDecoder::new(terminated)
+    }
+
+    fn decode(&self, input: &[BcjrSymbol], output: &mut [Llr]) {
+        BcjrDecoder::decode(self, input, output)
+    }
+}
+
 pub trait BcjrState: Debug + Default + Copy {
     /// Get the unscaled next value of A in the forward path given the current value and `g`.
     fn get_next_alpha(self, g: u32) -> Self;
@@ -203,3 +311,10 @@ pub trait BcjrState: Debug + Default + Copy {
     /// Invalidate unreachable states and scale remaining values so their sum is zero.
     fn get_valid_scaled(self, index: usize, symbol_count: usize) -> Self;
 }
+
+/// A [`BcjrState`] that can also compute the a-posteriori llr of the parity bit,
+/// e.g. for the outer decoder of a serially concatenated code.
+pub trait BcjrParityState: BcjrState {
+    /// Get the a-posteriori llr of the parity bit given the three computation values.
+    fn get_parity_aposteriori(g: u32, a: Self, b: Self) -> Llr;
+}
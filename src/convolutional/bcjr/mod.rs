@@ -2,5 +2,5 @@ mod decoder;
 mod symbol;
 pub mod umts;
 
-pub use decoder::{BcjrDecoder, BcjrState};
+pub use decoder::{BcjrDecoder, BcjrParityState, BcjrState};
 pub use symbol::BcjrSymbol;
@@ -5,7 +5,10 @@ use core::{
 
 use crate::Llr;
 
-use super::{decoder::BcjrState, BcjrDecoder};
+use super::{
+    decoder::{BcjrParityState, BcjrState},
+    BcjrDecoder,
+};
 
 pub type UmtsBcjrDecoder<C, const MAX_TRELLIS_BITS: usize> =
     BcjrDecoder<C, UmtsState, MAX_TRELLIS_BITS>;
@@ -104,6 +107,65 @@ impl UmtsState {
         }
     }
 
+    /// Get the metrics `alpha + gamma + beta` for the transitions from each state for u=0 and u=1.
+    fn get_transition_metrics(g: u32, a: Self, b: Self) -> (Self, Self) {
+        let Value { s74: b74, s30: b30 } = b.split();
+
+        // Align g for u=0
+        let g0 = Self::new(
+            (g & 0x000000FF) << 24 |    // s7 <-> s3 0/0
+                (g & 0x000000FF) << 16 |    // s6 <-> s7 0/0
+                (g & 0x0000FF00) |          // s5 <-> s6 0/1
+                (g & 0x0000FF00) >> 8, // s4 <-> s2 0/1
+            (g & 0x0000FF00) << 16 |    // s3 <-> s1 0/1
+                (g & 0x0000FFFF) << 8 |     // s2 <-> s5 0/1
+                                            // s1 <-> s4 0/0
+                (g & 0x000000FF), // s0 <-> s0 0/0
+        );
+
+        // Align B for u=0 according to A
+        let b0 = Self::new(
+            (b30 & 0xFF000000) |      // s7 <-- s3 0/0
+                (b74 & 0xFFFF0000) >> 8 | // s6 <-- s7 0/0
+                                            // s5 <-- s6 0/1
+                (b30 & 0x00FF0000) >> 16, // s4 <-- s2 0/1
+            (b30 & 0x0000FF00) << 16 |// s3 <-- s1 0/1
+                (b74 & 0x0000FFFF) << 8 | // s2 <-- s5 0/1
+                                            // s1 <-- s4 0/0
+                (b30 & 0x000000FF), // s0 <-- s0 0/0
+        );
+
+        let sum0 = Self::simd_saturating_add(Self::simd_saturating_add(a, g0), b0);
+
+        // Align g for u=1
+        let g1 = Self::new(
+            (g & 0xFF000000) |          // s7 <-> s7 1/1
+                (g & 0xFFFF0000) >> 8 |     // s6 <-> s3 1/1
+                                            // s5 <-> s2 1/0
+                (g & 0x00FF0000) >> 16, // s4 <-> s6 1/0
+            (g & 0x00FF0000) << 8 |     // s3 <-> s5 1/0
+                (g & 0x00FF0000) |          // s2 <-> s1 1/0
+                (g & 0xFF000000) >> 16 |    // s1 <-> s0 1/1
+                (g & 0xFF000000) >> 24, // s0 <-> s4 1/1
+        );
+
+        // Align B for u=1 according to A.
+        let b1 = Self::new(
+            (b74 & 0xFF000000) |      // s7 <-- s7 1/1
+                (b30 & 0xFFFF0000) >> 8 | // s6 <-- s3 1/1
+                                            // s5 <-- s2 1/0
+                (b74 & 0x00FF0000) >> 16, // s4 <-- s6 1/0
+            (b74 & 0x0000FF00) << 16 |// s3 <-- s5 1/0
+                (b30 & 0x0000FFFF) << 8 | // s2 <-- s1 1/0
+                                            // s1 <-- s0 1/1
+                (b74 & 0x000000FF), // s0 <-- s4 1/1
+        );
+
+        let sum1 = Self::simd_saturating_add(Self::simd_saturating_add(a, g1), b1);
+
+        (sum0, sum1)
+    }
+
     /// Get the scale coefficients so that all values accross all states sum to 0 as log(1) = 0
     fn get_scale_coefficients(masked_unscaled: Self, valid_state_count: usize) -> Self {
         let sum = masked_unscaled.reduce_sum();
@@ -236,60 +298,8 @@ impl BcjrState for UmtsState {
     }
 
     fn get_aposteriori(g: u32, a: Self, b: Self) -> Llr {
-        let Value { s74: b74, s30: b30 } = b.split();
-
-        // Align g for u=0
-        let g0 = Self::new(
-            (g & 0x000000FF) << 24 |    // s7 <-> s3 0/0
-                (g & 0x000000FF) << 16 |    // s6 <-> s7 0/0
-                (g & 0x0000FF00) |          // s5 <-> s6 0/1
-                (g & 0x0000FF00) >> 8, // s4 <-> s2 0/1
-            (g & 0x0000FF00) << 16 |    // s3 <-> s1 0/1
-                (g & 0x0000FFFF) << 8 |     // s2 <-> s5 0/1
-                                            // s1 <-> s4 0/0
-                (g & 0x000000FF), // s0 <-> s0 0/0
-        );
-
-        // Align B for u=0 according to A
-        let b0 = Self::new(
-            (b30 & 0xFF000000) |      // s7 <-- s3 0/0
-                (b74 & 0xFFFF0000) >> 8 | // s6 <-- s7 0/0
-                                            // s5 <-- s6 0/1
-                (b30 & 0x00FF0000) >> 16, // s4 <-- s2 0/1
-            (b30 & 0x0000FF00) << 16 |// s3 <-- s1 0/1
-                (b74 & 0x0000FFFF) << 8 | // s2 <-- s5 0/1
-                                            // s1 <-- s4 0/0
-                (b30 & 0x000000FF), // s0 <-- s0 0/0
-        );
-
-        let sum0 = Self::simd_saturating_add(Self::simd_saturating_add(a, g0), b0);
+        let (sum0, sum1) = Self::get_transition_metrics(g, a, b);
         let max0 = sum0.reduce_max();
-
-        // Align g for u=1
-        let g1 = Self::new(
-            (g & 0xFF000000) |          // s7 <-> s7 1/1
-                (g & 0xFFFF0000) >> 8 |     // s6 <-> s3 1/1
-                                            // s5 <-> s2 1/0
-                (g & 0x00FF0000) >> 16, // s4 <-> s6 1/0
-            (g & 0x00FF0000) << 8 |     // s3 <-> s5 1/0
-                (g & 0x00FF0000) |          // s2 <-> s1 1/0
-                (g & 0xFF000000) >> 16 |    // s1 <-> s0 1/1
-                (g & 0xFF000000) >> 24, // s0 <-> s4 1/1
-        );
-
-        // Align B for u=1 according to A.
-        let b1 = Self::new(
-            (b74 & 0xFF000000) |      // s7 <-- s7 1/1
-                (b30 & 0xFFFF0000) >> 8 | // s6 <-- s3 1/1
-                                            // s5 <-- s2 1/0
-                (b74 & 0x00FF0000) >> 16, // s4 <-- s6 1/0
-            (b74 & 0x0000FF00) << 16 |// s3 <-- s5 1/0
-                (b30 & 0x0000FFFF) << 8 | // s2 <-- s1 1/0
-                                            // s1 <-- s0 1/1
-                (b74 & 0x000000FF), // s0 <-- s4 1/1
-        );
-
-        let sum1 = Self::simd_saturating_add(Self::simd_saturating_add(a, g1), b1);
         let max1 = sum1.reduce_max();
 
         (max1 as i32 - max0 as i32).clamp(i8::MIN as i32, i8::MAX as i32) as Llr
@@ -318,9 +328,40 @@ impl BcjrState for UmtsState {
     }
 }
 
+impl BcjrParityState for UmtsState {
+    fn get_parity_aposteriori(g: u32, a: Self, b: Self) -> Llr {
+        let (sum0, sum1) = Self::get_transition_metrics(g, a, b);
+        let sum0 = sum0.split();
+        let sum1 = sum1.split();
+
+        // The transitions from s2, s3, s4 and s5 have parity 1 for u=0 and parity 0 for u=1,
+        // and the transitions from the remaining states have parity equal to u
+        const INVERTED: Value = Value {
+            s74: 0x0000FFFF,
+            s30: 0xFFFF0000,
+        };
+        let v0 = Self::new(
+            (sum1.s74 & INVERTED.s74) | (sum0.s74 & !INVERTED.s74),
+            (sum1.s30 & INVERTED.s30) | (sum0.s30 & !INVERTED.s30),
+        );
+        let v1 = Self::new(
+            (sum0.s74 & INVERTED.s74) | (sum1.s74 & !INVERTED.s74),
+            (sum0.s30 & INVERTED.s30) | (sum1.s30 & !INVERTED.s30),
+        );
+        let max0 = v0.reduce_max();
+        let max1 = v1.reduce_max();
+
+        (max1 as i32 - max0 as i32).clamp(i8::MIN as i32, i8::MAX as i32) as Llr
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{catalog, convolutional::bcjr::BcjrSymbol};
+    use crate::{
+        catalog,
+        convolutional::{bcjr::BcjrSymbol, ConvolutionalEncoder, Termination},
+        LlrMul,
+    };
 
     use super::*;
 
@@ -389,4 +430,96 @@ mod tests {
             output
         );
     }
+
+    #[test]
+    fn can_select_termination() {
+        // Given
+        let mut decoder = UmtsBcjrDecoder::<catalog::UMTS, 20>::new(true);
+
+        // When
+        decoder.terminated = false;
+
+        // Then
+        assert_eq!(Termination::Open, decoder.termination());
+        assert_eq!(
+            Termination::TailBiting,
+            UmtsBcjrDecoder::<catalog::UMTS, 20>::new_tail_biting().termination()
+        );
+    }
+
+    #[test]
+    fn can_decode_tail_biting() {
+        // Given
+        let decoder = UmtsBcjrDecoder::<catalog::UMTS, 20>::new_tail_biting();
+        let source = [
+            true, false, false, true, true, false, true, false, false, false, true, true, true,
+            false, true, false, true, true, false, false,
+        ];
+        let mut encoder = ConvolutionalEncoder::<catalog::UMTS>::new_tail_biting(&source).unwrap();
+        let mut input = [BcjrSymbol::new(0, 0); 20];
+        for (symbol, bit) in input.iter_mut().zip(source) {
+            let output = encoder.get_output(bit);
+            *symbol = BcjrSymbol::new((output & 1 != 0).mul(4), (output & 2 != 0).mul(4));
+        }
+        input[0].systematic = -input[0].systematic;
+        input[19].parity = -input[19].parity;
+        let mut output = [0; 20];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        for (llr, bit) in output.iter().zip(source) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The input is not long enough")]
+    fn cannot_decode_empty_tail_biting_input() {
+        // Given
+        let decoder = UmtsBcjrDecoder::<catalog::UMTS, 20>::new_tail_biting();
+        let mut output = [0; 20];
+
+        // When
+        decoder.decode(&[], &mut output);
+
+        // Then
+    }
+
+    #[test]
+    fn can_decode_parity() {
+        // Given
+        let decoder = UmtsBcjrDecoder::<catalog::UMTS, 19>::new(true);
+        let source = [
+            false, true, true, false, true, false, false, true, true, false, true, false, true,
+            true, false, false,
+        ];
+        let mut encoder = ConvolutionalEncoder::<catalog::UMTS>::default();
+        let mut input = [BcjrSymbol::new(0, 0); 19];
+        let mut parity = [false; 19];
+        for (index, symbol) in input.iter_mut().enumerate() {
+            let output = if index < source.len() {
+                encoder.get_output(source[index])
+            } else {
+                encoder.get_termination_output()
+            };
+            parity[index] = output & 2 != 0;
+            *symbol = BcjrSymbol::new((output & 1 != 0).mul(4), (output & 2 != 0).mul(4));
+        }
+        input[5].parity = -input[5].parity;
+        let mut output = [0; 19];
+        let mut parity_output = [0; 19];
+
+        // When
+        decoder.decode_with_parity(&input, &mut output, &mut parity_output);
+
+        // Then
+        for (llr, bit) in output.iter().zip(source) {
+            assert_eq!(bit, *llr > 0);
+        }
+        for (llr, bit) in parity_output.iter().zip(parity) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
 }
@@ -1,7 +1,12 @@
+use heapless::Vec;
+
 use crate::CodeRate;
 
 use super::EncoderOutput;
 
+/// The maximum number of generator polynomials of a [`DynConvolutionalCode`].
+pub const MAX_GENERATORS: usize = 8;
+
 pub(crate) type CodeState = usize;
 
 #[const_trait]
@@ -16,8 +21,13 @@ pub trait ConvolutionalCode: Default {
 
     /// The feedback polynomial describing the feedback path.
     const FEEDBACK: usize;
+
+    /// The output bits that are inverted after encoding, e.g. the inverted G2 output of the CCSDS code.
+    /// Bit 0 corresponds to the output of the first generator polynomial.
+    const INVERTED_OUTPUTS: EncoderOutput = 0;
 }
 
+#[const_trait]
 pub trait ConvolutionalCodeExt<C: ConvolutionalCode> {
     /// The code rate.
     fn rate() -> CodeRate {
@@ -39,32 +49,13 @@ pub trait ConvolutionalCodeExt<C: ConvolutionalCode> {
     }
 
     fn get_next_state(current: CodeState, input: bool) -> CodeState {
-        // Find the feedback sum
-        let sum = (current & C::FEEDBACK).count_ones() as usize & 1;
-
-        // Shift out the oldest value
-        let mut next: CodeState = current >> 1;
-
-        // Add the input + feedback as the newest value
-        next |= (input as usize ^ sum) << (C::mem() - 1);
-
-        next
+        next_state_of(C::CONSTRAINT_LENGTH, C::FEEDBACK, current, input)
     }
 
     /// Find the input that produces a 0 on the output of the first delay
     /// when used to compute the next state
     fn get_termination_input(current: CodeState) -> bool {
-        // Find the input bit that produces a 0 after the first delay
-        // Try with a guess of input=0
-        let next_if_0 = C::get_next_state(current, false);
-
-        // Take the MSB which is the newest value
-        // If this value is 0 then our guess was right - the input should be zero.
-        // If this value is 1 then it means that our guess was wrong, and we need to input 1 instead.
-        // For both cases this conviently means that we can simply take this bit as our input.
-        let input = next_if_0 >> (C::mem() - 1);
-
-        input != 0
+        termination_input(C::CONSTRAINT_LENGTH, C::FEEDBACK, current)
     }
 
     /// Get the output bits for all generator polynomials concatenated.
@@ -72,32 +63,284 @@ pub trait ConvolutionalCodeExt<C: ConvolutionalCode> {
     /// The first polynomial gets the _least_ significant bit, the last polynomial gets the most significant bit significant bit,
     /// i.e. polynomial[0] -> bit 0
     fn get_output(current: CodeState, input: bool) -> EncoderOutput {
-        let mut output = 0;
-        let mut mask = 1;
+        output_of(
+            C::GENERATORS,
+            C::FEEDBACK,
+            C::INVERTED_OUTPUTS,
+            current,
+            input,
+        )
+    }
+}
+
+impl<C: ConvolutionalCode> const ConvolutionalCodeExt<C> for C {}
+
+/// A convolutional code where the constraint length and the polynomials are runtime values,
+/// e.g. when the code is selected from a configuration.
+///
+/// The code follows the same conventions as [`ConvolutionalCode`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynConvolutionalCode {
+    constraint_length: usize,
+    generators: Vec<usize, MAX_GENERATORS>,
+    feedback: usize,
+    inverted_outputs: EncoderOutput,
+}
+
+impl DynConvolutionalCode {
+    /// Create a new code
+    ///
+    /// # Arguments
+    ///
+    /// * `constraint_length` - The constraint length, see [`ConvolutionalCode::CONSTRAINT_LENGTH`].
+    /// * `generators` - The generator polynomials, see [`ConvolutionalCode::GENERATORS`].
+    ///   There must be between 1 and [`MAX_GENERATORS`] polynomials of at most `constraint_length` bits.
+    /// * `feedback` - The feedback polynomial, see [`ConvolutionalCode::FEEDBACK`], of at most `constraint_length` bits.
+    pub fn new(constraint_length: usize, generators: &[usize], feedback: usize) -> Self {
+        assert!(constraint_length >= 2 && constraint_length <= CodeState::BITS as usize);
+        assert!(
+            !generators.is_empty() && generators.len() <= MAX_GENERATORS,
+            "There must be between 1 and {MAX_GENERATORS} generator polynomials"
+        );
+        assert!(
+            generators
+                .iter()
+                .chain([&feedback])
+                .all(|polynomial| polynomial
+                    .checked_shr(constraint_length as u32)
+                    .unwrap_or(0)
+                    == 0),
+            "The polynomials must fit within the constraint length"
+        );
+        Self {
+            constraint_length,
+            generators: Vec::from_slice(generators).unwrap(),
+            feedback,
+            inverted_outputs: 0,
+        }
+    }
+
+    /// Get the runtime representation of a code.
+    pub fn from_code<C: ConvolutionalCode>() -> Self {
+        Self::new(C::CONSTRAINT_LENGTH, C::GENERATORS, C::FEEDBACK)
+            .with_inverted_outputs(C::INVERTED_OUTPUTS)
+    }
+
+    /// Parse the octal notation of the generator polynomials, where the msb of each polynomial is the input tap.
+    ///
+    /// The polynomials are separated by commas or whitespace and may be enclosed in brackets, e.g. `171,133` for the CCSDS code.
+    /// Recursive codes are written as fractions with the feedback polynomial as the common denominator,
+    /// where `1` is the systematic output, e.g. `[1, 15/13]` for the UMTS code.
+    /// The constraint length is the length of the longest polynomial.
+    /// `None` is returned if the notation is invalid, or if a polynomial does not include the input tap.
+    pub fn from_octal(notation: &str) -> Option<Self> {
+        let notation = notation.trim();
+        let notation = notation
+            .strip_prefix('[')
+            .and_then(|notation| notation.strip_suffix(']'))
+            .unwrap_or(notation);
+
+        let mut numerators = Vec::<usize, MAX_GENERATORS>::new();
+        let mut feedback = None;
+        for term in notation
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|term| !term.is_empty())
+        {
+            let (numerator, denominator) = match term.split_once('/') {
+                Some((numerator, denominator)) => (numerator, Some(parse_octal(denominator)?)),
+                None => (term, None),
+            };
+            if denominator.is_some() {
+                if feedback.is_some_and(|feedback| Some(feedback) != denominator) {
+                    // The denominators must be the same
+                    return None;
+                }
+                feedback = denominator;
+            }
+            numerators.push(parse_octal(numerator)?).ok()?;
+        }
+
+        let feedback = feedback.unwrap_or(0);
+        let mut generators = Vec::<usize, MAX_GENERATORS>::new();
+        for numerator in numerators {
+            let generator = if feedback != 0 && numerator == 1 {
+                feedback
+            } else {
+                numerator
+            };
+            generators.push(generator).unwrap();
+        }
+
+        let constraint_length = generators
+            .iter()
+            .chain([&feedback])
+            .map(|poly| (usize::BITS - poly.leading_zeros()) as usize)
+            .max()?;
+        if constraint_length < 2 {
+            return None;
+        }
+        let input_tap = 1 << (constraint_length - 1);
+        if generators
+            .iter()
+            .any(|generator| generator & input_tap == 0)
+            || (feedback != 0 && feedback & input_tap == 0)
+        {
+            return None;
+        }
+
+        Some(Self::new(constraint_length, &generators, feedback))
+    }
+
+    /// Set the output bits that are inverted after encoding, see [`ConvolutionalCode::INVERTED_OUTPUTS`].
+    pub fn with_inverted_outputs(self, inverted_outputs: EncoderOutput) -> Self {
+        Self {
+            inverted_outputs,
+            ..self
+        }
+    }
+
+    /// The constraint length of the code.
+    pub fn constraint_length(&self) -> usize {
+        self.constraint_length
+    }
+
+    /// The generator polynomials.
+    pub fn generators(&self) -> &[usize] {
+        &self.generators
+    }
+
+    /// The feedback polynomial.
+    pub fn feedback(&self) -> usize {
+        self.feedback
+    }
 
-        // Find the feedback sum
-        let feedback_sum = (current & C::FEEDBACK).count_ones() as usize + input as usize;
+    /// The inverted output bits.
+    pub fn inverted_outputs(&self) -> EncoderOutput {
+        self.inverted_outputs
+    }
 
-        // Get the individual output for each generator and append to the final output
-        for poly in C::GENERATORS {
-            let sum = (feedback_sum + (current & poly).count_ones() as usize) & 1;
-            output |= sum * mask;
-            mask <<= 1;
+    /// The code rate.
+    pub fn rate(&self) -> CodeRate {
+        CodeRate {
+            k: 1,
+            n: self.generators.len() as u8,
         }
+    }
 
-        output
+    /// The memory in the code, i.e. the number of shift registers.
+    pub fn mem(&self) -> usize {
+        self.constraint_length - 1
+    }
+
+    /// Get whether the code is systematic, see [`ConvolutionalCodeExt::is_systematic`].
+    pub fn is_systematic(&self) -> bool {
+        self.generators[0] == self.feedback
     }
+
+    /// Get the next state, see [`ConvolutionalCodeExt::get_next_state`].
+    pub fn get_next_state(&self, current: CodeState, input: bool) -> CodeState {
+        next_state_of(self.constraint_length, self.feedback, current, input)
+    }
+
+    /// Get the termination input, see [`ConvolutionalCodeExt::get_termination_input`].
+    pub fn get_termination_input(&self, current: CodeState) -> bool {
+        termination_input(self.constraint_length, self.feedback, current)
+    }
+
+    /// Get the output bits, see [`ConvolutionalCodeExt::get_output`].
+    pub fn get_output(&self, current: CodeState, input: bool) -> EncoderOutput {
+        output_of(
+            &self.generators,
+            self.feedback,
+            self.inverted_outputs,
+            current,
+            input,
+        )
+    }
+}
+
+/// Parse an octal polynomial.
+fn parse_octal(value: &str) -> Option<usize> {
+    if value.is_empty() || !value.bytes().all(|digit| (b'0'..=b'7').contains(&digit)) {
+        return None;
+    }
+    usize::from_str_radix(value, 8).ok()
 }
 
-impl<C: ConvolutionalCode> ConvolutionalCodeExt<C> for C {}
+/// Get the next state for a code with the given constraint length and feedback polynomial.
+pub(crate) const fn next_state_of(
+    constraint_length: usize,
+    feedback: usize,
+    current: CodeState,
+    input: bool,
+) -> CodeState {
+    // Find the feedback sum
+    let sum = (current & feedback).count_ones() as usize & 1;
+
+    // Shift out the oldest value
+    let mut next: CodeState = current >> 1;
+
+    // Add the input + feedback as the newest value
+    next |= (input as usize ^ sum) << (constraint_length - 2);
+
+    next
+}
+
+/// Get the output bits for a code with the given polynomials.
+pub(crate) const fn output_of(
+    generators: &[usize],
+    feedback: usize,
+    inverted_outputs: EncoderOutput,
+    current: CodeState,
+    input: bool,
+) -> EncoderOutput {
+    let mut output = 0;
+    let mut mask = 1;
+
+    // Find the feedback sum
+    let feedback_sum = (current & feedback).count_ones() as usize + input as usize;
+
+    // Get the individual output for each generator and append to the final output
+    let mut index = 0;
+    while index < generators.len() {
+        let poly = generators[index];
+        let sum = (feedback_sum + (current & poly).count_ones() as usize) & 1;
+        output |= sum * mask;
+        mask <<= 1;
+        index += 1;
+    }
+
+    output ^ inverted_outputs
+}
+
+/// Find the input that produces a 0 on the output of the first delay
+/// for a code with the given constraint length and feedback polynomial.
+pub(crate) const fn termination_input(
+    constraint_length: usize,
+    feedback: usize,
+    current: CodeState,
+) -> bool {
+    // Find the input bit that produces a 0 after the first delay
+    // Try with a guess of input=0
+    let next_if_0 = next_state_of(constraint_length, feedback, current, false);
+
+    // Take the MSB which is the newest value
+    // If this value is 0 then our guess was right - the input should be zero.
+    // If this value is 1 then it means that our guess was wrong, and we need to input 1 instead.
+    // For both cases this conviently means that we can simply take this bit as our input.
+    let input = next_if_0 >> (constraint_length - 2);
+
+    input != 0
+}
 
 #[cfg(test)]
 mod tests {
-    use crate::catalog;
+    use crate::{catalog, convolutional::analysis};
 
-    use super::ConvolutionalCodeExt;
+    use super::{ConvolutionalCodeExt, DynConvolutionalCode};
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn abrantes() {
         // Given
         type Code = catalog::ABRANTES;
@@ -127,9 +370,12 @@ mod tests {
         assert_eq!(0b01, Code::get_output(2, true));
         assert_eq!(0b10, Code::get_output(3, false));
         assert_eq!(0b01, Code::get_output(3, true));
+
+        assert_eq!(5, analysis::free_distance::<Code, 4>());
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn umts() {
         // Given
         type Code = catalog::UMTS;
@@ -177,5 +423,123 @@ mod tests {
         assert_eq!(0b11, Code::get_output(6, true));
         assert_eq!(0b00, Code::get_output(7, false));
         assert_eq!(0b11, Code::get_output(7, true));
+
+        assert_eq!(6, analysis::free_distance::<Code, 8>());
+    }
+
+    #[test]
+    fn can_parse_feed_forward_octal() {
+        // Given
+        let notation = "171,133";
+
+        // When
+        let code = DynConvolutionalCode::from_octal(notation).unwrap();
+
+        // Then
+        assert_eq!(
+            DynConvolutionalCode::from_code::<catalog::CCSDS_PUNCTURED>(),
+            code
+        );
+        assert_eq!(
+            DynConvolutionalCode::from_code::<catalog::LTE_TBCC>(),
+            DynConvolutionalCode::from_octal("133 171 165").unwrap()
+        );
+    }
+
+    #[test]
+    fn can_parse_recursive_octal() {
+        // Given
+        let notation = "[1, 15/13]";
+
+        // When
+        let code = DynConvolutionalCode::from_octal(notation).unwrap();
+
+        // Then
+        assert_eq!(DynConvolutionalCode::from_code::<catalog::UMTS>(), code);
+        assert!(code.is_systematic());
+        assert_eq!(
+            DynConvolutionalCode::from_code::<catalog::ABRANTES>(),
+            DynConvolutionalCode::from_octal("[1, 5/7]").unwrap()
+        );
+        assert_eq!(
+            DynConvolutionalCode::from_code::<catalog::CDMA2000>(),
+            DynConvolutionalCode::from_octal("[1, 15/13, 17/13]").unwrap()
+        );
+    }
+
+    #[test]
+    fn can_reject_invalid_octal() {
+        assert_eq!(None, DynConvolutionalCode::from_octal(""));
+        assert_eq!(None, DynConvolutionalCode::from_octal("171,138"));
+        assert_eq!(None, DynConvolutionalCode::from_octal("171,33"));
+        assert_eq!(None, DynConvolutionalCode::from_octal("[1, 15/13, 17/11]"));
+        assert_eq!(None, DynConvolutionalCode::from_octal("[1, 15/]"));
+        assert_eq!(None, DynConvolutionalCode::from_octal("1"));
+    }
+
+    #[test]
+    fn dyn_code_matches_const_code() {
+        // Given
+        type Code = catalog::CCSDS;
+
+        // When
+        let code = DynConvolutionalCode::from_code::<Code>();
+
+        // Then
+        assert_eq!(Code::rate().n, code.rate().n);
+        for current in 0..64 {
+            for input in [false, true] {
+                assert_eq!(
+                    Code::get_next_state(current, input),
+                    code.get_next_state(current, input)
+                );
+                assert_eq!(
+                    Code::get_output(current, input),
+                    code.get_output(current, input)
+                );
+            }
+            assert_eq!(
+                Code::get_termination_input(current),
+                code.get_termination_input(current)
+            );
+        }
+    }
+
+    #[test]
+    fn can_create_longest_code() {
+        // Given
+        // 22 octal digits for a 64 bit polynomial
+        let notation = "1000000000000000000001, 1777777777777777777777";
+
+        // When
+        let code = DynConvolutionalCode::from_octal(notation).unwrap();
+
+        // Then
+        assert_eq!(64, code.constraint_length());
+        assert_eq!([(1 << 63) | 1, usize::MAX], code.generators());
+    }
+
+    #[test]
+    #[should_panic(expected = "There must be between 1 and 8 generator polynomials")]
+    fn rejects_too_many_generators() {
+        // Given
+        let generators = [0b111; 9];
+
+        // When
+        DynConvolutionalCode::new(3, &generators, 0);
+
+        // Then
+    }
+
+    #[test]
+    #[should_panic(expected = "The polynomials must fit within the constraint length")]
+    fn rejects_too_long_generator() {
+        // Given
+        let generators = [0b111, 0b1101];
+
+        // When
+        DynConvolutionalCode::new(3, &generators, 0);
+
+        // Then
     }
 }
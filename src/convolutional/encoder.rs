@@ -1,6 +1,10 @@
 use core::marker::PhantomData;
 
-use super::{code::CodeState, ConvolutionalCode, ConvolutionalCodeExt, EncoderOutput};
+use crate::BitView;
+
+use super::{
+    code::CodeState, ConvolutionalCode, ConvolutionalCodeExt, DynConvolutionalCode, EncoderOutput,
+};
 
 #[derive(Default)]
 pub struct ConvolutionalEncoder<C: ConvolutionalCode> {
@@ -9,6 +13,20 @@ pub struct ConvolutionalEncoder<C: ConvolutionalCode> {
 }
 
 impl<C: ConvolutionalCode> ConvolutionalEncoder<C> {
+    /// Create an encoder for tail-biting encoding of `source`.
+    ///
+    /// The encoder is preloaded with the circulation state, i.e. the state in which the encoder
+    /// also ends after all bits in `source` are encoded. For feed-forward codes this
+    /// is the state holding the last input bits. No termination output should be generated.
+    /// `None` is returned if there is no unique circulation state for the length of `source`.
+    pub fn new_tail_biting<S: BitView>(source: S) -> Option<Self> {
+        let state = circulation_state(C::mem(), source, C::get_next_state)?;
+        Some(Self {
+            _code: PhantomData,
+            state,
+        })
+    }
+
     /// Get the next encoder output given `input`
     pub fn get_output(&mut self, input: bool) -> EncoderOutput {
         let output = C::get_output(self.state, input);
@@ -23,6 +41,141 @@ impl<C: ConvolutionalCode> ConvolutionalEncoder<C> {
         self.state = C::get_next_state(self.state, input);
         output
     }
+
+    /// Encode a chunk of a continuous stream.
+    ///
+    /// The encoder state is kept between calls, so a stream can be encoded in arbitrary chunks.
+    pub fn encode<S: BitView>(&mut self, source: S, output: &mut [EncoderOutput]) {
+        assert!(output.len() >= source.len2());
+
+        for (i, output) in output.iter_mut().enumerate().take(source.len2()) {
+            *output = self.get_output(source.get(i));
+        }
+    }
+
+    /// Terminate the stream by writing the `mem` termination outputs.
+    ///
+    /// The encoder is in the zero state and ready for a new stream afterwards.
+    pub fn flush(&mut self, output: &mut [EncoderOutput]) {
+        assert!(output.len() >= C::mem());
+
+        for output in output.iter_mut().take(C::mem()) {
+            *output = self.get_termination_output();
+        }
+    }
+}
+
+/// Encoder for a [`DynConvolutionalCode`], see [`ConvolutionalEncoder`].
+pub struct DynConvolutionalEncoder {
+    code: DynConvolutionalCode,
+    state: CodeState,
+}
+
+impl DynConvolutionalEncoder {
+    /// Create an encoder starting in the zero state.
+    pub fn new(code: DynConvolutionalCode) -> Self {
+        Self { code, state: 0 }
+    }
+
+    /// Create an encoder for tail-biting encoding of `source`, see [`ConvolutionalEncoder::new_tail_biting`].
+    pub fn new_tail_biting<S: BitView>(code: DynConvolutionalCode, source: S) -> Option<Self> {
+        let state = circulation_state(code.mem(), source, |current, input| {
+            code.get_next_state(current, input)
+        })?;
+        Some(Self { code, state })
+    }
+
+    /// The code of the encoder.
+    pub fn code(&self) -> &DynConvolutionalCode {
+        &self.code
+    }
+
+    /// Get the next encoder output given `input`
+    pub fn get_output(&mut self, input: bool) -> EncoderOutput {
+        let output = self.code.get_output(self.state, input);
+        self.state = self.code.get_next_state(self.state, input);
+        output
+    }
+
+    /// Get the next encoder termination
+    pub fn get_termination_output(&mut self) -> EncoderOutput {
+        let input = self.code.get_termination_input(self.state);
+        self.get_output(input)
+    }
+
+    /// Encode a chunk of a continuous stream, see [`ConvolutionalEncoder::encode`].
+    pub fn encode<S: BitView>(&mut self, source: S, output: &mut [EncoderOutput]) {
+        assert!(output.len() >= source.len2());
+
+        for (i, output) in output.iter_mut().enumerate().take(source.len2()) {
+            *output = self.get_output(source.get(i));
+        }
+    }
+
+    /// Terminate the stream by writing the `mem` termination outputs, see [`ConvolutionalEncoder::flush`].
+    pub fn flush(&mut self, output: &mut [EncoderOutput]) {
+        assert!(output.len() >= self.code.mem());
+
+        for output in output.iter_mut().take(self.code.mem()) {
+            *output = self.get_termination_output();
+        }
+    }
+}
+
+/// Find the circulation state for tail-biting encoding of `source`,
+/// i.e. the state in which the encoder also ends after all bits in `source` are encoded.
+/// `None` is returned if there is no unique circulation state for the length of `source`.
+fn circulation_state<S: BitView>(
+    mem: usize,
+    source: S,
+    next_state: impl Fn(CodeState, bool) -> CodeState,
+) -> Option<CodeState> {
+    let length = source.len2();
+
+    // Find the final state when starting in the zero state
+    let mut zero_state_final = 0;
+    for i in 0..length {
+        zero_state_final = next_state(zero_state_final, source.get(i));
+    }
+
+    // The state transition is linear over GF(2), so the final state for any start state `s` is
+    // `zero_state_final ^ M s`, where `M s` is the final state when starting in `s` with all zero input.
+    // The circulation state solves `(I + M) s = zero_state_final`, which is solved by gaussian elimination.
+    // Each entry in the basis holds the reduced column and the start state bits that it is composed of.
+    let mut basis = [(0, 0); CodeState::BITS as usize];
+    for bit in 0..mem {
+        let mut response = 1 << bit;
+        for _ in 0..length {
+            response = next_state(response, false);
+        }
+
+        let column = reduce(&basis, (response ^ (1 << bit), 1 << bit));
+        if column.0 == 0 {
+            // (I + M) is singular
+            return None;
+        }
+        let pivot = CodeState::BITS - 1 - column.0.leading_zeros();
+        basis[pivot as usize] = column;
+    }
+
+    let (remainder, state) = reduce(&basis, (zero_state_final, 0));
+    assert_eq!(0, remainder);
+
+    Some(state)
+}
+
+fn reduce(
+    basis: &[(CodeState, CodeState)],
+    value: (CodeState, CodeState),
+) -> (CodeState, CodeState) {
+    let (mut column, mut bits) = value;
+    for (pivot, (basis_column, basis_bits)) in basis.iter().enumerate().rev() {
+        if column & (1 << pivot) != 0 && *basis_column != 0 {
+            column ^= basis_column;
+            bits ^= basis_bits;
+        }
+    }
+    (column, bits)
 }
 
 #[cfg(test)]
@@ -32,7 +185,8 @@ mod tests {
         convolutional::{ConvolutionalCodeExt, EncoderOutput},
     };
 
-    use super::ConvolutionalEncoder;
+    use super::{ConvolutionalEncoder, DynConvolutionalEncoder};
+    use crate::convolutional::DynConvolutionalCode;
 
     #[test]
     #[rustfmt::skip]
@@ -93,6 +247,124 @@ mod tests {
         );
     }
 
+    #[test]
+    fn can_encode_stream_in_chunks() {
+        // Given
+        let input = [
+            true, false, true, true, false, false, true, false, true, true,
+        ];
+        let mut encoder = ConvolutionalEncoder::<catalog::UMTS>::default();
+        let mut expected = Vec::new();
+        for bit in input {
+            expected.push(encoder.get_output(bit));
+        }
+        for _ in 0..catalog::UMTS::mem() {
+            expected.push(encoder.get_termination_output());
+        }
+        let mut encoder = ConvolutionalEncoder::<catalog::UMTS>::default();
+        let mut output = [0; 13];
+
+        // When
+        encoder.encode(&input[..4], &mut output[..4]);
+        encoder.encode(&input[4..], &mut output[4..10]);
+        encoder.flush(&mut output[10..]);
+
+        // Then
+        assert_eq!(expected, output);
+        assert_eq!(0, encoder.state);
+    }
+
+    #[test]
+    fn can_encode_tail_biting_feed_forward() {
+        // Given
+        let input = [
+            true, false, true, true, false, false, true, false, true, true,
+        ];
+        let mut encoder =
+            ConvolutionalEncoder::<catalog::LTE_TBCC>::new_tail_biting(&input).unwrap();
+
+        // When
+        let initial_state = encoder.state;
+        for bit in input {
+            encoder.get_output(bit);
+        }
+
+        // Then
+        // The state is preloaded with the last 6 input bits with the most recent bit as the msb
+        assert_eq!(0b110100, initial_state);
+        assert_eq!(initial_state, encoder.state);
+    }
+
+    #[test]
+    fn can_encode_tail_biting_recursive() {
+        // Given
+        let input = [
+            true, true, false, true, false, false, false, true, true, false,
+        ];
+        let mut encoder = ConvolutionalEncoder::<catalog::UMTS>::new_tail_biting(&input).unwrap();
+
+        // When
+        let initial_state = encoder.state;
+        for bit in input {
+            encoder.get_output(bit);
+        }
+
+        // Then
+        assert_eq!(initial_state, encoder.state);
+    }
+
+    #[test]
+    fn has_no_circulation_state_for_period_length() {
+        // Given
+        // The zero input response of the UMTS code has period 7
+        let input = [false; 7];
+
+        // When
+        let encoder = ConvolutionalEncoder::<catalog::UMTS>::new_tail_biting(&input);
+
+        // Then
+        assert!(encoder.is_none());
+    }
+
+    #[test]
+    fn dyn_encoder_matches_const_encoder() {
+        // Given
+        let input = [
+            true, false, true, true, false, false, true, false, true, true,
+        ];
+        let mut expected = [0; 13];
+        let mut encoder = ConvolutionalEncoder::<catalog::UMTS>::default();
+        encoder.encode(&input, &mut expected);
+        encoder.flush(&mut expected[10..]);
+        let code = DynConvolutionalCode::from_octal("[1, 15/13]").unwrap();
+        let mut encoder = DynConvolutionalEncoder::new(code);
+        let mut output = [0; 13];
+
+        // When
+        encoder.encode(&input, &mut output);
+        encoder.flush(&mut output[10..]);
+
+        // Then
+        assert_eq!(expected, output);
+        assert_eq!(0, encoder.state);
+    }
+
+    #[test]
+    fn can_encode_dyn_tail_biting() {
+        // Given
+        let input = [
+            true, false, true, true, false, false, true, false, true, true,
+        ];
+        let code = DynConvolutionalCode::from_code::<catalog::LTE_TBCC>();
+
+        // When
+        let encoder = DynConvolutionalEncoder::new_tail_biting(code, &input).unwrap();
+
+        // Then
+        assert_eq!(0b110100, encoder.state);
+    }
+
+    #[allow(clippy::into_iter_on_ref)]
     fn can_encode_umts_case(expected: &[EncoderOutput], input: &[u8]) {
         // Given
         let input: Vec<bool> = input.into_iter().map(|b| *b == 1).collect();
@@ -0,0 +1,267 @@
+use core::marker::PhantomData;
+
+use heapless::Vec;
+
+use crate::{Llr, LlrMul};
+
+use super::{code::CodeState, ConvolutionalCode, ConvolutionalCodeExt};
+
+/// Fano sequential decoder for terminated rate 1/n codes.
+///
+/// The complexity does not depend on the number of states, so the decoder is practical for codes with long constraint lengths,
+/// where the Viterbi and BCJR decoders are not.
+/// The decoder moves forward through the code tree as long as the path metric is above the running threshold,
+/// and otherwise moves back to search the next best branch or lowers the threshold.
+/// The number of computations grows quickly when the noise exceeds the capacity of the code,
+/// so the decoder gives up when the computation budget is exceeded.
+pub struct FanoDecoder<C: ConvolutionalCode, const MAX_TRELLIS_BITS: usize> {
+    _code: PhantomData<C>,
+    spacing: i32,
+    bias: i32,
+    budget: usize,
+}
+
+/// A node in the code tree on the current path.
+#[derive(Clone, Copy, Default)]
+struct Node {
+    /// The encoder state at the node.
+    state: CodeState,
+    /// The path metric at the node.
+    metric: i32,
+    /// The branch metric and input of the branches leaving the node, best first.
+    branches: [(i32, bool); 2],
+    /// The number of branches leaving the node, i.e. 1 in the termination.
+    branch_count: usize,
+    /// The index of the branch currently followed.
+    branch: usize,
+}
+
+impl<C: ConvolutionalCode, const MAX_TRELLIS_BITS: usize> FanoDecoder<C, MAX_TRELLIS_BITS> {
+    /// Create a new decoder
+    ///
+    /// # Arguments
+    ///
+    /// * `spacing` - The threshold spacing `Δ` in llr units.
+    /// * `bias` - The metric bias for each code bit in llr units,
+    ///   i.e. the Fano metric `(1 - R) ln 2` in the scale of the llrs.
+    ///   A larger bias favours moving forward but makes the decoder less tolerant to noise.
+    /// * `budget` - The maximum number of computations, i.e. forward and backward moves, per trellis step.
+    pub fn new(spacing: i32, bias: i32, budget: usize) -> Self {
+        assert!(spacing > 0);
+        assert!(bias >= 0);
+        assert!(budget > 0);
+        Self {
+            _code: PhantomData,
+            spacing,
+            bias,
+            budget,
+        }
+    }
+
+    /// Decode a block of llrs for a trellis that starts and is terminated in the zero state.
+    ///
+    /// There are `n` llrs for each trellis step for a rate 1/n code,
+    /// ordered the same way as the bits in [`EncoderOutput`](super::EncoderOutput).
+    /// The decoded bit for each trellis step, including the termination, is written to `output`.
+    /// Returns the number of computations, or `None` if the computation budget is exceeded.
+    pub fn decode(&self, input: &[Llr], output: &mut [bool]) -> Option<usize> {
+        let n = C::rate().n as usize;
+        assert_eq!(0, input.len() % n);
+        let steps = input.len() / n;
+        assert!(steps <= MAX_TRELLIS_BITS);
+        assert!(output.len() >= steps);
+        assert!(
+            steps >= C::mem(),
+            "The input is not long enough to close the trellis"
+        );
+        let tail = steps - C::mem();
+        let budget = self.budget * steps;
+
+        let mut nodes = Vec::<Node, MAX_TRELLIS_BITS>::new();
+        nodes
+            .push(self.expand(0, 0, &input[..n], tail > 0))
+            .ok()
+            .unwrap();
+        let mut threshold = 0;
+        let mut computations = 0;
+
+        loop {
+            let depth = nodes.len() - 1;
+            let node = nodes[depth];
+            let (branch_metric, input_bit) = node.branches[node.branch];
+            let next_metric = node.metric + branch_metric;
+
+            if next_metric >= threshold {
+                // Tighten the threshold if this is the first visit of the next node
+                if node.metric < threshold + self.spacing {
+                    while next_metric >= threshold + self.spacing {
+                        threshold += self.spacing;
+                    }
+                }
+
+                output[depth] = input_bit;
+                if depth + 1 == steps {
+                    return Some(computations);
+                }
+
+                // Move forward
+                let state = C::get_next_state(node.state, input_bit);
+                let llrs = &input[(depth + 1) * n..(depth + 2) * n];
+                nodes
+                    .push(self.expand(state, next_metric, llrs, depth + 1 < tail))
+                    .ok()
+                    .unwrap();
+            } else {
+                loop {
+                    let depth = nodes.len() - 1;
+                    if depth == 0 || nodes[depth - 1].metric < threshold {
+                        // Neither forward nor backward moves are possible, so lower the threshold and look forward from the best branch
+                        threshold -= self.spacing;
+                        nodes[depth].branch = 0;
+                        break;
+                    }
+
+                    // Move back and look forward from the next best branch if it has not been searched
+                    nodes.pop();
+                    let previous = nodes.last_mut().unwrap();
+                    if previous.branch + 1 < previous.branch_count {
+                        previous.branch += 1;
+                        break;
+                    }
+                }
+            }
+
+            computations += 1;
+            if computations > budget {
+                return None;
+            }
+        }
+    }
+
+    /// Create a node for the encoder state and compute the metrics of its branches.
+    fn expand(&self, state: CodeState, metric: i32, llrs: &[Llr], information: bool) -> Node {
+        let branch_metric = |input: bool| {
+            let output = C::get_output(state, input);
+            llrs.iter()
+                .enumerate()
+                .map(|(index, llr)| {
+                    // The likelihood metric is zero when the code bit agrees with the llr, and -|llr| otherwise
+                    let agreement = (output & (1 << index) != 0).mul(*llr);
+                    self.bias + (agreement as i32).min(0)
+                })
+                .sum::<i32>()
+        };
+
+        if information {
+            let zero = (branch_metric(false), false);
+            let one = (branch_metric(true), true);
+            Node {
+                state,
+                metric,
+                branches: if one.0 > zero.0 {
+                    [one, zero]
+                } else {
+                    [zero, one]
+                },
+                branch_count: 2,
+                branch: 0,
+            }
+        } else {
+            let input = C::get_termination_input(state);
+            Node {
+                state,
+                metric,
+                branches: [(branch_metric(input), input); 2],
+                branch_count: 1,
+                branch: 0,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{convolutional::ConvolutionalEncoder, LlrMul};
+
+    use super::*;
+
+    /// A rate 1/2 code with constraint length 32.
+    #[derive(Default)]
+    struct LongCode;
+
+    impl ConvolutionalCode for LongCode {
+        const CONSTRAINT_LENGTH: usize = 32;
+
+        const GENERATORS: &'static [usize] = &[0x8ACA0B4F, 0xE23C8627];
+
+        const FEEDBACK: usize = 0;
+    }
+
+    fn encode(source: &[bool]) -> std::vec::Vec<Llr> {
+        let mut encoder = ConvolutionalEncoder::<LongCode>::default();
+        let mut outputs = vec![0; source.len() + LongCode::mem()];
+        encoder.encode(source, &mut outputs);
+        encoder.flush(&mut outputs[source.len()..]);
+        outputs
+            .iter()
+            .flat_map(|output| (0..2).map(move |index| (output & (1 << index) != 0).mul(8)))
+            .collect()
+    }
+
+    #[test]
+    fn can_decode_without_errors() {
+        // Given
+        let source: std::vec::Vec<bool> = (0..100).map(|i| (i * 7 + i / 3) % 5 < 2).collect();
+        let input = encode(&source);
+        let decoder = FanoDecoder::<LongCode, 131>::new(8, 1, 10);
+        let mut output = [true; 131];
+
+        // When
+        let computations = decoder.decode(&input, &mut output);
+
+        // Then
+        assert_eq!(Some(130), computations);
+        assert_eq!(source, output[..100]);
+        assert_eq!([false; 31], output[100..]);
+    }
+
+    #[test]
+    fn can_correct_errors() {
+        // Given
+        let source: std::vec::Vec<bool> = (0..200).map(|i| (i * 11 + i / 7) % 3 == 0).collect();
+        let mut input = encode(&source);
+        for position in [
+            3, 10, 11, 50, 97, 98, 150, 200, 201, 260, 320, 399, 400, 401,
+        ] {
+            input[position] = -input[position];
+        }
+        let decoder = FanoDecoder::<LongCode, 231>::new(8, 1, 100);
+        let mut output = [false; 231];
+
+        // When
+        let computations = decoder.decode(&input, &mut output);
+
+        // Then
+        // The decoder has to back up to correct the errors
+        assert!(computations.unwrap() > 230);
+        assert_eq!(source, output[..200]);
+    }
+
+    #[test]
+    fn can_exceed_budget() {
+        // Given
+        let source = [false; 100];
+        let mut input = encode(&source);
+        for position in (0..input.len()).step_by(3) {
+            input[position] = -input[position];
+        }
+        let decoder = FanoDecoder::<LongCode, 131>::new(8, 1, 20);
+        let mut output = [false; 131];
+
+        // When
+        let computations = decoder.decode(&input, &mut output);
+
+        // Then
+        assert_eq!(None, computations);
+    }
+}
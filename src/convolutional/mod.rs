@@ -1,11 +1,62 @@
+pub mod analysis;
 pub mod bcjr;
 mod code;
 mod encoder;
+pub mod fano;
+pub mod rcpc;
+mod siso;
+pub mod sova;
+pub mod streaming;
+mod table;
+mod trellis;
+pub mod viterbi;
 
-pub use code::{ConvolutionalCode, ConvolutionalCodeExt};
-pub use encoder::ConvolutionalEncoder;
+pub use code::{ConvolutionalCode, ConvolutionalCodeExt, DynConvolutionalCode, MAX_GENERATORS};
+pub use encoder::{ConvolutionalEncoder, DynConvolutionalEncoder};
+pub use siso::SisoDecoder;
+pub use table::TableEncoder;
 
 /// Concatenated output bits for each generator polynomial.
 /// The output for the first polynomial maps to the least significant bit 0,
 /// The next polynomial to bit 1, etc.
 pub type EncoderOutput = usize;
+
+/// The start and end states of a trellis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination {
+    /// The trellis starts in the zero state and may end in any state.
+    Open,
+    /// The trellis starts and is terminated in the zero state.
+    Zero,
+    /// The trellis starts and ends in the same, unknown, state.
+    TailBiting,
+}
+
+impl Termination {
+    /// Get the termination for a trellis starting in the zero state.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminated` - Whether the trellis is terminated in the zero state.
+    pub const fn new(terminated: bool) -> Self {
+        if terminated {
+            Self::Zero
+        } else {
+            Self::Open
+        }
+    }
+
+    /// Whether the trellis is terminated in the zero state.
+    pub const fn is_terminated(self) -> bool {
+        matches!(self, Self::Zero)
+    }
+
+    /// Get the minimum number of trellis steps needed to open and possibly close
+    /// the trellis of a code with `mem` memory elements.
+    pub const fn min_steps(self, mem: usize) -> usize {
+        match self {
+            Self::Open | Self::TailBiting => mem,
+            Self::Zero => 2 * mem,
+        }
+    }
+}
@@ -0,0 +1,264 @@
+use core::marker::PhantomData;
+
+use crate::{ratematching::PuncturingMatrix, CodeRate, Llr};
+
+use super::{ConvolutionalCode, ConvolutionalCodeExt, EncoderOutput};
+
+/// Rate-compatible punctured convolutional (RCPC) code family.
+///
+/// The family consists of a rate 1/n mother code and an ordered set of puncturing tables,
+/// starting with the highest rate. The tables are rate-compatible,
+/// i.e. all bits transmitted for a table are also transmitted for all subsequent lower rate tables.
+/// This allows for incremental redundancy, where only the additional bits are transmitted in each step.
+pub struct RcpcCode<C: ConvolutionalCode> {
+    _code: PhantomData<C>,
+    tables: &'static [PuncturingMatrix],
+}
+
+impl<C: ConvolutionalCode> RcpcCode<C> {
+    /// Create a new code family.
+    ///
+    /// # Arguments
+    ///
+    /// * `tables` - The puncturing tables ordered by decreasing rate, all with the same period.
+    pub fn new(tables: &'static [PuncturingMatrix]) -> Self {
+        assert!(!tables.is_empty());
+        let period = tables[0].period;
+        for (index, table) in tables.iter().enumerate() {
+            assert_eq!(period, table.period);
+            assert_eq!(C::rate().n as usize, table.streams());
+
+            if index > 0 {
+                // The punctured bits must be a subset of those punctured for the previous higher rate
+                for (row, previous_row) in table.rows.iter().zip(tables[index - 1].rows) {
+                    assert_eq!(0, row & !previous_row, "The tables are not rate-compatible");
+                }
+            }
+        }
+
+        Self {
+            _code: PhantomData,
+            tables,
+        }
+    }
+
+    /// The number of rate steps in the family.
+    pub fn steps(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// The code rate after `step`.
+    pub fn rate(&self, step: usize) -> CodeRate {
+        self.tables[step].rate()
+    }
+
+    /// Get whether bit `stream` of encoder output `index` is transmitted in `step`,
+    /// i.e. it is transmitted for `step` but not for any previous step.
+    pub fn is_incremental(&self, step: usize, stream: usize, index: usize) -> bool {
+        let transmitted = !self.tables[step].is_punctured(stream, index);
+        let previously_transmitted = step > 0 && !self.tables[step - 1].is_punctured(stream, index);
+        transmitted && !previously_transmitted
+    }
+
+    /// Emit the incremental bits for `step` from the mother code encoder outputs.
+    ///
+    /// `write` is invoked for each bit in time order, and in stream order for the bits within the same encoder output.
+    /// Returns the number of written bits.
+    pub fn write_increment(
+        &self,
+        step: usize,
+        outputs: &[EncoderOutput],
+        mut write: impl FnMut(bool),
+    ) -> usize {
+        let mut written = 0;
+        for (index, output) in outputs.iter().enumerate() {
+            for stream in 0..C::rate().n as usize {
+                if self.is_incremental(step, stream, index) {
+                    write(output & (1 << stream) != 0);
+                    written += 1;
+                }
+            }
+        }
+        written
+    }
+
+    /// Get the number of incremental bits for `step` for a block of `length` encoder outputs.
+    pub fn increment_len(&self, step: usize, length: usize) -> usize {
+        let n = C::rate().n as usize;
+        (0..length)
+            .map(|index| {
+                (0..n)
+                    .filter(|stream| self.is_incremental(step, *stream, index))
+                    .count()
+            })
+            .sum()
+    }
+
+    /// Merge the received llrs for the incremental bits of `step` into the soft buffer.
+    ///
+    /// The soft buffer holds `n` llrs for each mother code encoder output, and should initially be all erasures, i.e. 0.
+    /// The llrs are added with saturation, so receiving the same step more than once combines the received llrs.
+    /// The buffer can be decoded directly with a decoder for the mother code.
+    /// Returns the number of llrs consumed from `input`.
+    pub fn merge_increment(&self, step: usize, input: &[Llr], buffer: &mut [Llr]) -> usize {
+        let n = C::rate().n as usize;
+        assert_eq!(0, buffer.len() % n);
+        assert!(
+            input.len() >= self.increment_len(step, buffer.len() / n),
+            "The input is too short for the increment"
+        );
+
+        let mut consumed = 0;
+        for (index, llrs) in buffer.chunks_exact_mut(n).enumerate() {
+            for (stream, llr) in llrs.iter_mut().enumerate() {
+                if self.is_incremental(step, stream, index) {
+                    *llr = llr.saturating_add(input[consumed]);
+                    consumed += 1;
+                }
+            }
+        }
+        consumed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog,
+        convolutional::{analysis, viterbi::ViterbiDecoder, ConvolutionalEncoder},
+        LlrMul,
+    };
+
+    use super::*;
+
+    type Code = catalog::CCSDS_PUNCTURED;
+
+    /// Rates 4/5, 4/6, 4/7 and 4/8 from the rate 1/2 mother code.
+    const TABLES: &[PuncturingMatrix] = &[
+        PuncturingMatrix::new(4, &[0b1010, 0b0100]),
+        PuncturingMatrix::new(4, &[0b1010, 0b0000]),
+        PuncturingMatrix::new(4, &[0b1000, 0b0000]),
+        PuncturingMatrix::new(4, &[0b0000, 0b0000]),
+    ];
+
+    #[test]
+    fn can_get_rates() {
+        // Given
+        let code = RcpcCode::<Code>::new(TABLES);
+
+        // When
+        let rates: Vec<(u8, u8)> = (0..code.steps())
+            .map(|step| (code.rate(step).k, code.rate(step).n))
+            .collect();
+
+        // Then
+        assert_eq!([(4, 5), (4, 6), (4, 7), (4, 8)].to_vec(), rates);
+        assert_eq!(10, code.increment_len(0, 8));
+        assert_eq!(2, code.increment_len(1, 8));
+        assert_eq!(2, code.increment_len(2, 8));
+        assert_eq!(2, code.increment_len(3, 8));
+    }
+
+    #[test]
+    #[should_panic(expected = "The tables are not rate-compatible")]
+    fn rejects_tables_that_are_not_rate_compatible() {
+        const TABLES: &[PuncturingMatrix] = &[
+            PuncturingMatrix::new(2, &[0b10, 0b00]),
+            PuncturingMatrix::new(2, &[0b00, 0b01]),
+        ];
+
+        RcpcCode::<Code>::new(TABLES);
+    }
+
+    #[test]
+    #[should_panic(expected = "The input is too short for the increment")]
+    fn cannot_merge_short_increment() {
+        // Given
+        let code = RcpcCode::<Code>::new(TABLES);
+        let mut buffer = [0; 16];
+
+        // When
+        code.merge_increment(0, &[10; 9], &mut buffer);
+
+        // Then
+    }
+
+    #[test]
+    fn rcpc_k5_family_is_rate_compatible() {
+        // Given
+        type Code = catalog::RCPC_K5;
+        let code = RcpcCode::<Code>::new(catalog::RCPC_K5_TABLES);
+
+        // When
+        let free_distances: Vec<usize> = catalog::RCPC_K5_TABLES
+            .iter()
+            .map(analysis::punctured_free_distance::<Code, 16>)
+            .collect();
+
+        // Then
+        assert_eq!(24, code.steps());
+        for step in 0..code.steps() {
+            assert_eq!(8, code.rate(step).k);
+            assert_eq!(9 + step as u8, code.rate(step).n);
+            assert_eq!(if step == 0 { 9 } else { 1 }, code.increment_len(step, 8));
+
+            // Every bit transmitted in a step is also transmitted in all lower rate steps
+            for later in step..code.steps() {
+                for stream in 0..4 {
+                    for index in 0..8 {
+                        let table = &catalog::RCPC_K5_TABLES;
+                        assert!(
+                            table[step].is_punctured(stream, index)
+                                || !table[later].is_punctured(stream, index)
+                        );
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            [3, 3, 4, 4, 5, 5, 6, 7, 7, 7, 8, 8, 9, 9, 10, 11, 11, 11, 12, 13, 13, 13, 14, 15]
+                .to_vec(),
+            free_distances
+        );
+        assert!(free_distances.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(
+            analysis::free_distance::<Code, 16>(),
+            free_distances[code.steps() - 1]
+        );
+    }
+
+    #[test]
+    fn can_decode_incremental_redundancy() {
+        // Given
+        let code = RcpcCode::<Code>::new(TABLES);
+        let source: Vec<bool> = (0..58).map(|i| (i * 7 + i / 3) % 5 < 2).collect();
+        let mut encoder = ConvolutionalEncoder::<Code>::default();
+        let mut outputs: Vec<EncoderOutput> =
+            source.iter().map(|bit| encoder.get_output(*bit)).collect();
+        for _ in 0..Code::mem() {
+            outputs.push(encoder.get_termination_output());
+        }
+        let decoder = ViterbiDecoder::<Code, 64, 64>::new(true);
+        let mut buffer = [0; 128];
+        let mut output = [false; 64];
+
+        // When
+        for step in 0..code.steps() {
+            let mut increment = Vec::new();
+            code.write_increment(step, &outputs, |bit| increment.push(bit.mul(8)));
+            if step == 0 {
+                // Corrupt the first transmission
+                for index in (0..increment.len()).step_by(9) {
+                    increment[index] = -increment[index];
+                }
+            }
+            let consumed = code.merge_increment(step, &increment, &mut buffer);
+            assert_eq!(increment.len(), consumed);
+        }
+        decoder.decode(&buffer, &mut output);
+
+        // Then
+        assert!(buffer.iter().all(|llr| *llr != 0));
+        assert_eq!(source, output[..58]);
+    }
+}
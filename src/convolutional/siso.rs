@@ -0,0 +1,18 @@
+use crate::Llr;
+
+use super::bcjr::BcjrSymbol;
+
+/// Soft-in/soft-out decoder for a rate 1/2 constituent code.
+pub trait SisoDecoder {
+    /// Create a new decoder.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminated` - Whether the trellis is terminated in the zero state.
+    fn new(terminated: bool) -> Self;
+
+    /// Soft decode a block of symbols.
+    ///
+    /// The a-posteriori llr for each input bit is written to `output`.
+    fn decode(&self, input: &[BcjrSymbol], output: &mut [Llr]);
+}
@@ -0,0 +1,323 @@
+use core::marker::PhantomData;
+
+use heapless::Vec;
+
+use crate::Llr;
+
+use super::{
+    bcjr::BcjrSymbol,
+    code::CodeState,
+    trellis::{self, add_compare_select, branch_metric, get_input, get_previous_state},
+    ConvolutionalCode, ConvolutionalCodeExt, SisoDecoder,
+};
+
+/// Soft-Output Viterbi Algorithm (SOVA) decoder.
+///
+/// The decoder finds the maximum likelihood path through the trellis,
+/// and assigns a reliability to each decision using the Hagenauer update rule:
+/// The reliability of a bit is the smallest path metric difference to any competing path
+/// that makes a different decision for that bit.
+/// Each competing path is only traced back over the last [`RELIABILITY_WINDOW`] constraint lengths,
+/// such that the decoding complexity is linear in the block length.
+///
+/// The symbol llr `systematic` is used for output bit 0 and `parity` for output bit 1,
+/// so any rate 1/2 code can be decoded.
+/// The decoder supports codes with up to 64 states, i.e. a constraint length of up to 7.
+pub struct SovaDecoder<C: ConvolutionalCode, const STATES: usize, const MAX_TRELLIS_BITS: usize> {
+    _code: PhantomData<C>,
+    pub terminated: bool,
+}
+
+/// The number of constraint lengths that a competing path is traced back over when updating the reliabilities.
+pub const RELIABILITY_WINDOW: usize = 5;
+
+/// The survivor decisions and path metric differences for all states in a trellis step.
+#[derive(Clone, Copy)]
+struct SovaStep<const STATES: usize> {
+    decisions: u64,
+    deltas: [u8; STATES],
+}
+
+impl<C, const STATES: usize, const MAX_TRELLIS_BITS: usize> SovaDecoder<C, STATES, MAX_TRELLIS_BITS>
+where
+    C: ConvolutionalCode,
+{
+    pub fn new(terminated: bool) -> Self {
+        assert_eq!(2, C::rate().n);
+        assert!(STATES <= 64);
+        assert_eq!(STATES, trellis::state_count(C::mem()));
+        Self {
+            _code: PhantomData,
+            terminated,
+        }
+    }
+
+    /// Soft decode a block of symbols
+    pub fn decode(&self, input: &[BcjrSymbol], output: &mut [Llr]) {
+        assert!(output.len() >= input.len());
+        assert!(
+            input.len() >= (1 + self.terminated as usize) * C::mem(),
+            "The input is not long enough to open and possibly close the trellis"
+        );
+
+        let (steps, metrics) = self.forward_path(input);
+        let final_state = if self.terminated {
+            0
+        } else {
+            trellis::best_state(&metrics)
+        };
+
+        let (states, inputs) = Self::traceback(&steps, final_state);
+        let mut reliability: Vec<u8, MAX_TRELLIS_BITS> = Vec::new();
+        reliability.resize(input.len(), u8::MAX).unwrap();
+
+        // Update the reliabilities from the competing path that merges into the survivor at each step
+        for (index, step) in steps.iter().enumerate() {
+            let state = states[index];
+            let bit = step.decisions & (1 << state) == 0;
+            let competitor = get_previous_state::<C>(state, bit);
+            Self::update_reliability(
+                &steps,
+                &states,
+                &inputs,
+                &mut reliability,
+                index,
+                competitor,
+                state,
+                step.deltas[state],
+            );
+        }
+
+        if !self.terminated {
+            // Any other final state is also a competitor when the trellis is open ended
+            for state in 0..STATES {
+                if state != final_state && metrics[state] > trellis::UNREACHABLE {
+                    let delta = metrics[final_state].abs_diff(metrics[state]);
+                    let delta = delta.min(u8::MAX as u32) as u8;
+                    let index = input.len() - 1;
+                    let bit = steps[index].decisions & (1 << state) != 0;
+                    let competitor = get_previous_state::<C>(state, bit);
+                    Self::update_reliability(
+                        &steps,
+                        &states,
+                        &inputs,
+                        &mut reliability,
+                        index,
+                        competitor,
+                        state,
+                        delta,
+                    );
+                }
+            }
+        }
+
+        for (index, bit) in inputs.iter().enumerate() {
+            let magnitude = reliability[index].min(Llr::MAX as u8) as Llr;
+            output[index] = if *bit { magnitude } else { -magnitude };
+        }
+    }
+
+    fn forward_path(
+        &self,
+        input: &[BcjrSymbol],
+    ) -> (Vec<SovaStep<STATES>, MAX_TRELLIS_BITS>, [i32; STATES]) {
+        let mut steps = Vec::new();
+        let mut metrics = trellis::zero_state_metrics::<STATES>();
+
+        for symbol in input {
+            let llrs = [symbol.systematic, symbol.parity];
+            let mut next = [0; STATES];
+            let mut deltas = [0; STATES];
+            let decisions = add_compare_select::<C, STATES>(
+                &metrics,
+                |current, bit| {
+                    let apriori = if bit { symbol.apriori as i32 } else { 0 };
+                    apriori + branch_metric(C::get_output(current, bit), &llrs)
+                },
+                &mut next,
+                &mut deltas,
+            );
+            steps.push(SovaStep { decisions, deltas }).ok().unwrap();
+            metrics = next;
+        }
+
+        (steps, metrics)
+    }
+
+    /// Trace the survivor path backwards from `final_state`.
+    ///
+    /// Returns the state after each step and the decided input bits.
+    #[allow(clippy::type_complexity)]
+    fn traceback(
+        steps: &[SovaStep<STATES>],
+        final_state: CodeState,
+    ) -> (
+        Vec<CodeState, MAX_TRELLIS_BITS>,
+        Vec<bool, MAX_TRELLIS_BITS>,
+    ) {
+        let mut states = Vec::new();
+        let mut inputs = Vec::new();
+        states.resize(steps.len(), 0).unwrap();
+        inputs.resize(steps.len(), false).unwrap();
+
+        let mut state = final_state;
+        for (index, step) in steps.iter().enumerate().rev() {
+            let previous = get_previous_state::<C>(state, step.decisions & (1 << state) != 0);
+            inputs[index] = get_input::<C>(previous, state);
+            states[index] = state;
+            state = previous;
+        }
+
+        (states, inputs)
+    }
+
+    /// Trace the competing path starting in `competitor` which enters `state` at step `index`,
+    /// and lower the reliability of all bits decided differently than on the survivor path.
+    #[allow(clippy::too_many_arguments)]
+    fn update_reliability(
+        steps: &[SovaStep<STATES>],
+        states: &[CodeState],
+        inputs: &[bool],
+        reliability: &mut [u8],
+        index: usize,
+        competitor: CodeState,
+        state: CodeState,
+        delta: u8,
+    ) {
+        let end = index.saturating_sub(RELIABILITY_WINDOW * C::CONSTRAINT_LENGTH);
+        let mut index = index;
+        let mut current = competitor;
+        let mut next = state;
+
+        loop {
+            if get_input::<C>(current, next) != inputs[index] {
+                reliability[index] = reliability[index].min(delta);
+            }
+
+            if index == end || current == states[index - 1] {
+                // The competing path has merged with the survivor or is outside the window
+                break;
+            }
+
+            index -= 1;
+            next = current;
+            current = get_previous_state::<C>(next, steps[index].decisions & (1 << next) != 0);
+        }
+    }
+}
+
+impl<C, const STATES: usize, const MAX_TRELLIS_BITS: usize> SisoDecoder
+    for SovaDecoder<C, STATES, MAX_TRELLIS_BITS>
+where
+    C: ConvolutionalCode,
+{
+    fn new(terminated: bool) -> Self {
+        SovaDecoder::new(terminated)
+    }
+
+    fn decode(&self, input: &[BcjrSymbol], output: &mut [Llr]) {
+        SovaDecoder::decode(self, input, output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog,
+        convolutional::{bcjr::umts::UmtsBcjrDecoder, ConvolutionalEncoder},
+        LlrMul,
+    };
+
+    use super::*;
+
+    #[test]
+    fn can_decode_byte() {
+        // Given
+        let decoder = SovaDecoder::<catalog::UMTS, 8, 11>::new(true);
+        let input = [
+            BcjrSymbol::new(4, 4),
+            BcjrSymbol::new(4, -4),
+            BcjrSymbol::new(-4, -4),
+            BcjrSymbol::new(4, 4),
+            BcjrSymbol::new(4, 4),
+            BcjrSymbol::new(-4, -4),
+            BcjrSymbol::new(-4, 4),
+            BcjrSymbol::new(4, 4),
+            BcjrSymbol::new(-4, -4),
+            BcjrSymbol::new(-4, -4),
+            BcjrSymbol::new(-4, -4),
+        ];
+        let mut output = [0; 11];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        assert_eq!([24, 24, -24, 24, 24, -24, -24, 24, -24, -24, -24], output);
+    }
+
+    #[test]
+    fn agrees_with_bcjr_on_noisy_input() {
+        // Given
+        let sova = SovaDecoder::<catalog::UMTS, 8, 19>::new(true);
+        let bcjr = UmtsBcjrDecoder::<catalog::UMTS, 19>::new(true);
+        let source = [
+            false, true, true, false, true, false, false, true, true, true, false, false, true,
+            false, true, true,
+        ];
+        let mut encoder = ConvolutionalEncoder::<catalog::UMTS>::default();
+        let mut input = [BcjrSymbol::new(0, 0); 19];
+        for (symbol, bit) in input.iter_mut().zip(source) {
+            let output = encoder.get_output(bit);
+            *symbol = BcjrSymbol::new((output & 1 != 0).mul(4), (output & 2 != 0).mul(4));
+        }
+        for symbol in input.iter_mut().skip(source.len()) {
+            let output = encoder.get_termination_output();
+            *symbol = BcjrSymbol::new((output & 1 != 0).mul(4), (output & 2 != 0).mul(4));
+        }
+
+        // Flip and weaken some of the symbols
+        input[2].systematic = -input[2].systematic;
+        input[9].parity = -input[9].parity;
+        input[13].systematic /= 4;
+        input[13].parity = -input[13].parity / 2;
+        let mut sova_output = [0; 19];
+        let mut bcjr_output = [0; 19];
+
+        // When
+        sova.decode(&input, &mut sova_output);
+        bcjr.decode(&input, &mut bcjr_output);
+
+        // Then
+        for (llr, bit) in sova_output.iter().zip(source) {
+            assert_eq!(bit, *llr > 0);
+        }
+        for (sova, bcjr) in sova_output.iter().zip(bcjr_output) {
+            assert_eq!(bcjr.signum(), sova.signum());
+            assert!(sova.abs() >= bcjr.abs());
+        }
+    }
+
+    #[test]
+    fn can_decode_open_ended_non_systematic_code() {
+        // Given
+        let decoder = SovaDecoder::<catalog::ABRANTES, 4, 8>::new(false);
+        let source = [true, false, true, true, false, false, true, false];
+        let mut encoder = ConvolutionalEncoder::<catalog::ABRANTES>::default();
+        let mut input = [BcjrSymbol::new(0, 0); 8];
+        for (symbol, bit) in input.iter_mut().zip(source) {
+            let output = encoder.get_output(bit);
+            *symbol = BcjrSymbol::new((output & 1 != 0).mul(4), (output & 2 != 0).mul(4));
+        }
+        input[2].parity = -input[2].parity;
+        let mut output = [0; 8];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        for (llr, bit) in output.iter().zip(source) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use core::marker::PhantomData;
+
+use crate::Llr;
+
+use super::{
+    code::CodeState,
+    trellis::{self, add_compare_select, branch_metric, get_input, get_previous_state},
+    ConvolutionalCode, ConvolutionalCodeExt,
+};
+
+/// Streaming Viterbi decoder for continuous rate 1/n streams.
+///
+/// Symbols are pushed one trellis step at a time, and each decided bit is emitted
+/// `TRACEBACK_DEPTH` steps after its symbol was pushed.
+/// The memory used by the decoder is fixed and independent of the stream length.
+/// A traceback depth of about five times the constraint length is usually sufficient.
+pub struct StreamingViterbiDecoder<
+    C: ConvolutionalCode,
+    const STATES: usize,
+    const TRACEBACK_DEPTH: usize,
+> {
+    _code: PhantomData<C>,
+    /// Whether the stream is terminated in the zero state when flushed.
+    pub terminated: bool,
+    metrics: [i32; STATES],
+    /// Ring buffer with the survivor decisions for the most recent trellis steps.
+    decisions: [u64; TRACEBACK_DEPTH],
+    /// The index of the oldest decisions in the ring buffer.
+    head: usize,
+    /// The number of decisions in the ring buffer.
+    len: usize,
+}
+
+impl<C, const STATES: usize, const TRACEBACK_DEPTH: usize>
+    StreamingViterbiDecoder<C, STATES, TRACEBACK_DEPTH>
+where
+    C: ConvolutionalCode,
+{
+    /// Create a new decoder for a stream starting in the zero state.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminated` - Whether the stream is terminated in the zero state when flushed.
+    pub fn new(terminated: bool) -> Self {
+        assert!(STATES <= 64);
+        assert_eq!(STATES, trellis::state_count(C::mem()));
+        assert!(TRACEBACK_DEPTH > 0);
+        Self {
+            _code: PhantomData,
+            terminated,
+            metrics: trellis::zero_state_metrics(),
+            decisions: [0; TRACEBACK_DEPTH],
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Push the `n` llrs for a single trellis step.
+    ///
+    /// The llrs are ordered the same way as the bits in [`EncoderOutput`](super::EncoderOutput).
+    /// The decided bit for the step pushed `TRACEBACK_DEPTH` steps ago is returned
+    /// once the traceback buffer is filled.
+    pub fn push(&mut self, llrs: &[Llr]) -> Option<bool> {
+        assert_eq!(C::rate().n as usize, llrs.len());
+
+        let mut next = [0; STATES];
+        let mut deltas = [0; STATES];
+        let decisions = add_compare_select::<C, STATES>(
+            &self.metrics,
+            |current, bit| branch_metric(C::get_output(current, bit), llrs),
+            &mut next,
+            &mut deltas,
+        );
+        self.metrics = next;
+
+        if self.len < TRACEBACK_DEPTH {
+            self.decisions[(self.head + self.len) % TRACEBACK_DEPTH] = decisions;
+            self.len += 1;
+            return None;
+        }
+
+        // Trace back from the currently best state through the new and all buffered decisions
+        let mut state = trellis::best_state(&self.metrics);
+        state = get_previous_state::<C>(state, decisions & (1 << state) != 0);
+        for offset in (1..TRACEBACK_DEPTH).rev() {
+            let step_decisions = self.decisions[(self.head + offset) % TRACEBACK_DEPTH];
+            state = get_previous_state::<C>(state, step_decisions & (1 << state) != 0);
+        }
+        let oldest = self.decisions[self.head];
+        let previous = get_previous_state::<C>(state, oldest & (1 << state) != 0);
+        let bit = get_input::<C>(previous, state);
+
+        // Replace the oldest decisions with the new
+        self.decisions[self.head] = decisions;
+        self.head = (self.head + 1) % TRACEBACK_DEPTH;
+
+        Some(bit)
+    }
+
+    /// Flush the decoder at the end of the stream.
+    ///
+    /// The remaining decided bits are written to `output` in stream order, and the number of bits is returned.
+    /// The decoder is reset and ready for a new stream afterwards.
+    pub fn flush(&mut self, output: &mut [bool]) -> usize {
+        assert!(output.len() >= self.len);
+
+        let mut state: CodeState = if self.terminated {
+            0
+        } else {
+            trellis::best_state(&self.metrics)
+        };
+        for offset in (0..self.len).rev() {
+            let step_decisions = self.decisions[(self.head + offset) % TRACEBACK_DEPTH];
+            let previous = get_previous_state::<C>(state, step_decisions & (1 << state) != 0);
+            output[offset] = get_input::<C>(previous, state);
+            state = previous;
+        }
+
+        let written = self.len;
+        self.reset();
+        written
+    }
+
+    /// Reset the decoder to the zero state.
+    pub fn reset(&mut self) {
+        self.metrics = trellis::zero_state_metrics();
+        self.head = 0;
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog,
+        convolutional::{ConvolutionalEncoder, EncoderOutput},
+        LlrMul,
+    };
+
+    use super::*;
+
+    #[test]
+    fn can_decode_stream() {
+        // Given
+        let mut decoder = StreamingViterbiDecoder::<catalog::MIOTY, 64, 35>::new(true);
+        let mut encoder = ConvolutionalEncoder::<catalog::MIOTY>::default();
+        let source: Vec<bool> = (0..500).map(|i| (i * 13 + i / 7) % 3 == 0).collect();
+        let mut encoded = [0; 500];
+        let mut termination = [0; 6];
+        encoder.encode(&source[..], &mut encoded);
+        encoder.flush(&mut termination);
+
+        let mut decoded = Vec::new();
+
+        // When
+        for (index, output) in encoded.iter().chain(termination.iter()).enumerate() {
+            let mut llrs = to_llrs(*output);
+            if index % 50 == 3 {
+                llrs[1] = -llrs[1];
+            }
+            if let Some(bit) = decoder.push(&llrs) {
+                decoded.push(bit);
+            }
+        }
+        assert_eq!(500 + 6 - 35, decoded.len());
+        let mut remaining = [false; 35];
+        assert_eq!(35, decoder.flush(&mut remaining));
+        decoded.extend_from_slice(&remaining);
+
+        // Then
+        assert_eq!(source, decoded[..500]);
+        assert_eq!([false; 6], decoded[500..]);
+    }
+
+    #[test]
+    fn can_flush_partially_filled_decoder() {
+        // Given
+        let mut decoder = StreamingViterbiDecoder::<catalog::ABRANTES, 4, 16>::new(false);
+        let mut encoder = ConvolutionalEncoder::<catalog::ABRANTES>::default();
+        let source = [true, true, false, true, false];
+        let mut encoded = [0; 5];
+        encoder.encode(&source, &mut encoded);
+
+        // When
+        for output in encoded {
+            assert_eq!(None, decoder.push(&to_llrs(output)[..2]));
+        }
+        let mut decoded = [false; 16];
+        let written = decoder.flush(&mut decoded);
+
+        // Then
+        assert_eq!(5, written);
+        assert_eq!(source, decoded[..5]);
+    }
+
+    fn to_llrs(output: EncoderOutput) -> [Llr; 3] {
+        [
+            (output & 1 != 0).mul(8),
+            (output & 2 != 0).mul(8),
+            (output & 4 != 0).mul(8),
+        ]
+    }
+}
@@ -0,0 +1,239 @@
+use core::marker::PhantomData;
+
+use bitvec::prelude::*;
+
+use super::{code::CodeState, ConvolutionalCode, ConvolutionalCodeExt, EncoderOutput};
+
+/// Table driven convolutional encoder that encodes a byte at a time.
+///
+/// The output and next state for every state and input byte is computed at compile time,
+/// so the tables hold `STATES * 256 * (N + 1)` bytes, e.g. 48 KiB for a rate 1/2 code with constraint length 7.
+/// Input bits are consumed msb first, and the `N` output bits for each input bit are emitted in generator order,
+/// so each input byte produces `N` output bytes for a rate 1/N code.
+/// The output is identical to that of [`ConvolutionalEncoder`](super::ConvolutionalEncoder).
+pub struct TableEncoder<C: ConvolutionalCode, const STATES: usize, const N: usize> {
+    _code: PhantomData<C>,
+    state: CodeState,
+}
+
+struct Table<C: ConvolutionalCode, const STATES: usize, const N: usize> {
+    _code: PhantomData<C>,
+}
+
+impl<C: ConvolutionalCode, const STATES: usize, const N: usize> Table<C, STATES, N> {
+    /// The msb first output bytes for each state and input byte.
+    const OUTPUT: &'static [[[u8; N]; 256]; STATES] = &Self::create().0;
+
+    /// The next state for each state and input byte.
+    const NEXT_STATE: &'static [[u8; 256]; STATES] = &Self::create().1;
+
+    /// Create the byte tables by combining the outputs for the two nibbles in each byte.
+    const fn create() -> ([[[u8; N]; 256]; STATES], [[u8; 256]; STATES]) {
+        let (nibble_output, nibble_next_state) = Self::create_nibble();
+        let mut output = [[[0; N]; 256]; STATES];
+        let mut next_state = [[0; 256]; STATES];
+
+        let mut state = 0;
+        while state < STATES {
+            let mut byte = 0;
+            while byte < 256 {
+                let high = byte >> 4;
+                let low = byte & 0x0F;
+                let middle = nibble_next_state[state][high];
+                let encoded = (nibble_output[state][high] << (4 * N)) | nibble_output[middle][low];
+                let mut index = 0;
+                while index < N {
+                    output[state][byte][index] = (encoded >> (8 * (N - 1 - index))) as u8;
+                    index += 1;
+                }
+                next_state[state][byte] = nibble_next_state[middle][low] as u8;
+                byte += 1;
+            }
+            state += 1;
+        }
+
+        (output, next_state)
+    }
+
+    const fn create_nibble() -> ([[u64; 16]; STATES], [[CodeState; 16]; STATES]) {
+        let mut output = [[0; 16]; STATES];
+        let mut next_state = [[0; 16]; STATES];
+
+        let mut state = 0;
+        while state < STATES {
+            let mut nibble = 0;
+            while nibble < 16 {
+                let mut current = state;
+                let mut nibble_output = 0;
+                let mut bit = 0;
+                while bit < 4 {
+                    let input = nibble & (0x08 >> bit) != 0;
+                    let step_output = C::get_output(current, input);
+
+                    // Append the output bits in generator order
+                    let mut index = 0;
+                    while index < N {
+                        nibble_output = (nibble_output << 1) | ((step_output >> index) & 1) as u64;
+                        index += 1;
+                    }
+
+                    current = C::get_next_state(current, input);
+                    bit += 1;
+                }
+                output[state][nibble] = nibble_output;
+                next_state[state][nibble] = current;
+                nibble += 1;
+            }
+            state += 1;
+        }
+
+        (output, next_state)
+    }
+}
+
+impl<C: ConvolutionalCode, const STATES: usize, const N: usize> TableEncoder<C, STATES, N> {
+    pub fn new() -> Self {
+        assert_eq!(N, C::GENERATORS.len());
+        assert!(N <= 8);
+        assert!(STATES <= 256);
+        assert_eq!(STATES, 1 << C::mem());
+        Self {
+            _code: PhantomData,
+            state: 0,
+        }
+    }
+
+    /// Encode packed bytes.
+    ///
+    /// The encoder state is kept between calls, so a stream can be encoded in arbitrary chunks.
+    /// `N` output bytes are written for each input byte.
+    pub fn encode_bytes(&mut self, input: &[u8], output: &mut [u8]) {
+        assert!(output.len() >= N * input.len());
+
+        for (byte, output) in input.iter().zip(output.chunks_exact_mut(N)) {
+            output.copy_from_slice(&Table::<C, STATES, N>::OUTPUT[self.state][*byte as usize]);
+            self.state = Table::<C, STATES, N>::NEXT_STATE[self.state][*byte as usize] as CodeState;
+        }
+    }
+
+    /// Encode a bit slice.
+    ///
+    /// Whole bytes are encoded using the table and any remaining bits are encoded one bit at a time.
+    /// `N` output bits are written for each input bit.
+    pub fn encode_bits(&mut self, input: &BitSlice<u8, Msb0>, output: &mut BitSlice<u8, Msb0>) {
+        assert!(output.len() >= N * input.len());
+
+        let mut bytes = input.chunks_exact(8);
+        let mut outputs = output.chunks_exact_mut(8 * N);
+        for (byte, output) in (&mut bytes).zip(&mut outputs) {
+            let byte: u8 = byte.load_be();
+            let encoded = &Table::<C, STATES, N>::OUTPUT[self.state][byte as usize];
+            self.state = Table::<C, STATES, N>::NEXT_STATE[self.state][byte as usize] as CodeState;
+            output.clone_from_bitslice(encoded.view_bits::<Msb0>());
+        }
+
+        let remainder = bytes.remainder();
+        let output = &mut output[N * (input.len() - remainder.len())..];
+        for (input, output) in remainder.iter().zip(output.chunks_exact_mut(N)) {
+            let encoded = C::get_output(self.state, *input);
+            self.state = C::get_next_state(self.state, *input);
+            for (index, mut bit) in output.iter_mut().enumerate() {
+                *bit = encoded & (1 << index) != 0;
+            }
+        }
+    }
+
+    /// Get the next encoder termination
+    pub fn get_termination_output(&mut self) -> EncoderOutput {
+        let input = C::get_termination_input(self.state);
+        let output = C::get_output(self.state, input);
+        self.state = C::get_next_state(self.state, input);
+        output
+    }
+}
+
+impl<C: ConvolutionalCode, const STATES: usize, const N: usize> Default
+    for TableEncoder<C, STATES, N>
+{
+    fn default() -> Self {
+        TableEncoder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{catalog, convolutional::ConvolutionalEncoder};
+
+    use super::*;
+
+    #[test]
+    fn is_identical_to_bit_serial_encoder() {
+        is_identical_to_bit_serial_encoder_case::<catalog::ABRANTES, 4, 2>();
+        is_identical_to_bit_serial_encoder_case::<catalog::UMTS, 8, 2>();
+        is_identical_to_bit_serial_encoder_case::<catalog::MIOTY, 64, 3>();
+        is_identical_to_bit_serial_encoder_case::<catalog::LTE_TBCC, 64, 3>();
+        is_identical_to_bit_serial_encoder_case::<catalog::CCSDS, 64, 2>();
+        is_identical_to_bit_serial_encoder_case::<catalog::CCSDS_PUNCTURED, 64, 2>();
+        is_identical_to_bit_serial_encoder_case::<catalog::CDMA2000, 8, 3>();
+    }
+
+    fn is_identical_to_bit_serial_encoder_case<
+        C: ConvolutionalCode,
+        const STATES: usize,
+        const N: usize,
+    >() {
+        // Given
+        let n = C::rate().n as usize;
+        let input: Vec<u8> = (0..32u32).map(|i| (i * 151 + 17) as u8).collect();
+        let mut serial_encoder = ConvolutionalEncoder::<C>::default();
+        let mut expected = Vec::new();
+        for bit in input.view_bits::<Msb0>() {
+            push_output(&mut expected, serial_encoder.get_output(*bit), n);
+        }
+        let mut encoder = TableEncoder::<C, STATES, N>::new();
+        let mut output = vec![0; n * input.len()];
+
+        // When
+        encoder.encode_bytes(&input[..20], &mut output[..20 * n]);
+        encoder.encode_bytes(&input[20..], &mut output[20 * n..]);
+
+        // Then
+        let output: Vec<bool> = output.view_bits::<Msb0>().iter().map(|bit| *bit).collect();
+        assert_eq!(expected, output);
+        for _ in 0..C::mem() {
+            assert_eq!(
+                serial_encoder.get_termination_output(),
+                encoder.get_termination_output()
+            );
+        }
+    }
+
+    #[test]
+    fn can_encode_unaligned_bits() {
+        // Given
+        type Code = catalog::UMTS;
+        let input = [0xA5u8, 0x3C, 0xF0];
+        let input = &input.view_bits::<Msb0>()[3..22];
+        let mut serial_encoder = ConvolutionalEncoder::<Code>::default();
+        let mut expected = Vec::new();
+        for bit in input {
+            push_output(&mut expected, serial_encoder.get_output(*bit), 2);
+        }
+        let mut encoder = TableEncoder::<Code, 8, 2>::new();
+        let mut output = [0u8; 5];
+        let output = &mut output.view_bits_mut::<Msb0>()[1..1 + 2 * input.len()];
+
+        // When
+        encoder.encode_bits(input, output);
+
+        // Then
+        let output: Vec<bool> = output.iter().map(|bit| *bit).collect();
+        assert_eq!(expected, output);
+    }
+
+    fn push_output(bits: &mut Vec<bool>, output: EncoderOutput, n: usize) {
+        for index in 0..n {
+            bits.push(output & (1 << index) != 0);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::Llr;
+
+use super::{code::CodeState, ConvolutionalCode, ConvolutionalCodeExt, EncoderOutput};
+
+/// The path metric assigned to states that cannot be reached.
+pub(crate) const UNREACHABLE: i32 = i32::MIN / 4;
+
+/// The number of states in the trellis of the code.
+pub(crate) const fn state_count(mem: usize) -> usize {
+    1 << mem
+}
+
+/// The connections between the states in the trellis of a code.
+#[derive(Clone, Copy)]
+pub(crate) struct Trellis {
+    mem: usize,
+    feedback: usize,
+}
+
+impl Trellis {
+    pub(crate) const fn new(mem: usize, feedback: usize) -> Self {
+        Self { mem, feedback }
+    }
+
+    pub(crate) fn of<C: ConvolutionalCode>() -> Self {
+        Self::new(C::mem(), C::FEEDBACK)
+    }
+
+    /// The memory in the code.
+    pub(crate) const fn mem(&self) -> usize {
+        self.mem
+    }
+
+    /// The number of states in the trellis.
+    pub(crate) const fn states(&self) -> usize {
+        state_count(self.mem)
+    }
+
+    /// Get the state preceding `next` given the `bit` that was shifted out of the register.
+    pub(crate) fn previous_state(&self, next: CodeState, bit: bool) -> CodeState {
+        let mask = self.states() - 1;
+        ((next << 1) & mask) | bit as CodeState
+    }
+
+    /// Get the input bit that moves the encoder from `current` to `next`.
+    pub(crate) fn input(&self, current: CodeState, next: CodeState) -> bool {
+        let newest = next >> (self.mem - 1);
+        let feedback = (current & self.feedback).count_ones() as usize & 1;
+        (newest ^ feedback) != 0
+    }
+
+    /// Run a single add-compare-select step of the Viterbi algorithm, see [`add_compare_select`].
+    ///
+    /// Only the first [`Trellis::states`] entries of the slices are used.
+    pub(crate) fn add_compare_select(
+        &self,
+        metrics: &[i32],
+        mut branch: impl FnMut(CodeState, bool) -> i32,
+        next: &mut [i32],
+        deltas: &mut [u8],
+    ) -> u64 {
+        let states = self.states();
+        let mut decisions = 0;
+        let mut best = UNREACHABLE;
+
+        for state in 0..states {
+            let previous0 = self.previous_state(state, false);
+            let previous1 = self.previous_state(state, true);
+            let metric0 =
+                metrics[previous0].saturating_add(branch(previous0, self.input(previous0, state)));
+            let metric1 =
+                metrics[previous1].saturating_add(branch(previous1, self.input(previous1, state)));
+
+            if metric1 > metric0 {
+                decisions |= 1 << state;
+                next[state] = metric1;
+            } else {
+                next[state] = metric0;
+            }
+
+            deltas[state] = metric0.abs_diff(metric1).min(u8::MAX as u32) as u8;
+            best = best.max(next[state]);
+        }
+
+        for metric in next[..states].iter_mut() {
+            *metric = metric.saturating_sub(best).max(UNREACHABLE);
+        }
+
+        decisions
+    }
+}
+
+/// Get the state preceding `next` given the `bit` that was shifted out of the register.
+pub(crate) fn get_previous_state<C: ConvolutionalCode>(next: CodeState, bit: bool) -> CodeState {
+    Trellis::of::<C>().previous_state(next, bit)
+}
+
+/// Get the input bit that moves the encoder from `current` to `next`.
+pub(crate) fn get_input<C: ConvolutionalCode>(current: CodeState, next: CodeState) -> bool {
+    Trellis::of::<C>().input(current, next)
+}
+
+/// Get the correlation between the encoder `output` and the received llrs,
+/// i.e. the sum of all llrs for which the corresponding output bit is 1.
+pub(crate) fn branch_metric(output: EncoderOutput, llrs: &[Llr]) -> i32 {
+    let mut metric = 0;
+    for (index, llr) in llrs.iter().enumerate() {
+        if output & (1 << index) != 0 {
+            metric += *llr as i32;
+        }
+    }
+    metric
+}
+
+/// Run a single add-compare-select step of the Viterbi algorithm.
+///
+/// The metric for each transition is given by `branch(current, input)`.
+/// The path metrics are normalized so that the best state has metric 0,
+/// and the metric difference between the survivor and the discarded path is written to `deltas`.
+/// Bit `s` in the returned decision word is the bit shifted out of the surviving predecessor of state `s`.
+pub(crate) fn add_compare_select<C, const STATES: usize>(
+    metrics: &[i32; STATES],
+    branch: impl FnMut(CodeState, bool) -> i32,
+    next: &mut [i32; STATES],
+    deltas: &mut [u8; STATES],
+) -> u64
+where
+    C: ConvolutionalCode,
+{
+    Trellis::of::<C>().add_compare_select(metrics, branch, next, deltas)
+}
+
+/// Get the index of the state with the largest path metric.
+pub(crate) fn best_state(metrics: &[i32]) -> CodeState {
+    let mut best = 0;
+    for (state, metric) in metrics.iter().enumerate() {
+        if *metric > metrics[best] {
+            best = state;
+        }
+    }
+    best
+}
+
+/// Get the initial path metrics where only the zero state is reachable.
+pub(crate) fn zero_state_metrics<const STATES: usize>() -> [i32; STATES] {
+    let mut metrics = [UNREACHABLE; STATES];
+    metrics[0] = 0;
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog;
+
+    use super::*;
+
+    #[test]
+    fn can_walk_trellis_backwards() {
+        // Given
+        type Code = catalog::UMTS;
+
+        // When
+
+        // Then
+        for current in 0..8 {
+            for input in [false, true] {
+                let next = Code::get_next_state(current, input);
+                let bit = current & 1 != 0;
+
+                assert_eq!(current, get_previous_state::<Code>(next, bit));
+                assert_eq!(input, get_input::<Code>(current, next));
+            }
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use core::marker::PhantomData;
+
+use heapless::Vec;
+
+use crate::Llr;
+
+use super::{
+    code::CodeState,
+    trellis::{self, branch_metric, Trellis},
+    ConvolutionalCode, ConvolutionalCodeExt, DynConvolutionalCode, EncoderOutput, Termination,
+};
+
+/// The maximum number of passes through the block when decoding a tail-biting trellis.
+const MAX_WRAP_AROUND_ITERATIONS: usize = 4;
+
+/// Viterbi decoder for rate 1/n codes.
+///
+/// The decoder supports codes with up to 64 states, i.e. a constraint length of up to 7.
+pub struct ViterbiDecoder<C: ConvolutionalCode, const STATES: usize, const MAX_TRELLIS_BITS: usize>
+{
+    _code: PhantomData<C>,
+    pub termination: Termination,
+}
+
+impl<C, const STATES: usize, const MAX_TRELLIS_BITS: usize>
+    ViterbiDecoder<C, STATES, MAX_TRELLIS_BITS>
+where
+    C: ConvolutionalCode,
+{
+    /// Create a new decoder for a trellis starting in the zero state.
+    ///
+    /// # Arguments
+    ///
+    /// * `terminated` - Whether the trellis is terminated in the zero state.
+    pub fn new(terminated: bool) -> Self {
+        assert!(STATES <= 64);
+        assert_eq!(STATES, trellis::state_count(C::mem()));
+        Self {
+            _code: PhantomData,
+            termination: Termination::new(terminated),
+        }
+    }
+
+    /// Create a new decoder for a tail-biting trellis.
+    ///
+    /// The decoder uses the wrap-around Viterbi algorithm, where the final path metrics
+    /// are used as the initial path metrics for the next pass until the survivor path is tail-biting.
+    pub fn new_tail_biting() -> Self {
+        Self {
+            termination: Termination::TailBiting,
+            ..Self::new(false)
+        }
+    }
+
+    /// Hard decode a block of llrs.
+    ///
+    /// There are `n` llrs for each trellis step for a rate 1/n code,
+    /// ordered the same way as the bits in [`EncoderOutput`](super::EncoderOutput).
+    /// The decoded bit for each trellis step, including any termination, is written to `output`.
+    pub fn decode(&self, input: &[Llr], output: &mut [bool]) {
+        decode::<STATES, MAX_TRELLIS_BITS>(
+            Trellis::of::<C>(),
+            C::rate().n as usize,
+            C::get_output,
+            self.termination,
+            input,
+            output,
+        );
+    }
+}
+
+/// Viterbi decoder for a [`DynConvolutionalCode`], see [`ViterbiDecoder`].
+///
+/// The decoder supports codes with up to `MAX_STATES` states, which must be at most 64.
+pub struct DynViterbiDecoder<const MAX_STATES: usize, const MAX_TRELLIS_BITS: usize> {
+    code: DynConvolutionalCode,
+    pub termination: Termination,
+}
+
+impl<const MAX_STATES: usize, const MAX_TRELLIS_BITS: usize>
+    DynViterbiDecoder<MAX_STATES, MAX_TRELLIS_BITS>
+{
+    /// Create a new decoder for a trellis starting in the zero state.
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The code.
+    /// * `terminated` - Whether the trellis is terminated in the zero state.
+    pub fn new(code: DynConvolutionalCode, terminated: bool) -> Self {
+        assert!(MAX_STATES <= 64);
+        assert!(trellis::state_count(code.mem()) <= MAX_STATES);
+        Self {
+            code,
+            termination: Termination::new(terminated),
+        }
+    }
+
+    /// Create a new decoder for a tail-biting trellis, see [`ViterbiDecoder::new_tail_biting`].
+    pub fn new_tail_biting(code: DynConvolutionalCode) -> Self {
+        Self {
+            termination: Termination::TailBiting,
+            ..Self::new(code, false)
+        }
+    }
+
+    /// The code of the decoder.
+    pub fn code(&self) -> &DynConvolutionalCode {
+        &self.code
+    }
+
+    /// Hard decode a block of llrs, see [`ViterbiDecoder::decode`].
+    pub fn decode(&self, input: &[Llr], output: &mut [bool]) {
+        decode::<MAX_STATES, MAX_TRELLIS_BITS>(
+            Trellis::new(self.code.mem(), self.code.feedback()),
+            self.code.rate().n as usize,
+            |current, input| self.code.get_output(current, input),
+            self.termination,
+            input,
+            output,
+        );
+    }
+}
+
+/// Hard decode a block of llrs for a code with at most `STATES` states.
+fn decode<const STATES: usize, const MAX_TRELLIS_BITS: usize>(
+    trellis: Trellis,
+    n: usize,
+    get_output: impl Fn(CodeState, bool) -> EncoderOutput,
+    termination: Termination,
+    input: &[Llr],
+    output: &mut [bool],
+) {
+    let states = trellis.states();
+    assert_eq!(0, input.len() % n);
+    let symbol_count = input.len() / n;
+    assert!(output.len() >= symbol_count);
+    assert!(
+        symbol_count >= termination.min_steps(trellis.mem()),
+        "The input is not long enough to open and possibly close the trellis"
+    );
+
+    let mut decisions = Vec::<u64, MAX_TRELLIS_BITS>::new();
+
+    if termination == Termination::TailBiting {
+        // All states are equally likely at the beginning of the first pass
+        let mut metrics = [0; STATES];
+
+        for _ in 0..MAX_WRAP_AROUND_ITERATIONS {
+            let initial_metrics = metrics;
+            decisions.clear();
+            metrics = forward_path(
+                trellis,
+                n,
+                &get_output,
+                input,
+                initial_metrics,
+                &mut decisions,
+            );
+
+            let final_state = trellis::best_state(&metrics[..states]);
+            let initial_state = traceback(trellis, &decisions, final_state, output);
+            if initial_state == final_state {
+                break;
+            }
+        }
+    } else {
+        let metrics = forward_path(
+            trellis,
+            n,
+            &get_output,
+            input,
+            trellis::zero_state_metrics::<STATES>(),
+            &mut decisions,
+        );
+        let final_state = if termination.is_terminated() {
+            0
+        } else {
+            trellis::best_state(&metrics[..states])
+        };
+        traceback(trellis, &decisions, final_state, output);
+    }
+}
+
+fn forward_path<const STATES: usize, const MAX_TRELLIS_BITS: usize>(
+    trellis: Trellis,
+    n: usize,
+    get_output: impl Fn(CodeState, bool) -> EncoderOutput,
+    input: &[Llr],
+    initial_metrics: [i32; STATES],
+    decisions: &mut Vec<u64, MAX_TRELLIS_BITS>,
+) -> [i32; STATES] {
+    let mut metrics = initial_metrics;
+
+    for llrs in input.chunks_exact(n) {
+        let mut next = [0; STATES];
+        let mut deltas = [0; STATES];
+        let step_decisions = trellis.add_compare_select(
+            &metrics,
+            |current, bit| branch_metric(get_output(current, bit), llrs),
+            &mut next,
+            &mut deltas,
+        );
+        decisions.push(step_decisions).unwrap();
+        metrics = next;
+    }
+
+    metrics
+}
+
+/// Trace the survivor path backwards from `final_state` and return its initial state.
+fn traceback(
+    trellis: Trellis,
+    decisions: &[u64],
+    final_state: CodeState,
+    output: &mut [bool],
+) -> CodeState {
+    let mut state = final_state;
+    for (index, step_decisions) in decisions.iter().enumerate().rev() {
+        let previous = trellis.previous_state(state, step_decisions & (1 << state) != 0);
+        output[index] = trellis.input(previous, state);
+        state = previous;
+    }
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog,
+        convolutional::{ConvolutionalEncoder, DynConvolutionalEncoder},
+        LlrMul,
+    };
+
+    use super::*;
+
+    #[test]
+    fn can_decode_terminated() {
+        // Given
+        let decoder = ViterbiDecoder::<catalog::MIOTY, 64, 22>::new(true);
+        let source = [
+            true, false, true, true, false, false, true, false, false, false, true, true, true,
+            false, true, false,
+        ];
+        let mut encoder = ConvolutionalEncoder::<catalog::MIOTY>::default();
+        let mut input = std::vec::Vec::new();
+        for bit in source {
+            push_bpsk(&mut input, encoder.get_output(bit), 3);
+        }
+        for _ in 0..catalog::MIOTY::mem() {
+            push_bpsk(&mut input, encoder.get_termination_output(), 3);
+        }
+        input[4] = -input[4];
+        input[20] = -input[20];
+        let mut output = [true; 22];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        assert_eq!(source, output[..16]);
+        assert_eq!([false; 6], output[16..]);
+    }
+
+    #[test]
+    fn can_decode_tail_biting() {
+        // Given
+        let decoder = ViterbiDecoder::<catalog::LTE_TBCC, 64, 40>::new_tail_biting();
+        let source: std::vec::Vec<bool> = (0..40).map(|i| (i * 7 + i / 3) % 5 < 2).collect();
+        let mut encoder =
+            ConvolutionalEncoder::<catalog::LTE_TBCC>::new_tail_biting(&source[..]).unwrap();
+        let mut input = std::vec::Vec::new();
+        for bit in source.iter() {
+            push_bpsk(&mut input, encoder.get_output(*bit), 3);
+        }
+        input[0] = -input[0];
+        input[1] = -input[1];
+        input[60] = -input[60];
+        input[119] = -input[119];
+        let mut output = [false; 40];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        assert_eq!(source, output);
+    }
+
+    #[test]
+    fn can_decode_dyn_code() {
+        // Given
+        let code = DynConvolutionalCode::from_octal("171,133").unwrap();
+        let decoder = DynViterbiDecoder::<64, 22>::new(code.clone(), true);
+        let source = [
+            true, false, true, true, false, false, true, false, false, false, true, true, true,
+            false, true, false,
+        ];
+        let mut encoder = DynConvolutionalEncoder::new(code);
+        let mut outputs = [0; 22];
+        encoder.encode(&source, &mut outputs);
+        encoder.flush(&mut outputs[16..]);
+        let mut input = std::vec::Vec::new();
+        for output in outputs {
+            push_bpsk(&mut input, output, 2);
+        }
+        input[3] = -input[3];
+        input[20] = -input[20];
+        let mut output = [true; 22];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        assert_eq!(source, output[..16]);
+        assert_eq!([false; 6], output[16..]);
+    }
+
+    #[test]
+    fn can_decode_dyn_code_with_fewer_states() {
+        // Given
+        let code = DynConvolutionalCode::from_octal("[1, 15/13]").unwrap();
+        let decoder = DynViterbiDecoder::<64, 40>::new_tail_biting(code.clone());
+        let source: std::vec::Vec<bool> = (0..40).map(|i| (i * 7 + i / 3) % 5 < 2).collect();
+        let mut encoder = DynConvolutionalEncoder::new_tail_biting(code, &source[..]).unwrap();
+        let mut input = std::vec::Vec::new();
+        for bit in source.iter() {
+            push_bpsk(&mut input, encoder.get_output(*bit), 2);
+        }
+        input[11] = -input[11];
+        input[50] = -input[50];
+        let mut output = [false; 40];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        assert_eq!(source, output);
+    }
+
+    fn push_bpsk(input: &mut std::vec::Vec<Llr>, output: usize, n: usize) {
+        for index in 0..n {
+            input.push((output & (1 << index) != 0).mul(8));
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use bitvec::prelude::*;
+
+/// Bitwise cyclic redundancy check with no output inversion,
+/// as used for the `3GPP` transport block and code block CRCs.
+///
+/// The initial value is zero unless set with [`Crc::with_leading_ones`].
+#[derive(Clone, Copy, Debug)]
+pub struct Crc {
+    width: usize,
+    polynomial: u32,
+    initial: u32,
+}
+
+impl Crc {
+    /// Create a new crc
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The number of crc bits, at most 32.
+    /// * `polynomial` - The generator polynomial without the `D^width` term, e.g. `0x21` for D^6+D^5+1.
+    pub const fn new(width: usize, polynomial: u32) -> Self {
+        assert!(width > 0 && width <= 32);
+        Self {
+            width,
+            polynomial,
+            initial: 0,
+        }
+    }
+
+    /// Get the crc computed as if `width` ones preceded the bits,
+    /// e.g. for the NR DCI crc per 38.212 §7.3.2.
+    pub const fn with_leading_ones(self) -> Self {
+        let mut initial = 0u32;
+        let mut index = 0;
+        while index < self.width {
+            let feedback = initial & (1 << (self.width - 1)) == 0;
+            initial = (initial << 1) & self.mask();
+            if feedback {
+                initial ^= self.polynomial;
+            }
+            index += 1;
+        }
+        Self { initial, ..self }
+    }
+
+    /// The number of crc bits.
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Compute the crc of the bits.
+    pub fn compute(&self, bits: &BitSlice<u8, Msb0>) -> u32 {
+        let top = 1 << (self.width - 1);
+        let mask = self.mask();
+
+        let mut crc = self.initial;
+        for bit in bits.iter().by_vals() {
+            let feedback = (crc & top != 0) ^ bit;
+            crc = (crc << 1) & mask;
+            if feedback {
+                crc ^= self.polynomial;
+            }
+        }
+        crc
+    }
+
+    /// Write the crc of the first `bits.len() - width` bits to the last `width` bits, msb first.
+    pub fn append(&self, bits: &mut BitSlice<u8, Msb0>) {
+        let payload_len = bits.len() - self.width;
+        let crc = self.compute(&bits[..payload_len]);
+        for index in 0..self.width {
+            bits.set(
+                payload_len + index,
+                crc & (1 << (self.width - 1 - index)) != 0,
+            );
+        }
+    }
+
+    /// Get whether the last `width` bits are the crc of the preceding bits.
+    pub fn check(&self, bits: &BitSlice<u8, Msb0>) -> bool {
+        self.compute(bits) == 0
+    }
+
+    const fn mask(&self) -> u32 {
+        if self.width == 32 {
+            u32::MAX
+        } else {
+            (1 << self.width) - 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog;
+
+    use super::*;
+
+    #[test]
+    fn can_compute() {
+        // Given
+        let crc = catalog::NR_CRC16;
+
+        // When
+        let value = crc.compute(b"123456789".view_bits());
+
+        // Then
+        // The CRC-16/XMODEM check value
+        assert_eq!(0x31C3, value);
+    }
+
+    #[test]
+    fn can_check() {
+        // Given
+        let crc = catalog::NR_CRC24C;
+        let mut bits = [0x12, 0x34, 0x56, 0x78, 0x9A, 0, 0, 0];
+
+        // When
+        crc.append(bits.view_bits_mut());
+
+        // Then
+        assert!(crc.check(bits.view_bits()));
+        bits[2] ^= 0x04;
+        assert!(!crc.check(bits.view_bits()));
+    }
+
+    #[test]
+    fn can_compute_with_leading_ones() {
+        // Given
+        let crc = catalog::NR_CRC24C.with_leading_ones();
+        let mut bits = [0x12, 0x34, 0x56, 0x78, 0x9A, 0, 0, 0];
+
+        // When
+        crc.append(bits.view_bits_mut());
+
+        // Then
+        let prepended = [0xFF, 0xFF, 0xFF, 0x12, 0x34, 0x56, 0x78, 0x9A];
+        let value = catalog::NR_CRC24C.compute(prepended.view_bits());
+        assert_eq!(value, crc.compute(bits[..5].view_bits()));
+        assert_ne!(value, catalog::NR_CRC24C.compute(bits[..5].view_bits()));
+        assert!(crc.check(bits.view_bits()));
+        bits[2] ^= 0x04;
+        assert!(!crc.check(bits.view_bits()));
+    }
+}
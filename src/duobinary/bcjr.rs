@@ -0,0 +1,182 @@
+use heapless::Vec;
+
+use crate::Llr;
+
+use super::{
+    code::{self, Couple, STATES},
+    DuoBinarySymbol,
+};
+
+/// The metric for an unreachable state.
+const UNREACHABLE: i32 = i32::MIN / 4;
+
+/// Symbol level (4-ary) max-log BCJR decoder for the circular duo-binary CRSC code.
+///
+/// The decoder emits the a-posteriori llrs of the couples `01`, `10` and `11` relative to `00` for each couple.
+/// The trellis is circular, so the initial forward and backward state metrics are estimated by
+/// wrapping around the block once before the actual decoding.
+pub struct DuoBinaryBcjrDecoder<const MAX_COUPLES: usize>;
+
+impl<const MAX_COUPLES: usize> DuoBinaryBcjrDecoder<MAX_COUPLES> {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Soft decode a block of symbols.
+    pub fn decode(&self, input: &[DuoBinarySymbol], output: &mut [[Llr; 3]]) {
+        assert!(output.len() >= input.len());
+
+        // Estimate the circulation state metrics
+        let mut alpha = [0; STATES];
+        for symbol in input {
+            alpha = Self::get_next_alpha(&alpha, symbol);
+        }
+        let mut beta = [0; STATES];
+        for symbol in input.iter().rev() {
+            beta = Self::get_previous_beta(&beta, symbol);
+        }
+
+        // Forward path
+        let mut alphas = Vec::<[i32; STATES], MAX_COUPLES>::new();
+        for symbol in input {
+            alphas.push(alpha).unwrap();
+            alpha = Self::get_next_alpha(&alpha, symbol);
+        }
+
+        // Backward path
+        for (index, symbol) in input.iter().enumerate().rev() {
+            let alpha = &alphas[index];
+            let mut metrics = [UNREACHABLE; 4];
+            for (state, a) in alpha.iter().enumerate() {
+                for (couple, max) in metrics.iter_mut().enumerate() {
+                    let next = code::next_state(state, couple);
+                    *max = (*max).max(a + Self::get_gamma(state, couple, symbol) + beta[next]);
+                }
+            }
+
+            for (llr, metric) in output[index].iter_mut().zip(&metrics[1..]) {
+                *llr = (metric - metrics[0]).clamp(Llr::MIN as i32, Llr::MAX as i32) as Llr;
+            }
+
+            beta = Self::get_previous_beta(&beta, symbol);
+        }
+    }
+
+    /// Get the branch metric for the transition from `state` given `couple`.
+    fn get_gamma(state: usize, couple: Couple, symbol: &DuoBinarySymbol) -> i32 {
+        let output = code::output(state, couple);
+        let mut gamma = get_systematic(couple, symbol);
+        if couple != 0 {
+            gamma += symbol.apriori[couple - 1] as i32;
+        }
+        if output & 1 != 0 {
+            gamma += symbol.y as i32;
+        }
+        if output & 2 != 0 {
+            gamma += symbol.w as i32;
+        }
+        gamma
+    }
+
+    fn get_next_alpha(alpha: &[i32; STATES], symbol: &DuoBinarySymbol) -> [i32; STATES] {
+        let mut next = [UNREACHABLE; STATES];
+        for (state, a) in alpha.iter().enumerate() {
+            for couple in 0..4 {
+                let metric = a + Self::get_gamma(state, couple, symbol);
+                let next = &mut next[code::next_state(state, couple)];
+                *next = (*next).max(metric);
+            }
+        }
+        normalize(next)
+    }
+
+    fn get_previous_beta(beta: &[i32; STATES], symbol: &DuoBinarySymbol) -> [i32; STATES] {
+        let mut previous = [UNREACHABLE; STATES];
+        for (state, previous) in previous.iter_mut().enumerate() {
+            for couple in 0..4 {
+                let metric =
+                    beta[code::next_state(state, couple)] + Self::get_gamma(state, couple, symbol);
+                *previous = (*previous).max(metric);
+            }
+        }
+        normalize(previous)
+    }
+}
+
+impl<const MAX_COUPLES: usize> Default for DuoBinaryBcjrDecoder<MAX_COUPLES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Get the contribution of the systematic llrs to the metric of `couple`.
+pub(crate) fn get_systematic(couple: Couple, symbol: &DuoBinarySymbol) -> i32 {
+    let mut metric = 0;
+    if couple & 1 != 0 {
+        metric += symbol.a as i32;
+    }
+    if couple & 2 != 0 {
+        metric += symbol.b as i32;
+    }
+    metric
+}
+
+/// Get the bit llrs for `A` and `B` from the llrs of the couples `01`, `10` and `11` relative to `00`.
+pub fn get_bit_llrs(llrs: &[Llr; 3]) -> (Llr, Llr) {
+    let [l01, l10, l11] = llrs.map(|llr| llr as i32);
+    let a = l01.max(l11) - l10.max(0);
+    let b = l10.max(l11) - l01.max(0);
+    (
+        a.clamp(Llr::MIN as i32, Llr::MAX as i32) as Llr,
+        b.clamp(Llr::MIN as i32, Llr::MAX as i32) as Llr,
+    )
+}
+
+fn normalize(mut metrics: [i32; STATES]) -> [i32; STATES] {
+    let max = *metrics.iter().max().unwrap();
+    for metric in metrics.iter_mut() {
+        *metric -= max;
+    }
+    metrics
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{duobinary::DuoBinaryEncoder, LlrMul};
+
+    use super::*;
+
+    #[test]
+    fn can_decode() {
+        // Given
+        let couples: Vec<Couple, 24> = (0..24).map(|i| (i * 7 + i / 5) % 4).collect();
+        let mut encoder = DuoBinaryEncoder::new_circular(couples.iter().copied()).unwrap();
+        let mut input = Vec::<DuoBinarySymbol, 24>::new();
+        for couple in couples.iter() {
+            let output = encoder.get_output(*couple);
+            input
+                .push(DuoBinarySymbol::new(
+                    (couple & 1 != 0).mul(4),
+                    (couple & 2 != 0).mul(4),
+                    (output & 1 != 0).mul(4),
+                    (output & 2 != 0).mul(4),
+                ))
+                .unwrap();
+        }
+        input[0].a = -input[0].a;
+        input[11].b = -input[11].b;
+        input[23].y = -input[23].y;
+        let decoder = DuoBinaryBcjrDecoder::<24>::new();
+        let mut output = [[0; 3]; 24];
+
+        // When
+        decoder.decode(&input, &mut output);
+
+        // Then
+        for (llrs, couple) in output.iter().zip(couples) {
+            let (a, b) = get_bit_llrs(llrs);
+            assert_eq!(couple & 1 != 0, a > 0);
+            assert_eq!(couple & 2 != 0, b > 0);
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::convolutional::EncoderOutput;
+
+/// The number of states in the duo-binary circular recursive systematic convolutional (CRSC) code.
+pub const STATES: usize = 8;
+
+/// The number of couples after which the all zero input state transition repeats itself.
+const PERIOD: usize = 7;
+
+/// A couple of input bits, where bit 0 is `A` and bit 1 is `B`.
+pub type Couple = usize;
+
+/// Get the next state of the constituent CRSC code.
+///
+/// The state is `4 S1 + 2 S2 + S3`, where the feedback polynomial is `1 + D + D^3`.
+/// `A` is applied to the first stage, and `B` is applied to all three stages.
+pub(crate) const fn next_state(state: usize, couple: Couple) -> usize {
+    let (s1, s2, s3) = (state >> 2, (state >> 1) & 1, state & 1);
+    let (a, b) = (couple & 1, couple >> 1);
+
+    let s1_next = a ^ b ^ s1 ^ s3;
+    let s2_next = s1 ^ b;
+    let s3_next = s2 ^ b;
+    (s1_next << 2) | (s2_next << 1) | s3_next
+}
+
+/// Get the parity output of the constituent CRSC code.
+///
+/// Bit 0 is `Y` with polynomial `1 + D^2 + D^3`, and bit 1 is `W` with polynomial `1 + D^3`.
+pub(crate) const fn output(state: usize, couple: Couple) -> EncoderOutput {
+    let (s2, s3) = ((state >> 1) & 1, state & 1);
+    let s1_next = next_state(state, couple) >> 2;
+
+    let y = s1_next ^ s2 ^ s3;
+    let w = s1_next ^ s3;
+    y | (w << 1)
+}
+
+/// Swap `A` and `B` in a couple.
+pub(crate) const fn swap(couple: Couple) -> Couple {
+    ((couple & 1) << 1) | (couple >> 1)
+}
+
+/// Get the circulation state for a block of `couples` couples,
+/// i.e. the state in which the encoder both starts and ends.
+///
+/// # Arguments
+///
+/// * `couples` - The number of couples in the block.
+/// * `zero_state_final` - The final state when encoding the block starting in the zero state.
+///
+/// `None` is returned if `couples` is a multiple of 7 for which there is no unique circulation state.
+pub const fn circulation_state(couples: usize, zero_state_final: usize) -> Option<usize> {
+    assert!(zero_state_final < STATES);
+    match couples % PERIOD {
+        0 => None,
+        remainder => Some(CIRCULATION_STATES[remainder - 1][zero_state_final]),
+    }
+}
+
+/// The circulation state for each `N mod 7` from 1 to 6 and final state `S0N` per IEEE 802.16 §8.4.9.2.3.3.
+///
+/// The final state for the start state `s` is `S0N ^ G^N s`, where `G^N s` is the final state
+/// when starting in `s` with all zero input, so the circulation state solves `s ^ G^N s = S0N`.
+const CIRCULATION_STATES: [[usize; STATES]; PERIOD - 1] = [
+    [0, 6, 4, 2, 7, 1, 3, 5],
+    [0, 3, 7, 4, 5, 6, 2, 1],
+    [0, 5, 3, 6, 2, 7, 1, 4],
+    [0, 4, 1, 5, 6, 2, 7, 3],
+    [0, 2, 5, 7, 1, 3, 4, 6],
+    [0, 7, 6, 1, 3, 4, 5, 2],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_get_circulation_state() {
+        // Given
+        #[rustfmt::skip]
+        let expected = [
+            [0, 6, 4, 2, 7, 1, 3, 5],
+            [0, 3, 7, 4, 5, 6, 2, 1],
+            [0, 5, 3, 6, 2, 7, 1, 4],
+            [0, 4, 1, 5, 6, 2, 7, 3],
+            [0, 2, 5, 7, 1, 3, 4, 6],
+            [0, 7, 6, 1, 3, 4, 5, 2],
+        ];
+
+        for (index, expected) in expected.iter().enumerate() {
+            // When
+            let states = core::array::from_fn(|final_state| {
+                circulation_state(index + 1, final_state).unwrap()
+            });
+
+            // Then
+            assert_eq!(*expected, states);
+        }
+        assert_eq!(None, circulation_state(14, 3));
+    }
+
+    #[test]
+    fn circulation_state_ends_in_itself() {
+        for couples in (1..50).filter(|couples| couples % PERIOD != 0) {
+            for zero_state_final in 0..STATES {
+                // Given
+                let state = circulation_state(couples, zero_state_final).unwrap();
+
+                // When
+                let response = (0..couples).fold(state, |state, _| next_state(state, 0));
+
+                // Then
+                assert_eq!(zero_state_final, state ^ response);
+            }
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use heapless::Vec;
+
+use crate::{
+    interleaver::{arp::ArpInterleaver, Interleaver, InterleaverMapping},
+    Llr,
+};
+
+use super::{
+    bcjr::{get_bit_llrs, get_systematic},
+    code::swap,
+    DuoBinaryBcjrDecoder, DuoBinarySymbol, DuoBinaryTurboSymbol,
+};
+
+/// Duo-binary turbo decoder.
+///
+/// `MAX_BITS` must be `2 * MAX_COUPLES`.
+pub struct DuoBinaryTurboDecoder<const MAX_COUPLES: usize, const MAX_BITS: usize>;
+
+impl<const MAX_COUPLES: usize, const MAX_BITS: usize> DuoBinaryTurboDecoder<MAX_COUPLES, MAX_BITS> {
+    pub fn new() -> Self {
+        assert_eq!(MAX_BITS, 2 * MAX_COUPLES);
+        Self
+    }
+
+    pub fn decode<'a>(
+        &self,
+        input: &[DuoBinaryTurboSymbol],
+        interleaver: &'a ArpInterleaver,
+    ) -> DuoBinaryTurboDecoding<'a, MAX_COUPLES, MAX_BITS> {
+        assert_eq!(interleaver.len(), input.len());
+
+        // Prepare input for the first decoder
+        let mut first_input = Vec::new();
+        for symbol in input {
+            first_input
+                .push(DuoBinarySymbol::new(
+                    symbol.a,
+                    symbol.b,
+                    symbol.first_y,
+                    symbol.first_w,
+                ))
+                .unwrap();
+        }
+
+        // Prepare input for the second decoder
+        let mut second_input = Vec::new();
+        for InterleaverMapping(i, ii) in interleaver.iter() {
+            let (a, b) = if ArpInterleaver::is_swapped(ii) {
+                (input[ii].b, input[ii].a)
+            } else {
+                (input[ii].a, input[ii].b)
+            };
+            second_input
+                .push(DuoBinarySymbol::new(
+                    a,
+                    b,
+                    input[i].second_y,
+                    input[i].second_w,
+                ))
+                .unwrap();
+        }
+
+        let mut bcjr_result = Vec::new();
+        bcjr_result.resize_default(input.len()).unwrap();
+        let mut result = Vec::new();
+        result.resize_default(2 * input.len()).unwrap();
+
+        DuoBinaryTurboDecoding {
+            bcjr: DuoBinaryBcjrDecoder::new(),
+            interleaver,
+            first_input,
+            second_input,
+            bcjr_result,
+            result,
+        }
+    }
+}
+
+impl<const MAX_COUPLES: usize, const MAX_BITS: usize> Default
+    for DuoBinaryTurboDecoder<MAX_COUPLES, MAX_BITS>
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct DuoBinaryTurboDecoding<'a, const MAX_COUPLES: usize, const MAX_BITS: usize> {
+    bcjr: DuoBinaryBcjrDecoder<MAX_COUPLES>,
+    interleaver: &'a ArpInterleaver,
+    first_input: Vec<DuoBinarySymbol, MAX_COUPLES>,
+    second_input: Vec<DuoBinarySymbol, MAX_COUPLES>,
+    bcjr_result: Vec<[Llr; 3], MAX_COUPLES>,
+    result: Vec<Llr, MAX_BITS>,
+}
+
+impl<const MAX_COUPLES: usize, const MAX_BITS: usize>
+    DuoBinaryTurboDecoding<'_, MAX_COUPLES, MAX_BITS>
+{
+    /// Get the a-posteriori llrs for the source bits,
+    /// where llr `2j` is for `A` and llr `2j + 1` is for `B` of couple `j`.
+    pub fn get_result(&self) -> &[Llr] {
+        &self.result
+    }
+
+    /// Run a single decode iteration
+    pub fn run_decode_iteration(&mut self) {
+        self.run_first_decoder();
+        self.run_second_decoder();
+    }
+
+    fn run_first_decoder(&mut self) {
+        self.bcjr.decode(&self.first_input, &mut self.bcjr_result);
+
+        // Emit the extrinsic couple llrs from the first decoder as the a-priori llrs for the second.
+        for InterleaverMapping(i, ii) in self.interleaver.iter() {
+            let extrinsic = get_extrinsic(&self.bcjr_result[ii], &self.first_input[ii]);
+            self.second_input[i].apriori = if ArpInterleaver::is_swapped(ii) {
+                swap_llrs(extrinsic)
+            } else {
+                extrinsic
+            };
+        }
+    }
+
+    fn run_second_decoder(&mut self) {
+        self.bcjr.decode(&self.second_input, &mut self.bcjr_result);
+
+        // Emit the extrinsic couple llrs from the second decoder as the a-priori llrs for the first,
+        // and deinterleave the a-posteriori llrs for decision making.
+        for InterleaverMapping(i, ii) in self.interleaver.iter() {
+            let extrinsic = get_extrinsic(&self.bcjr_result[i], &self.second_input[i]);
+            let mut lapp = self.bcjr_result[i];
+            if ArpInterleaver::is_swapped(ii) {
+                self.first_input[ii].apriori = swap_llrs(extrinsic);
+                lapp = swap_llrs(lapp);
+            } else {
+                self.first_input[ii].apriori = extrinsic;
+            }
+
+            let (a, b) = get_bit_llrs(&lapp);
+            self.result[2 * ii] = a;
+            self.result[2 * ii + 1] = b;
+        }
+    }
+}
+
+/// Get the extrinsic couple llrs by removing the a-priori and systematic llrs from the a-posteriori llrs.
+/// This is eqn. 28. in Abrantes applied to each couple.
+fn get_extrinsic(lapp: &[Llr; 3], symbol: &DuoBinarySymbol) -> [Llr; 3] {
+    core::array::from_fn(|index| {
+        let couple = index + 1;
+        let extrinsic =
+            lapp[index] as i32 - symbol.apriori[index] as i32 - get_systematic(couple, symbol);
+        extrinsic.clamp(Llr::MIN as i32, Llr::MAX as i32) as Llr
+    })
+}
+
+/// Swap `A` and `B` in the llrs for the couples `01`, `10` and `11`.
+fn swap_llrs(llrs: [Llr; 3]) -> [Llr; 3] {
+    core::array::from_fn(|index| llrs[swap(index + 1) - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        convolutional::EncoderOutput, duobinary::DuoBinaryTurboEncoder, interleaver::wimax, LlrMul,
+    };
+
+    use super::*;
+
+    #[test]
+    fn can_decode() {
+        // Given
+        let source: [bool; 96] = core::array::from_fn(|i| (i * 5 + i / 3) % 3 == 0);
+        let encoder = DuoBinaryTurboEncoder::new();
+        let interleaver = wimax::create(48).unwrap();
+        let mut encoded = [0; 48];
+        encoder.encode(&source, &interleaver, &mut encoded).unwrap();
+        let mut input = encoded.map(to_symbol);
+        for index in (2..48).step_by(7) {
+            input[index].a = -input[index].a;
+        }
+        for index in (5..48).step_by(9) {
+            input[index].first_w = -input[index].first_w;
+            input[index].second_y = -input[index].second_y;
+        }
+        let decoder = DuoBinaryTurboDecoder::<48, 96>::new();
+
+        // When
+        let mut decoding = decoder.decode(&input, &interleaver);
+        assert_eq!([0; 96], decoding.get_result());
+        for _ in 0..4 {
+            decoding.run_decode_iteration();
+        }
+
+        // Then
+        for (llr, bit) in decoding.get_result().iter().zip(source) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+
+    fn to_symbol(output: EncoderOutput) -> DuoBinaryTurboSymbol {
+        let bit = |index: usize| (output & (1 << index) != 0).mul(4);
+        DuoBinaryTurboSymbol::new(bit(0), bit(1), bit(2), bit(3), bit(4), bit(5))
+    }
+}
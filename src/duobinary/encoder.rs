@@ -0,0 +1,165 @@
+use crate::{
+    convolutional::EncoderOutput,
+    interleaver::{arp::ArpInterleaver, Interleaver, InterleaverMapping},
+    BitView,
+};
+
+use super::code::{self, circulation_state, swap, Couple};
+
+/// Duo-binary circular recursive systematic convolutional (CRSC) encoder.
+pub struct DuoBinaryEncoder {
+    state: usize,
+}
+
+impl DuoBinaryEncoder {
+    /// Create an encoder starting in `state`.
+    pub const fn new(state: usize) -> Self {
+        assert!(state < code::STATES);
+        Self { state }
+    }
+
+    /// Create an encoder for circular encoding of the couples yielded by `couples`.
+    ///
+    /// The encoder is preloaded with the circulation state so that it ends in the same state after all couples are encoded.
+    /// `None` is returned if there is no circulation state for the number of couples.
+    pub fn new_circular(couples: impl Iterator<Item = Couple>) -> Option<Self> {
+        let mut length = 0;
+        let mut zero_state_final = 0;
+        for couple in couples {
+            zero_state_final = code::next_state(zero_state_final, couple);
+            length += 1;
+        }
+
+        circulation_state(length, zero_state_final).map(Self::new)
+    }
+
+    /// The current encoder state.
+    pub const fn state(&self) -> usize {
+        self.state
+    }
+
+    /// Get the next encoder output given `couple`.
+    ///
+    /// Bit 0 is the `Y` parity and bit 1 is the `W` parity.
+    pub fn get_output(&mut self, couple: Couple) -> EncoderOutput {
+        let output = code::output(self.state, couple);
+        self.state = code::next_state(self.state, couple);
+        output
+    }
+}
+
+/// Duo-binary turbo encoder, e.g. the WiMAX convolutional turbo code (CTC) or the DVB-RCS turbo code.
+///
+/// Both constituent encoders use circular encoding, so no termination is generated.
+#[derive(Default)]
+pub struct DuoBinaryTurboEncoder;
+
+impl DuoBinaryTurboEncoder {
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Encode a block of `2N` bits, where bit `2j` is `A` and bit `2j + 1` is `B` of couple `j`.
+    ///
+    /// The output for each couple is written to `output`, where bits 0 and 1 are the systematic `A` and `B`,
+    /// bits 2 and 3 are `Y` and `W` from the first encoder and
+    /// bits 4 and 5 are `Y` and `W` from the second encoder.
+    /// `None` is returned if there is no circulation state for the block length.
+    pub fn encode<S: BitView>(
+        &self,
+        source: S,
+        interleaver: &ArpInterleaver,
+        output: &mut [EncoderOutput],
+    ) -> Option<()> {
+        let length = interleaver.len();
+        assert_eq!(2 * length, source.len2());
+        assert!(output.len() >= length);
+
+        let couple = |index: usize| {
+            source.get(2 * index) as usize | (source.get(2 * index + 1) as usize) << 1
+        };
+        let interleaved_couple = |ii: usize| {
+            if ArpInterleaver::is_swapped(ii) {
+                swap(couple(ii))
+            } else {
+                couple(ii)
+            }
+        };
+
+        let mut first_encoder = DuoBinaryEncoder::new_circular((0..length).map(couple))?;
+        let mut second_encoder = DuoBinaryEncoder::new_circular(
+            interleaver
+                .iter()
+                .map(|InterleaverMapping(_, ii)| interleaved_couple(ii)),
+        )?;
+
+        for (index, output) in output[..length].iter_mut().enumerate() {
+            let first_output = first_encoder.get_output(couple(index));
+            *output = couple(index) | first_output << 2;
+        }
+        for InterleaverMapping(i, ii) in interleaver.iter() {
+            let second_output = second_encoder.get_output(interleaved_couple(ii));
+            output[i] |= second_output << 4;
+        }
+
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interleaver::wimax;
+
+    use super::*;
+
+    #[test]
+    fn ends_in_circulation_state() {
+        // Given
+        let couples: Vec<Couple> = (0..24).map(|i| (i * 7 + i / 5) % 4).collect();
+        let mut encoder = DuoBinaryEncoder::new_circular(couples.iter().copied()).unwrap();
+        let start = encoder.state();
+
+        // When
+        for couple in couples {
+            encoder.get_output(couple);
+        }
+
+        // Then
+        assert_eq!(start, encoder.state());
+    }
+
+    #[test]
+    fn can_encode() {
+        // Given
+        let source: Vec<bool> = (0..96).map(|i| (i * 5 + i / 3) % 3 == 0).collect();
+        let encoder = DuoBinaryTurboEncoder::new();
+        let interleaver = wimax::create(48).unwrap();
+        let mut output = [0; 48];
+
+        // When
+        encoder
+            .encode(&source[..], &interleaver, &mut output)
+            .unwrap();
+
+        // Then
+        for (index, output) in output.iter().enumerate() {
+            assert_eq!(source[2 * index], output & 1 != 0);
+            assert_eq!(source[2 * index + 1], output & 2 != 0);
+        }
+    }
+
+    #[test]
+    fn has_no_circulation_state_for_multiples_of_seven() {
+        // Given
+        let source = [true; 56];
+        let encoder = DuoBinaryTurboEncoder::new();
+        let interleaver = ArpInterleaver::new(28, 3, 0, 0, 0);
+        let mut output = [0; 28];
+
+        // When
+        let result = encoder.encode(&source, &interleaver, &mut output);
+
+        // Then
+        assert_eq!(None, result);
+    }
+}
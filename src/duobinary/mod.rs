@@ -0,0 +1,11 @@
+mod bcjr;
+mod code;
+mod decoder;
+mod encoder;
+mod symbol;
+
+pub use bcjr::{get_bit_llrs, DuoBinaryBcjrDecoder};
+pub use code::{circulation_state, Couple};
+pub use decoder::{DuoBinaryTurboDecoder, DuoBinaryTurboDecoding};
+pub use encoder::{DuoBinaryEncoder, DuoBinaryTurboEncoder};
+pub use symbol::{DuoBinarySymbol, DuoBinaryTurboSymbol};
@@ -0,0 +1,56 @@
+use crate::Llr;
+
+/// The received llrs for a couple of the constituent CRSC code.
+#[derive(Clone, Copy, Debug)]
+pub struct DuoBinarySymbol {
+    pub a: Llr,
+    pub b: Llr,
+    pub y: Llr,
+    pub w: Llr,
+    /// The a-priori llrs of the couples `01`, `10` and `11` relative to `00`,
+    /// where bit 0 is `A` and bit 1 is `B`.
+    pub apriori: [Llr; 3],
+}
+
+impl DuoBinarySymbol {
+    pub const fn new(a: Llr, b: Llr, y: Llr, w: Llr) -> Self {
+        Self {
+            a,
+            b,
+            y,
+            w,
+            apriori: [0; 3],
+        }
+    }
+}
+
+/// The received llrs for a couple of the duo-binary turbo code.
+#[derive(Clone, Copy, Debug)]
+pub struct DuoBinaryTurboSymbol {
+    pub a: Llr,
+    pub b: Llr,
+    pub first_y: Llr,
+    pub first_w: Llr,
+    pub second_y: Llr,
+    pub second_w: Llr,
+}
+
+impl DuoBinaryTurboSymbol {
+    pub const fn new(
+        a: Llr,
+        b: Llr,
+        first_y: Llr,
+        first_w: Llr,
+        second_y: Llr,
+        second_w: Llr,
+    ) -> Self {
+        Self {
+            a,
+            b,
+            first_y,
+            first_w,
+            second_y,
+            second_w,
+        }
+    }
+}
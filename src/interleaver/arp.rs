@@ -0,0 +1,128 @@
+use super::{Interleaver, InterleaverMapping};
+
+/// Almost Regular Permutation (ARP) Interleaver for duo-binary turbo codes.
+///
+/// The interleaver works on couples and has two steps:
+/// 1. `A` and `B` are swapped in every odd couple `(B, A) -> (A, B)`.
+/// 2. Interleaved couple `j` takes couple `pi(j) = (P0 j + P + 1) mod N`, where
+///    `P` is `0`, `N/2 + P1`, `P2` or `N/2 + P3` for `j mod 4` being `0`, `1`, `2` or `3`.
+///
+/// The mapping produced by [`Interleaver::iter`] only covers the second step,
+/// use [`ArpInterleaver::is_swapped`] for the first.
+/// The WiMAX CTC parameters are available from [`wimax::create`](super::wimax::create),
+/// DVB-RCS uses the same permutation with the parameters from EN 301 790.
+#[derive(Clone, Copy)]
+pub struct ArpInterleaver {
+    /// The block length `N` in couples.
+    length: usize,
+    p0: usize,
+    p1: usize,
+    p2: usize,
+    p3: usize,
+}
+
+impl ArpInterleaver {
+    /// Create a new interleaver
+    pub const fn new(length: usize, p0: usize, p1: usize, p2: usize, p3: usize) -> Self {
+        assert!(length % 4 == 0);
+        Self {
+            length,
+            p0,
+            p1,
+            p2,
+            p3,
+        }
+    }
+
+    /// Get whether `A` and `B` are swapped for original couple `i` when interleaved.
+    pub const fn is_swapped(i: usize) -> bool {
+        i % 2 == 1
+    }
+
+    const fn offset(&self, j: usize) -> usize {
+        match j % 4 {
+            0 => 0,
+            1 => self.length / 2 + self.p1,
+            2 => self.p2,
+            _ => self.length / 2 + self.p3,
+        }
+    }
+}
+
+impl Interleaver for ArpInterleaver {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn get(&self, i: usize) -> usize {
+        (self.p0 * i + self.offset(i) + 1) % self.length
+    }
+
+    fn iter(&self) -> impl Iterator<Item = InterleaverMapping> {
+        ArpIterator {
+            interleaver: *self,
+            base: 1,
+            i: 0,
+        }
+    }
+}
+
+pub struct ArpIterator {
+    interleaver: ArpInterleaver,
+    /// The value `(P0 i + 1) mod N`
+    base: usize,
+    i: usize,
+}
+
+impl Iterator for ArpIterator {
+    type Item = InterleaverMapping;
+
+    fn next(&mut self) -> Option<InterleaverMapping> {
+        let length = self.interleaver.length;
+        if self.i < length {
+            let i = self.i;
+            let pi = (self.base + self.interleaver.offset(i)) % length;
+
+            self.base = (self.base + self.interleaver.p0) % length;
+            self.i = i + 1;
+
+            Some(InterleaverMapping(i, pi))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.interleaver.length - self.i;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for ArpIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_interleave() {
+        // Given
+        let interleaver = ArpInterleaver::new(24, 5, 0, 0, 0);
+
+        // When
+        let permuted: Vec<usize> = interleaver.iter().map(|mapping| *mapping).collect();
+
+        // Then
+        assert_eq!(
+            [
+                1, 18, 11, 4, 21, 14, 7, 0, 17, 10, 3, 20, 13, 6, 23, 16, 9, 2, 19, 12, 5, 22, 15,
+                8
+            ]
+            .to_vec(),
+            permuted
+        );
+        for InterleaverMapping(i, ii) in interleaver.iter() {
+            assert_eq!(ii, interleaver.get(i));
+        }
+    }
+}
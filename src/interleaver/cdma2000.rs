@@ -0,0 +1,212 @@
+use super::{Interleaver, InterleaverMapping};
+
+/// The 3GPP2 turbo interleaver per C.S0002 §2.1.3.1.4.2.3.
+///
+/// The interleaver writes the input into an array of 32 rows with `2^n` columns,
+/// where `n` is the smallest integer such that `N <= 2^(n + 5)`.
+/// Each row is permuted by a linear congruential sequence (LCS) with a multiplier from [`LOOKUP_TABLE`],
+/// and the rows are read in bit-reversed order. Addresses outside the block are discarded.
+#[derive(Clone, Copy)]
+pub struct Cdma2000Interleaver {
+    /// The block length `N` in bits.
+    length: usize,
+    n: u32,
+    table: &'static [usize; 32],
+}
+
+/// The turbo interleaver lookup table per C.S0002 Table 2.1.3.1.4.2.3-2.
+///
+/// Holds the 32 row multipliers for each of the column exponents `n` from 4 to 10,
+/// i.e. `LOOKUP_TABLE[n - 4][row]`.
+pub const LOOKUP_TABLE: [[usize; 32]; 7] = [
+    [
+        5, 15, 5, 15, 1, 9, 9, 15, 13, 15, 7, 11, 15, 3, 15, 5, 13, 15, 9, 3, 1, 3, 15, 1, 13, 1,
+        9, 15, 11, 3, 15, 5,
+    ],
+    [
+        27, 3, 1, 15, 13, 17, 23, 13, 9, 3, 15, 3, 13, 1, 13, 29, 21, 19, 1, 3, 29, 17, 25, 29, 9,
+        13, 23, 13, 13, 1, 13, 1,
+    ],
+    [
+        3, 27, 15, 13, 29, 5, 1, 31, 3, 9, 15, 31, 17, 5, 39, 1, 19, 27, 15, 13, 45, 5, 33, 15, 13,
+        9, 15, 31, 17, 5, 15, 33,
+    ],
+    [
+        15, 127, 89, 1, 31, 15, 61, 47, 127, 17, 119, 15, 57, 123, 95, 5, 85, 17, 55, 57, 15, 41,
+        93, 87, 63, 15, 13, 15, 81, 57, 31, 69,
+    ],
+    [
+        3, 1, 5, 83, 19, 179, 19, 99, 23, 1, 3, 13, 13, 3, 17, 1, 63, 131, 17, 131, 211, 173, 231,
+        171, 23, 147, 243, 213, 189, 51, 15, 67,
+    ],
+    [
+        13, 335, 87, 15, 15, 1, 333, 11, 13, 1, 121, 155, 1, 175, 421, 5, 509, 215, 47, 425, 295,
+        229, 427, 83, 409, 387, 193, 57, 501, 313, 489, 391,
+    ],
+    [
+        1, 349, 303, 721, 973, 703, 761, 327, 453, 95, 241, 187, 497, 909, 769, 349, 71, 557, 197,
+        499, 409, 259, 335, 253, 677, 717, 313, 757, 189, 15, 75, 163,
+    ],
+];
+
+/// The turbo interleaver block sizes per C.S0002 Table 2.1.3.1.4.2.3-1.
+pub const BLOCK_SIZES: [usize; 12] = [
+    378, 570, 762, 1146, 1530, 2298, 3066, 4602, 6138, 9210, 12282, 20730,
+];
+
+impl Cdma2000Interleaver {
+    /// Create a new interleaver
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The block length `N` in bits, between 257 and 32768 such that `n` is in the lookup table.
+    pub const fn new(length: usize) -> Self {
+        assert!(length > 256 && length <= 32768);
+        let n = (length - 1).ilog2() + 1 - 5;
+
+        Self {
+            length,
+            n,
+            table: &LOOKUP_TABLE[n as usize - 4],
+        }
+    }
+
+    /// Get the tentative output address for counter value `counter`.
+    const fn get_address(&self, counter: usize) -> usize {
+        let mask = (1 << self.n) - 1;
+        let row = counter & 0x1F;
+
+        // The column is the n most significant bits of the counter plus one,
+        // multiplied by the table entry for the row
+        let column = ((((counter >> 5) + 1) & mask) * self.table[row]) & mask;
+        let row = row.reverse_bits() >> (usize::BITS - 5);
+
+        (row << self.n) | column
+    }
+}
+
+impl Interleaver for Cdma2000Interleaver {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn get(&self, i: usize) -> usize {
+        self.iter().nth(i).unwrap().1
+    }
+
+    fn iter(&self) -> impl Iterator<Item = InterleaverMapping> {
+        Cdma2000Iterator {
+            interleaver: *self,
+            counter: 0,
+            i: 0,
+        }
+    }
+}
+
+pub struct Cdma2000Iterator {
+    interleaver: Cdma2000Interleaver,
+    counter: usize,
+    i: usize,
+}
+
+impl Iterator for Cdma2000Iterator {
+    type Item = InterleaverMapping;
+
+    fn next(&mut self) -> Option<InterleaverMapping> {
+        if self.i < self.interleaver.length {
+            // Discard the addresses that are outside the block
+            let mut address = self.interleaver.get_address(self.counter);
+            while address >= self.interleaver.length {
+                self.counter += 1;
+                address = self.interleaver.get_address(self.counter);
+            }
+
+            let i = self.i;
+            self.counter += 1;
+            self.i = i + 1;
+
+            Some(InterleaverMapping(i, address))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.interleaver.length - self.i;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Cdma2000Iterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_table_has_odd_multipliers_within_the_row() {
+        for (index, column) in LOOKUP_TABLE.iter().enumerate() {
+            for multiplier in column {
+                assert_eq!(1, multiplier % 2);
+                assert!(*multiplier < 1 << (index + 4));
+            }
+        }
+    }
+
+    #[test]
+    fn can_interleave() {
+        // Given
+        let interleaver = Cdma2000Interleaver::new(378);
+
+        // When
+        let permuted: Vec<usize> = interleaver.iter().map(|mapping| *mapping).collect();
+
+        // Then
+        // The first addresses follow the steps in §2.1.3.1.4.2.3 with n = 4, e.g. for counter 1:
+        // The row is 1 with multiplier 15, the column is (0 + 1) * 15 mod 16 = 15,
+        // and the bit-reversed row is 16, which gives the address 16 * 16 + 15 = 271.
+        // Counter 3 gives the address 24 * 16 + 15 = 399 which is discarded.
+        assert_eq!(
+            [5, 271, 133, 65, 329, 201, 45, 303, 167, 111, 355, 239, 29, 287, 153, 81],
+            permuted[..16]
+        );
+        assert_eq!([176, 112, 368, 240], permuted[374..]);
+    }
+
+    #[test]
+    fn can_interleave_largest_block() {
+        // Given
+        let interleaver = Cdma2000Interleaver::new(20730);
+
+        // When
+        let permuted: Vec<usize> = interleaver.iter().take(8).map(|mapping| *mapping).collect();
+
+        // Then
+        assert_eq!(
+            [1, 16733, 8495, 5069, 13049, 2501, 18527, 10481].to_vec(),
+            permuted
+        );
+    }
+
+    #[test]
+    fn is_permutation() {
+        for length in BLOCK_SIZES {
+            // Given
+            let interleaver = Cdma2000Interleaver::new(length);
+            let mut used = vec![false; length];
+
+            // When
+            for mapping in interleaver.iter() {
+                assert!(!used[*mapping]);
+                used[*mapping] = true;
+            }
+
+            // Then
+            assert!(used.iter().all(|used| *used));
+            assert_eq!(
+                interleaver.get(length / 3),
+                interleaver.iter().nth(length / 3).unwrap().1
+            );
+        }
+    }
+}
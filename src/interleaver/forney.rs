@@ -0,0 +1,220 @@
+/// The number of branches of the DVB convolutional interleaver per EN 300 421 §4.4.3.
+pub const DVB_BRANCHES: usize = 12;
+
+/// The depth of the DVB convolutional interleaver per EN 300 421 §4.4.3.
+pub const DVB_DEPTH: usize = 17;
+
+/// The number of delay cells in the DVB interleaver and deinterleaver.
+pub const DVB_CELLS: usize = cells(DVB_BRANCHES, DVB_DEPTH);
+
+/// The DVB byte interleaver with `I = 12` and `M = 17`.
+/// The sync byte of each 204 byte packet passes the undelayed branch 0 when the first packet is pushed after [`ForneyInterleaver::reset`].
+pub type DvbInterleaver = ForneyInterleaver<u8, DVB_BRANCHES, DVB_CELLS>;
+
+/// The DVB byte deinterleaver with `I = 12` and `M = 17`.
+pub type DvbDeinterleaver = ForneyDeinterleaver<u8, DVB_BRANCHES, DVB_CELLS>;
+
+/// Get the number of delay cells `M I (I - 1) / 2` for an interleaver with `branches` branches of depth `depth`.
+pub const fn cells(branches: usize, depth: usize) -> usize {
+    depth * branches * (branches - 1) / 2
+}
+
+/// Forney convolutional interleaver with `BRANCHES` branches.
+///
+/// Symbols are cyclically switched into the branches, where branch `j` is a FIFO of `j M` symbols,
+/// so branch 0 has no delay. The interleaver is streaming,
+/// and each pushed symbol pops the oldest symbol from the same branch.
+/// `CELLS` must be `M BRANCHES (BRANCHES - 1) / 2`, see [`cells`].
+pub struct ForneyInterleaver<T: Copy + Default, const BRANCHES: usize, const CELLS: usize> {
+    lines: DelayLines<T, BRANCHES, CELLS>,
+}
+
+impl<T: Copy + Default, const BRANCHES: usize, const CELLS: usize>
+    ForneyInterleaver<T, BRANCHES, CELLS>
+{
+    /// Create a new interleaver with all delay cells initialized to the default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The depth `M`, i.e. the number of cells added for each branch.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            lines: DelayLines::new(depth, |branch| branch),
+        }
+    }
+
+    /// Push a symbol into the current branch and pop the oldest symbol from that branch.
+    pub fn push(&mut self, symbol: T) -> T {
+        self.lines.push(symbol)
+    }
+
+    /// Interleave a sequence of symbols.
+    pub fn interleave(&mut self, input: &[T], output: &mut [T]) {
+        self.lines.push_slice(input, output);
+    }
+
+    /// Clear all delay cells and switch to the first branch.
+    pub fn reset(&mut self) {
+        self.lines.reset();
+    }
+}
+
+/// Forney convolutional deinterleaver with `BRANCHES` branches.
+///
+/// This is the inverse of the [`ForneyInterleaver`], where branch `j` is a FIFO of `(BRANCHES - 1 - j) M` symbols.
+/// The end-to-end delay of the interleaver and the deinterleaver is `M BRANCHES (BRANCHES - 1)` symbols.
+pub struct ForneyDeinterleaver<T: Copy + Default, const BRANCHES: usize, const CELLS: usize> {
+    lines: DelayLines<T, BRANCHES, CELLS>,
+}
+
+impl<T: Copy + Default, const BRANCHES: usize, const CELLS: usize>
+    ForneyDeinterleaver<T, BRANCHES, CELLS>
+{
+    /// Create a new deinterleaver with all delay cells initialized to the default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `depth` - The depth `M`, i.e. the number of cells added for each branch.
+    pub fn new(depth: usize) -> Self {
+        Self {
+            lines: DelayLines::new(depth, |branch| BRANCHES - 1 - branch),
+        }
+    }
+
+    /// The end-to-end delay in symbols of the interleaver and deinterleaver.
+    pub fn delay(&self) -> usize {
+        self.lines.depth * BRANCHES * (BRANCHES - 1)
+    }
+
+    /// Push a symbol into the current branch and pop the oldest symbol from that branch.
+    pub fn push(&mut self, symbol: T) -> T {
+        self.lines.push(symbol)
+    }
+
+    /// Deinterleave a sequence of symbols.
+    pub fn deinterleave(&mut self, input: &[T], output: &mut [T]) {
+        self.lines.push_slice(input, output);
+    }
+
+    /// Clear all delay cells and switch to the first branch.
+    pub fn reset(&mut self) {
+        self.lines.reset();
+    }
+}
+
+/// The FIFO delay lines for each branch, stored back to back in a single buffer.
+struct DelayLines<T: Copy + Default, const BRANCHES: usize, const CELLS: usize> {
+    depth: usize,
+    cells: [T; CELLS],
+    /// The offset of the first cell and the length of each branch.
+    lines: [(usize, usize); BRANCHES],
+    /// The position of the oldest symbol in each branch.
+    positions: [usize; BRANCHES],
+    branch: usize,
+}
+
+impl<T: Copy + Default, const BRANCHES: usize, const CELLS: usize> DelayLines<T, BRANCHES, CELLS> {
+    fn new(depth: usize, units: impl Fn(usize) -> usize) -> Self {
+        assert!(BRANCHES > 0);
+        assert_eq!(CELLS, cells(BRANCHES, depth));
+
+        let mut lines = [(0, 0); BRANCHES];
+        let mut offset = 0;
+        for (branch, line) in lines.iter_mut().enumerate() {
+            let len = units(branch) * depth;
+            *line = (offset, len);
+            offset += len;
+        }
+
+        Self {
+            depth,
+            cells: [T::default(); CELLS],
+            lines,
+            positions: [0; BRANCHES],
+            branch: 0,
+        }
+    }
+
+    fn push(&mut self, symbol: T) -> T {
+        let branch = self.branch;
+        self.branch = if branch + 1 < BRANCHES { branch + 1 } else { 0 };
+
+        let (offset, len) = self.lines[branch];
+        if len == 0 {
+            return symbol;
+        }
+
+        let position = self.positions[branch];
+        let output = core::mem::replace(&mut self.cells[offset + position], symbol);
+        self.positions[branch] = if position + 1 < len { position + 1 } else { 0 };
+        output
+    }
+
+    fn push_slice(&mut self, input: &[T], output: &mut [T]) {
+        assert_eq!(input.len(), output.len());
+        for (input, output) in input.iter().zip(output.iter_mut()) {
+            *output = self.push(*input);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.cells.fill(T::default());
+        self.positions.fill(0);
+        self.branch = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_interleave() {
+        // Given
+        let mut interleaver = ForneyInterleaver::<u8, 3, 3>::new(1);
+        let input: Vec<u8> = (1..=12).collect();
+        let mut output = [0; 12];
+
+        // When
+        interleaver.interleave(&input, &mut output);
+
+        // Then
+        // Branch 0 has no delay, branch 1 delays one round and branch 2 delays two rounds
+        assert_eq!([1, 0, 0, 4, 2, 0, 7, 5, 3, 10, 8, 6], output);
+    }
+
+    #[test]
+    fn can_deinterleave() {
+        // Given
+        let mut interleaver = DvbInterleaver::new(DVB_DEPTH);
+        let mut deinterleaver = DvbDeinterleaver::new(DVB_DEPTH);
+        let input: Vec<u8> = (0..15 * 204).map(|i| (i * 7 + i / 13) as u8).collect();
+        let mut interleaved = vec![0; input.len()];
+        let mut output = vec![0; input.len()];
+
+        // When
+        interleaver.interleave(&input, &mut interleaved);
+        deinterleaver.deinterleave(&interleaved, &mut output);
+
+        // Then
+        let delay = deinterleaver.delay();
+        assert_eq!(2244, delay);
+        assert_eq!([0; 2244], output[..delay]);
+        assert_eq!(input[..input.len() - delay], output[delay..]);
+    }
+
+    #[test]
+    fn can_reset() {
+        // Given
+        let mut interleaver = ForneyInterleaver::<u8, 3, 6>::new(2);
+        let mut output = [0; 7];
+        interleaver.interleave(&[1, 2, 3, 4, 5, 6, 7], &mut output);
+
+        // When
+        interleaver.reset();
+
+        // Then
+        interleaver.interleave(&[1, 2, 3, 4, 5, 6, 7], &mut output);
+        assert_eq!([1, 0, 0, 4, 0, 0, 7], output);
+    }
+}
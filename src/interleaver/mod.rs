@@ -1,7 +1,11 @@
 use core::ops::Deref;
 
+pub mod arp;
+pub mod cdma2000;
+pub mod forney;
 pub mod qpp;
 pub mod umts;
+pub mod wimax;
 
 /// The interleaver.
 #[allow(clippy::len_without_is_empty)]
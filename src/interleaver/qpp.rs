@@ -21,6 +21,44 @@ impl QppInterleaver {
     pub const fn new(length: usize, f1: u16, f2: u16) -> Self {
         Self { length, f1, f2 }
     }
+
+    /// The linear coefficient `f1`.
+    pub const fn f1(&self) -> u16 {
+        self.f1
+    }
+
+    /// The quadratic coefficient `f2`.
+    pub const fn f2(&self) -> u16 {
+        self.f2
+    }
+
+    /// Get whether `f1 * i + f2 * i^2` is a permutation polynomial modulo `length`,
+    /// i.e. whether the interleaver maps the block onto itself.
+    ///
+    /// Per Sun and Takeshita, this is the case if for all primes `p` dividing `length`, `p` divides `f2` and not `f1`,
+    /// except for `p = 2` when `length` is not divisible by 4, where `f1 + f2` must be odd.
+    pub const fn is_permutation(length: usize, f1: u16, f2: u16) -> bool {
+        let (f1, f2) = (f1 as usize, f2 as usize);
+        let mut remaining = length;
+        let mut p = 2;
+        while p <= remaining {
+            if remaining % p == 0 {
+                let valid = if p == 2 && length % 4 != 0 {
+                    (f1 + f2) % 2 == 1
+                } else {
+                    f1 % p != 0 && f2 % p == 0
+                };
+                if !valid {
+                    return false;
+                }
+                while remaining % p == 0 {
+                    remaining /= p;
+                }
+            }
+            p += 1;
+        }
+        length > 0
+    }
 }
 
 impl Interleaver for QppInterleaver {
@@ -81,3 +119,47 @@ impl Iterator for QppIterator {
 }
 
 impl ExactSizeIterator for QppIterator {}
+
+#[cfg(test)]
+mod tests {
+    use crate::interleaver::umts;
+
+    use super::*;
+
+    #[test]
+    fn can_check_permutation() {
+        for length in (8..=96).step_by(4).chain([30, 31, 42]) {
+            for f1 in 0..length as u16 {
+                for f2 in 0..length as u16 {
+                    // Given
+                    let interleaver = QppInterleaver::new(length, f1, f2);
+
+                    // When
+                    let mut visited = [false; 96];
+                    for InterleaverMapping(_, ii) in interleaver.iter() {
+                        visited[ii] = true;
+                    }
+
+                    // Then
+                    let is_permutation = visited[..length].iter().all(|visited| *visited);
+                    assert_eq!(
+                        is_permutation,
+                        QppInterleaver::is_permutation(length, f1, f2)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn umts_interleavers_are_permutations() {
+        for length in (40..=512).step_by(8) {
+            let interleaver = umts::create(length).unwrap();
+            assert!(QppInterleaver::is_permutation(
+                length,
+                interleaver.f1(),
+                interleaver.f2()
+            ));
+        }
+    }
+}
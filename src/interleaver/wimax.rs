@@ -0,0 +1,46 @@
+use super::arp::ArpInterleaver;
+
+/// Create the CTC interleaver per IEEE 802.16 §8.4.9.2.3.2 for a block of `length` couples.
+pub const fn create(length: usize) -> Option<ArpInterleaver> {
+    match length {
+        24 => Some(ArpInterleaver::new(24, 5, 0, 0, 0)),
+        36 => Some(ArpInterleaver::new(36, 11, 18, 0, 18)),
+        48 => Some(ArpInterleaver::new(48, 13, 24, 0, 24)),
+        72 => Some(ArpInterleaver::new(72, 11, 6, 0, 6)),
+        96 => Some(ArpInterleaver::new(96, 7, 48, 24, 72)),
+        108 => Some(ArpInterleaver::new(108, 11, 54, 56, 2)),
+        120 => Some(ArpInterleaver::new(120, 13, 60, 0, 60)),
+        144 => Some(ArpInterleaver::new(144, 17, 74, 72, 2)),
+        180 => Some(ArpInterleaver::new(180, 11, 90, 0, 90)),
+        192 => Some(ArpInterleaver::new(192, 11, 96, 48, 144)),
+        216 => Some(ArpInterleaver::new(216, 13, 108, 0, 108)),
+        240 => Some(ArpInterleaver::new(240, 13, 120, 60, 180)),
+        480 => Some(ArpInterleaver::new(480, 53, 62, 12, 2)),
+        960 => Some(ArpInterleaver::new(960, 43, 64, 300, 824)),
+        1440 => Some(ArpInterleaver::new(1440, 43, 720, 360, 540)),
+        1920 => Some(ArpInterleaver::new(1920, 31, 8, 24, 16)),
+        2400 => Some(ArpInterleaver::new(2400, 53, 66, 24, 2)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interleaver::Interleaver;
+
+    use super::*;
+
+    #[test]
+    fn all_are_permutations() {
+        for length in 1..=2400 {
+            if let Some(interleaver) = create(length) {
+                let mut used = [false; 2400];
+                for mapping in interleaver.iter() {
+                    assert!(!used[*mapping]);
+                    used[*mapping] = true;
+                }
+                assert!(used[..length].iter().all(|used| *used));
+            }
+        }
+    }
+}
@@ -0,0 +1,311 @@
+use core::simd::prelude::*;
+
+use heapless::Vec;
+
+use crate::Llr;
+
+use super::QcMatrix;
+
+/// The number of check nodes that are updated in parallel.
+const LANES: usize = 16;
+
+/// The maximum number of circulants in a block row.
+const MAX_ROW_DEGREE: usize = 32;
+
+type Lanes = Simd<i8, LANES>;
+
+/// The check node update approximation.
+#[derive(Clone, Copy)]
+pub enum MinSum {
+    /// Normalized min-sum, where the minimum is scaled by `factor / 8`, e.g. `6` for 0.75.
+    /// The factor must be at most 8, such that the scaled magnitude does not grow.
+    Normalized(u8),
+    /// Offset min-sum, where the offset is subtracted from the minimum, limited at zero.
+    Offset(Llr),
+}
+
+/// Layered min-sum decoder for quasi-cyclic LDPC codes.
+///
+/// Each block row is a layer, and the `Z` check nodes within a layer are updated in parallel using `SIMD` instructions.
+/// The check to variable node messages are stored for all ones in the matrix,
+/// so `MAX_MESSAGES` must be at least [`QcMatrix::messages`].
+pub struct LdpcDecoder<const MAX_BITS: usize, const MAX_MESSAGES: usize> {
+    matrix: QcMatrix,
+    min_sum: MinSum,
+}
+
+impl<const MAX_BITS: usize, const MAX_MESSAGES: usize> LdpcDecoder<MAX_BITS, MAX_MESSAGES> {
+    pub fn new(matrix: QcMatrix, min_sum: MinSum) -> Self {
+        if let MinSum::Normalized(factor) = min_sum {
+            assert!(factor <= 8, "The normalization factor must be at most 8");
+        }
+        assert!(matrix.codeword_bits() <= MAX_BITS);
+        assert!(matrix.messages() <= MAX_MESSAGES);
+        for row in 0..matrix.base().rows() {
+            assert!(matrix.row(row).count() <= MAX_ROW_DEGREE);
+        }
+
+        Self { matrix, min_sum }
+    }
+
+    /// Start decoding the channel llrs of the `N` codeword bits.
+    pub fn decode(&self, input: &[Llr]) -> LdpcDecoding<MAX_BITS, MAX_MESSAGES> {
+        assert_eq!(self.matrix.codeword_bits(), input.len());
+
+        // The llrs are limited to be symmetric so that they can always be negated
+        let app = input.iter().map(|llr| (*llr).max(-Llr::MAX)).collect();
+        let mut messages = Vec::new();
+        messages.resize_default(self.matrix.messages()).unwrap();
+
+        LdpcDecoding {
+            matrix: self.matrix,
+            min_sum: self.min_sum,
+            app,
+            messages,
+        }
+    }
+}
+
+pub struct LdpcDecoding<const MAX_BITS: usize, const MAX_MESSAGES: usize> {
+    matrix: QcMatrix,
+    min_sum: MinSum,
+    app: Vec<Llr, MAX_BITS>,
+    messages: Vec<Llr, MAX_MESSAGES>,
+}
+
+impl<const MAX_BITS: usize, const MAX_MESSAGES: usize> LdpcDecoding<MAX_BITS, MAX_MESSAGES> {
+    /// Get the a-posteriori llrs for the codeword bits.
+    pub fn get_result(&self) -> &[Llr] {
+        &self.app
+    }
+
+    /// Get whether the hard decisions of the a-posteriori llrs satisfy all parity checks.
+    pub fn is_codeword(&self) -> bool {
+        let z = self.matrix.lifting();
+        (0..self.matrix.base().rows()).all(|row| {
+            (0..z).all(|t| {
+                !self.matrix.row(row).fold(false, |sum, (column, shift)| {
+                    sum ^ (self.app[column * z + (t + shift) % z] > 0)
+                })
+            })
+        })
+    }
+
+    /// Run decode iterations until all parity checks are satisfied.
+    ///
+    /// Returns the number of iterations that were run, or `None` if the checks were not satisfied within `max_iterations`.
+    pub fn run(&mut self, max_iterations: usize) -> Option<usize> {
+        for iteration in 0..max_iterations {
+            if self.is_codeword() {
+                return Some(iteration);
+            }
+            self.run_decode_iteration();
+        }
+        self.is_codeword().then_some(max_iterations)
+    }
+
+    /// Run a single decode iteration, i.e. update all layers once.
+    pub fn run_decode_iteration(&mut self) {
+        let mut edge = 0;
+        for row in 0..self.matrix.base().rows() {
+            let mut circulants = Vec::<(usize, usize), MAX_ROW_DEGREE>::new();
+            circulants.extend(self.matrix.row(row));
+            self.update_layer(&circulants, edge);
+            edge += circulants.len();
+        }
+    }
+
+    /// Update the check nodes in a layer, where the messages for the first circulant starts at `edge`.
+    fn update_layer(&mut self, circulants: &[(usize, usize)], edge: usize) {
+        let z = self.matrix.lifting();
+        let zero = Lanes::splat(0);
+        let mut q = [zero; MAX_ROW_DEGREE];
+
+        for t0 in (0..z).step_by(LANES) {
+            let check = Simd::from_array(core::array::from_fn(|lane| t0 + lane));
+            let valid = check.simd_lt(Simd::splat(z));
+
+            // Compute the variable to check node messages and find the two smallest magnitudes and the sign parity
+            let mut min1 = Lanes::splat(Llr::MAX);
+            let mut min2 = Lanes::splat(Llr::MAX);
+            let mut min_index = zero;
+            // A positive llr is a 1 bit, so the message is positive when an odd number of the other llrs are positive,
+            // which inverts the usual sign parity for odd degree layers
+            let mut parity = if circulants.len() % 2 == 1 {
+                Lanes::splat(-1)
+            } else {
+                zero
+            };
+            for (index, (column, shift)) in circulants.iter().enumerate() {
+                let app = Lanes::gather_or_default(
+                    &self.app,
+                    Self::app_indices(check, valid, z, *column, *shift),
+                );
+                let message = Lanes::gather_or_default(
+                    &self.messages,
+                    Self::message_indices(check, valid, z, edge + index),
+                );
+                let value = app
+                    .saturating_sub(message)
+                    .simd_max(Lanes::splat(-Llr::MAX));
+                let magnitude = value.abs();
+
+                let smallest = magnitude.simd_lt(min1);
+                min2 = smallest.select(min1, min2.simd_min(magnitude));
+                min1 = smallest.select(magnitude, min1);
+                min_index = smallest.select(Lanes::splat(index as i8), min_index);
+                parity ^= value;
+                q[index] = value;
+            }
+
+            let min1 = self.correct(min1);
+            let min2 = self.correct(min2);
+
+            // Compute the check to variable node messages and update the a-posteriori llrs
+            for (index, (column, shift)) in circulants.iter().enumerate() {
+                let value = q[index];
+                let magnitude = min_index
+                    .simd_eq(Lanes::splat(index as i8))
+                    .select(min2, min1);
+                let message = (parity ^ value).simd_lt(zero).select(-magnitude, magnitude);
+                let app = value.saturating_add(message);
+
+                app.scatter(
+                    &mut self.app,
+                    Self::app_indices(check, valid, z, *column, *shift),
+                );
+                message.scatter(
+                    &mut self.messages,
+                    Self::message_indices(check, valid, z, edge + index),
+                );
+            }
+        }
+    }
+
+    /// Apply the min-sum correction to the magnitudes.
+    fn correct(&self, magnitude: Lanes) -> Lanes {
+        match self.min_sum {
+            MinSum::Normalized(factor) => {
+                let scaled = (magnitude.cast::<i16>() * Simd::splat(factor as i16)) >> 3;
+                scaled.cast::<i8>()
+            }
+            MinSum::Offset(offset) => magnitude
+                .saturating_sub(Lanes::splat(offset))
+                .simd_max(Lanes::splat(0)),
+        }
+    }
+
+    /// Get the indices of the variable nodes in a circulant for the check nodes,
+    /// where indices for invalid check nodes are out of bounds.
+    fn app_indices(
+        check: Simd<usize, LANES>,
+        valid: Mask<isize, LANES>,
+        z: usize,
+        column: usize,
+        shift: usize,
+    ) -> Simd<usize, LANES> {
+        let splat = Simd::splat;
+        let position = check + splat(shift);
+        let position = position
+            .simd_ge(splat(z))
+            .select(position - splat(z), position);
+        valid.select(splat(column * z) + position, splat(usize::MAX))
+    }
+
+    /// Get the indices of the messages for the check nodes in a circulant,
+    /// where indices for invalid check nodes are out of bounds.
+    fn message_indices(
+        check: Simd<usize, LANES>,
+        valid: Mask<isize, LANES>,
+        z: usize,
+        edge: usize,
+    ) -> Simd<usize, LANES> {
+        valid.select(Simd::splat(edge * z) + check, Simd::splat(usize::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitvec::prelude::*;
+
+    use crate::{
+        ldpc::{ieee80211n, LdpcEncoder},
+        LlrMul,
+    };
+
+    use super::*;
+
+    fn encode() -> [u8; 81] {
+        let matrix = ieee80211n::RATE_1_2_N648;
+        let source: [u8; 41] = core::array::from_fn(|i| (i * 37 + i / 3) as u8);
+        let mut codeword = [0u8; 81];
+        LdpcEncoder::new(matrix).encode(&source.view_bits()[..324], codeword.view_bits_mut());
+        codeword
+    }
+
+    fn to_llrs(codeword: &[u8; 81]) -> std::vec::Vec<Llr> {
+        codeword.view_bits::<Msb0>()[..648]
+            .iter()
+            .map(|bit| bit.mul(8))
+            .collect()
+    }
+
+    #[test]
+    fn can_decode_without_errors() {
+        // Given
+        let codeword = encode();
+        let input = to_llrs(&codeword);
+        let decoder = LdpcDecoder::<648, 2376>::new(ieee80211n::RATE_1_2_N648, MinSum::Offset(1));
+
+        // When
+        let mut decoding = decoder.decode(&input);
+        let iterations = decoding.run(10);
+
+        // Then
+        assert_eq!(Some(0), iterations);
+    }
+
+    #[test]
+    fn can_decode_normalized() {
+        assert_decode(MinSum::Normalized(6));
+    }
+
+    #[test]
+    fn can_decode_offset() {
+        assert_decode(MinSum::Offset(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "The normalization factor must be at most 8")]
+    fn rejects_amplifying_normalization() {
+        LdpcDecoder::<648, 2376>::new(ieee80211n::RATE_1_2_N648, MinSum::Normalized(9));
+    }
+
+    fn assert_decode(min_sum: MinSum) {
+        // Given
+        let codeword = encode();
+        let mut input = to_llrs(&codeword);
+        for index in (3..648).step_by(23) {
+            input[index] = -input[index] / 2;
+        }
+        for index in (10..648).step_by(41) {
+            input[index] = 0;
+        }
+        let decoder = LdpcDecoder::<648, 2376>::new(ieee80211n::RATE_1_2_N648, min_sum);
+
+        // When
+        let mut decoding = decoder.decode(&input);
+        assert!(!decoding.is_codeword());
+        let iterations = decoding.run(20);
+
+        // Then
+        assert!(iterations.unwrap() > 0);
+        for (llr, bit) in decoding
+            .get_result()
+            .iter()
+            .zip(codeword.view_bits::<Msb0>())
+        {
+            assert_eq!(*bit, *llr > 0);
+        }
+    }
+}
@@ -0,0 +1,198 @@
+use bitvec::prelude::*;
+use heapless::Vec;
+
+use super::QcMatrix;
+
+/// The maximum number of block rows in the base graph.
+const MAX_ROWS: usize = 64;
+
+/// Systematic encoder for quasi-cyclic LDPC codes.
+///
+/// The first parity block is found by summing the core rows of the parity-check matrix,
+/// and the remaining parity blocks are then solved one at a time in a precomputed order.
+/// The codeword is the source bits followed by the parity bits.
+pub struct LdpcEncoder {
+    matrix: QcMatrix,
+    /// The column and lifted shift of the first parity block.
+    first_parity: (usize, usize),
+    /// The `(row, column)` solving order for the remaining parity blocks.
+    order: Vec<(u8, u8), MAX_ROWS>,
+}
+
+impl LdpcEncoder {
+    pub fn new(matrix: QcMatrix) -> Self {
+        let base = matrix.base();
+        assert!(base.rows() <= MAX_ROWS);
+        assert!(base.columns() <= u8::MAX as usize);
+
+        let first_parity = Self::find_first_parity(&matrix);
+        let order = Self::find_order(&matrix, first_parity.0);
+
+        Self {
+            matrix,
+            first_parity,
+            order,
+        }
+    }
+
+    /// Find the single parity column that remains after summing the core rows,
+    /// where all other parity columns must cancel.
+    fn find_first_parity(matrix: &QcMatrix) -> (usize, usize) {
+        let base = matrix.base();
+        let mut first_parity = None;
+        for column in base.systematic_columns()..base.columns() {
+            // Find the shifts that occur an odd number of times in the column
+            let mut remaining = Vec::<usize, MAX_ROWS>::new();
+            for row in 0..base.core_rows() {
+                if let Some(shift) = matrix.shift(row, column) {
+                    if let Some(index) = remaining.iter().position(|x| *x == shift) {
+                        remaining.swap_remove(index);
+                    } else {
+                        remaining.push(shift).unwrap();
+                    }
+                }
+            }
+
+            match remaining.len() {
+                0 => {}
+                1 => {
+                    assert!(
+                        first_parity.is_none(),
+                        "The core has no single parity column"
+                    );
+                    first_parity = Some((column, remaining[0]));
+                }
+                _ => panic!("The core parity columns do not cancel"),
+            }
+        }
+        first_parity.expect("The core has no single parity column")
+    }
+
+    /// Find the order in which the remaining parity columns can be solved one at a time.
+    fn find_order(matrix: &QcMatrix, first_parity: usize) -> Vec<(u8, u8), MAX_ROWS> {
+        let base = matrix.base();
+        let systematic = base.systematic_columns();
+        let mut solved = 1u64 << (first_parity - systematic);
+        let all = if base.rows() == 64 {
+            u64::MAX
+        } else {
+            (1 << base.rows()) - 1
+        };
+
+        let mut order = Vec::new();
+        while solved != all {
+            let mut progress = false;
+            for row in 0..base.rows() {
+                let mut unknown = matrix
+                    .row(row)
+                    .filter(|(column, _)| *column >= systematic)
+                    .map(|(column, _)| column - systematic)
+                    .filter(|parity| solved & (1 << parity) == 0);
+                if let (Some(parity), None) = (unknown.next(), unknown.next()) {
+                    solved |= 1 << parity;
+                    order
+                        .push((row as u8, (systematic + parity) as u8))
+                        .unwrap();
+                    progress = true;
+                }
+            }
+            assert!(
+                progress,
+                "The parity columns cannot be solved one at a time"
+            );
+        }
+        order
+    }
+
+    /// Encode the `K` source bits into the `N` bit codeword.
+    pub fn encode(&self, source: &BitSlice<u8, Msb0>, codeword: &mut BitSlice<u8, Msb0>) {
+        let matrix = &self.matrix;
+        let base = matrix.base();
+        let z = matrix.lifting();
+        assert_eq!(matrix.source_bits(), source.len());
+        assert!(codeword.len() >= matrix.codeword_bits());
+
+        codeword[..source.len()].copy_from_bitslice(source);
+
+        // The sum of the core rows gives P^s p0 = sum of the systematic contributions
+        let (first_column, first_shift) = self.first_parity;
+        for t in 0..z {
+            let mut sum = false;
+            for row in 0..base.core_rows() {
+                for (column, shift) in matrix.row(row) {
+                    if column < base.systematic_columns() {
+                        sum ^= codeword[column * z + (t + shift) % z];
+                    }
+                }
+            }
+            codeword.set(first_column * z + (t + first_shift) % z, sum);
+        }
+
+        // Solve each remaining parity column from a row where it is the only unknown
+        for (row, unknown) in self.order.iter() {
+            let (row, unknown) = (*row as usize, *unknown as usize);
+            let unknown_shift = matrix.shift(row, unknown).unwrap();
+            for t in 0..z {
+                let sum = matrix
+                    .row(row)
+                    .filter(|(column, _)| *column != unknown)
+                    .fold(false, |sum, (column, shift)| {
+                        sum ^ codeword[column * z + (t + shift) % z]
+                    });
+                codeword.set(unknown * z + (t + unknown_shift) % z, sum);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ldpc::{ieee80211n, BaseGraph};
+
+    use super::*;
+
+    #[test]
+    fn can_encode() {
+        // Given
+        let matrix = ieee80211n::RATE_1_2_N648;
+        let encoder = LdpcEncoder::new(matrix);
+        let source: [u8; 41] = core::array::from_fn(|i| (i * 37 + i / 3) as u8);
+        let mut codeword = [0u8; 81];
+
+        // When
+        encoder.encode(&source.view_bits()[..324], codeword.view_bits_mut());
+
+        // Then
+        assert_eq!(
+            source.view_bits::<Msb0>()[..324],
+            codeword.view_bits::<Msb0>()[..324]
+        );
+        assert!(matrix.is_codeword(codeword.view_bits()));
+        codeword[7] ^= 0x10;
+        assert!(!matrix.is_codeword(codeword.view_bits()));
+    }
+
+    #[test]
+    fn can_encode_with_extension() {
+        // Given
+        // A core of two rows with the first parity column at shift 1, extended by an identity row
+        #[rustfmt::skip]
+        const BASE: BaseGraph = BaseGraph::new(3, 5, 2, &[
+            0, 2, 1, 0, -1,
+            3, 0, -1, 0, -1,
+            1, 0, 2, -1, 0,
+        ]);
+        let matrix = QcMatrix::new(&BASE, 4);
+        let encoder = LdpcEncoder::new(matrix);
+
+        for source in 0..=255u8 {
+            let mut codeword = [0u8; 3];
+
+            // When
+            encoder.encode(source.view_bits(), codeword.view_bits_mut());
+
+            // Then
+            assert!(matrix.is_codeword(codeword.view_bits()));
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use bitvec::prelude::*;
+
+/// The base graph (prototype matrix) of a quasi-cyclic LDPC code.
+///
+/// Each entry is the shift value of a `Z x Z` circulant permutation matrix, or `-1` for the zero matrix.
+/// The shift of the lifted circulant is the entry modulo `Z`,
+/// so the same base graph can be lifted to any size as in 5G NR.
+///
+/// The last `rows` columns are the parity columns. The first `core_rows` rows must have the double diagonal core structure,
+/// i.e. summing the core rows cancels all parity columns except one, which is a single circulant.
+/// The remaining parity columns must then be solvable one at a time, as for the dual diagonal of 802.11n
+/// and the identity extension of 5G NR.
+pub struct BaseGraph {
+    rows: usize,
+    columns: usize,
+    core_rows: usize,
+    shifts: &'static [i16],
+}
+
+impl BaseGraph {
+    /// Create a new base graph from the row major shift values.
+    pub const fn new(
+        rows: usize,
+        columns: usize,
+        core_rows: usize,
+        shifts: &'static [i16],
+    ) -> Self {
+        assert!(rows < columns);
+        assert!(core_rows > 0 && core_rows <= rows);
+        assert!(shifts.len() == rows * columns);
+        Self {
+            rows,
+            columns,
+            core_rows,
+            shifts,
+        }
+    }
+
+    /// The number of block rows.
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// The number of block columns.
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// The number of block rows in the core.
+    pub const fn core_rows(&self) -> usize {
+        self.core_rows
+    }
+
+    /// The number of systematic block columns.
+    pub const fn systematic_columns(&self) -> usize {
+        self.columns - self.rows
+    }
+
+    /// Get the entry at `row` and `column`, or `None` for the zero matrix.
+    pub const fn get(&self, row: usize, column: usize) -> Option<usize> {
+        let shift = self.shifts[row * self.columns + column];
+        if shift >= 0 {
+            Some(shift as usize)
+        } else {
+            None
+        }
+    }
+}
+
+/// A quasi-cyclic parity-check matrix, i.e. a [`BaseGraph`] lifted by `Z`.
+///
+/// The circulant with shift `s` maps row `t` to column `(t + s) mod Z` within the block.
+#[derive(Clone, Copy)]
+pub struct QcMatrix {
+    base: &'static BaseGraph,
+    lifting: usize,
+}
+
+impl QcMatrix {
+    /// Create a new matrix
+    ///
+    /// # Arguments
+    ///
+    /// * `base` - The base graph.
+    /// * `lifting` - The lifting size `Z`.
+    pub const fn new(base: &'static BaseGraph, lifting: usize) -> Self {
+        assert!(lifting > 0);
+        Self { base, lifting }
+    }
+
+    /// The base graph.
+    pub const fn base(&self) -> &'static BaseGraph {
+        self.base
+    }
+
+    /// The lifting size `Z`.
+    pub const fn lifting(&self) -> usize {
+        self.lifting
+    }
+
+    /// The number of source bits `K`.
+    pub const fn source_bits(&self) -> usize {
+        self.base.systematic_columns() * self.lifting
+    }
+
+    /// The number of parity bits.
+    pub const fn parity_bits(&self) -> usize {
+        self.base.rows * self.lifting
+    }
+
+    /// The number of codeword bits `N`.
+    pub const fn codeword_bits(&self) -> usize {
+        self.base.columns * self.lifting
+    }
+
+    /// The number of circulants (non-zero entries) in the base graph.
+    pub const fn circulants(&self) -> usize {
+        let mut count = 0;
+        let mut index = 0;
+        while index < self.base.shifts.len() {
+            if self.base.shifts[index] >= 0 {
+                count += 1;
+            }
+            index += 1;
+        }
+        count
+    }
+
+    /// The number of check to variable node messages, i.e. the number of ones in the matrix.
+    pub const fn messages(&self) -> usize {
+        self.circulants() * self.lifting
+    }
+
+    /// Get the lifted shift at `row` and `column`, or `None` for the zero matrix.
+    pub const fn shift(&self, row: usize, column: usize) -> Option<usize> {
+        match self.base.get(row, column) {
+            Some(shift) => Some(shift % self.lifting),
+            None => None,
+        }
+    }
+
+    /// Get the `(column, shift)` of the circulants in a block row.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.base.columns)
+            .filter_map(move |column| self.shift(row, column).map(|shift| (column, shift)))
+    }
+
+    /// Get whether all parity checks are satisfied for the codeword bits.
+    pub fn is_codeword(&self, codeword: &BitSlice<u8, Msb0>) -> bool {
+        assert!(codeword.len() >= self.codeword_bits());
+        let z = self.lifting;
+        (0..self.base.rows).all(|row| {
+            (0..z).all(|t| {
+                !self.row(row).fold(false, |sum, (column, shift)| {
+                    sum ^ codeword[column * z + (t + shift) % z]
+                })
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ldpc::ieee80211n;
+
+    #[test]
+    fn can_lift() {
+        // Given
+        let matrix = ieee80211n::RATE_1_2_N648;
+
+        // When
+        let circulants = matrix.circulants();
+
+        // Then
+        assert_eq!(27, matrix.lifting());
+        assert_eq!(324, matrix.source_bits());
+        assert_eq!(648, matrix.codeword_bits());
+        assert_eq!(88, circulants);
+        assert_eq!(88 * 27, matrix.messages());
+        assert_eq!(Some(22), matrix.shift(1, 0));
+        assert_eq!(None, matrix.shift(0, 1));
+        assert_eq!(
+            [(0, 0), (4, 0), (5, 0), (8, 0), (11, 0), (12, 1), (13, 0)].to_vec(),
+            matrix.row(0).collect::<Vec<_>>()
+        );
+    }
+}
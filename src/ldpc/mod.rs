@@ -0,0 +1,9 @@
+mod decoder;
+mod encoder;
+pub mod ieee80211n;
+mod matrix;
+pub mod nr;
+
+pub use decoder::{LdpcDecoder, LdpcDecoding, MinSum};
+pub use encoder::LdpcEncoder;
+pub use matrix::{BaseGraph, QcMatrix};
@@ -2,11 +2,20 @@
 #![feature(const_trait_impl)]
 #![feature(portable_simd)]
 
+pub mod bch;
 mod bitview;
+pub mod block;
 pub mod catalog;
 pub mod convolutional;
+pub mod crc;
+pub mod duobinary;
 pub mod interleaver;
+pub mod ldpc;
+pub mod polar;
 pub mod ratematching;
+pub mod reedsolomon;
+pub mod sccc;
+pub mod tpc;
 pub mod turbo;
 
 pub type Llr = i8;
@@ -0,0 +1,492 @@
+use bitvec::prelude::*;
+
+use crate::Llr;
+
+/// The largest mother code length `N`.
+pub const MAX_BLOCK_BITS: usize = 1024;
+
+/// The smallest mother code length exponent `n_min`.
+const MIN_LOG2: usize = 5;
+
+/// The subblock interleaver pattern `P(i)` per 38.212 Table 5.4.1.1-1.
+const SUBBLOCK_PATTERN: [usize; 32] = [
+    0, 1, 2, 4, 3, 5, 6, 7, 8, 16, 9, 17, 10, 18, 11, 19, 12, 20, 13, 21, 14, 22, 15, 23, 24, 25,
+    26, 28, 27, 29, 30, 31,
+];
+
+type BitSet = BitArr!(for MAX_BLOCK_BITS, in u32);
+
+/// The polar sequence `Q_0^(N_max - 1)` per 38.212 Table 5.3.1.2-1 in increasing order of reliability.
+///
+/// The sequence for a smaller `N` is the subsequence of the indices less than `N`.
+#[rustfmt::skip]
+pub const RELIABILITY_SEQUENCE: [u16; MAX_BLOCK_BITS] = [
+       0,    1,    2,    4,    8,   16,   32,    3,    5,   64,    9,    6,   17,   10,   18,  128,
+      12,   33,   65,   20,  256,   34,   24,   36,    7,  129,   66,  512,   11,   40,   68,  130,
+      19,   13,   48,   14,   72,  257,   21,  132,   35,  258,   26,  513,   80,   37,   25,   22,
+     136,  260,  264,   38,  514,   96,   67,   41,  144,   28,   69,   42,  516,   49,   74,  272,
+     160,  520,  288,  528,  192,  544,   70,   44,  131,   81,   50,   73,   15,  320,  133,   52,
+      23,  134,  384,   76,  137,   82,   56,   27,   97,   39,  259,   84,  138,  145,  261,   29,
+      43,   98,  515,   88,  140,   30,  146,   71,  262,  265,  161,  576,   45,  100,  640,   51,
+     148,   46,   75,  266,  273,  517,  104,  162,   53,  193,  152,   77,  164,  768,  268,  274,
+     518,   54,   83,   57,  521,  112,  135,   78,  289,  194,   85,  276,  522,   58,  168,  139,
+      99,   86,   60,  280,   89,  290,  529,  524,  196,  141,  101,  147,  176,  142,  530,  321,
+      31,  200,   90,  545,  292,  322,  532,  263,  149,  102,  105,  304,  296,  163,   92,   47,
+     267,  385,  546,  324,  208,  386,  150,  153,  165,  106,   55,  328,  536,  577,  548,  113,
+     154,   79,  269,  108,  578,  224,  166,  519,  552,  195,  270,  641,  523,  275,  580,  291,
+      59,  169,  560,  114,  277,  156,   87,  197,  116,  170,   61,  531,  525,  642,  281,  278,
+     526,  177,  293,  388,   91,  584,  769,  198,  172,  120,  201,  336,   62,  282,  143,  103,
+     178,  294,   93,  644,  202,  592,  323,  392,  297,  770,  107,  180,  151,  209,  284,  648,
+      94,  204,  298,  400,  608,  352,  325,  533,  155,  210,  305,  547,  300,  109,  184,  534,
+     537,  115,  167,  225,  326,  306,  772,  157,  656,  329,  110,  117,  212,  171,  776,  330,
+     226,  549,  538,  387,  308,  216,  416,  271,  279,  158,  337,  550,  672,  118,  332,  579,
+     540,  389,  173,  121,  553,  199,  784,  179,  228,  338,  312,  704,  390,  174,  554,  581,
+     393,  283,  122,  448,  353,  561,  203,   63,  340,  394,  527,  582,  556,  181,  295,  285,
+     232,  124,  205,  182,  643,  562,  286,  585,  299,  354,  211,  401,  185,  396,  344,  586,
+     645,  593,  535,  240,  206,   95,  327,  564,  800,  402,  356,  307,  301,  417,  213,  568,
+     832,  588,  186,  646,  404,  227,  896,  594,  418,  302,  649,  771,  360,  539,  111,  331,
+     214,  309,  188,  449,  217,  408,  609,  596,  551,  650,  229,  159,  420,  310,  541,  773,
+     610,  657,  333,  119,  600,  339,  218,  368,  652,  230,  391,  313,  450,  542,  334,  233,
+     555,  774,  175,  123,  658,  612,  341,  777,  220,  314,  424,  395,  673,  583,  355,  287,
+     183,  234,  125,  557,  660,  616,  342,  316,  241,  778,  563,  345,  452,  397,  403,  207,
+     674,  558,  785,  432,  357,  187,  236,  664,  624,  587,  780,  705,  126,  242,  565,  398,
+     346,  456,  358,  405,  303,  569,  244,  595,  189,  566,  676,  361,  706,  589,  215,  786,
+     647,  348,  419,  406,  464,  680,  801,  362,  590,  409,  570,  788,  597,  572,  219,  311,
+     708,  598,  601,  651,  421,  792,  802,  611,  602,  410,  231,  688,  653,  248,  369,  190,
+     364,  654,  659,  335,  480,  315,  221,  370,  613,  422,  425,  451,  614,  543,  235,  412,
+     343,  372,  775,  317,  222,  426,  453,  237,  559,  833,  804,  712,  834,  661,  808,  779,
+     617,  604,  433,  720,  816,  836,  347,  897,  243,  662,  454,  318,  675,  618,  898,  781,
+     376,  428,  665,  736,  567,  840,  625,  238,  359,  457,  399,  787,  591,  678,  434,  677,
+     349,  245,  458,  666,  620,  363,  127,  191,  782,  407,  436,  626,  571,  465,  681,  246,
+     707,  350,  599,  668,  790,  460,  249,  682,  573,  411,  803,  789,  709,  365,  440,  628,
+     689,  374,  423,  466,  793,  250,  371,  481,  574,  413,  603,  366,  468,  655,  900,  805,
+     615,  684,  710,  429,  794,  252,  373,  605,  848,  690,  713,  632,  482,  806,  427,  904,
+     414,  223,  663,  692,  835,  619,  472,  455,  796,  809,  714,  721,  837,  716,  864,  810,
+     606,  912,  722,  696,  377,  435,  817,  319,  621,  812,  484,  430,  838,  667,  488,  239,
+     378,  459,  622,  627,  437,  380,  818,  461,  496,  669,  679,  724,  841,  629,  351,  467,
+     438,  737,  251,  462,  442,  441,  469,  247,  683,  842,  738,  899,  670,  783,  849,  820,
+     728,  928,  791,  367,  901,  630,  685,  844,  633,  711,  253,  691,  824,  902,  686,  740,
+     850,  375,  444,  470,  483,  415,  485,  905,  795,  473,  634,  744,  852,  960,  865,  693,
+     797,  906,  715,  807,  474,  636,  694,  254,  717,  575,  913,  798,  811,  379,  697,  431,
+     607,  489,  866,  723,  486,  908,  718,  813,  476,  856,  839,  725,  698,  914,  752,  868,
+     819,  814,  439,  929,  490,  623,  671,  739,  916,  463,  843,  381,  497,  930,  821,  726,
+     961,  872,  492,  631,  729,  700,  443,  741,  845,  920,  382,  822,  851,  730,  498,  880,
+     742,  445,  471,  635,  932,  687,  903,  825,  500,  846,  745,  826,  732,  446,  962,  936,
+     475,  853,  867,  637,  907,  487,  695,  746,  828,  753,  854,  857,  504,  799,  255,  964,
+     909,  719,  477,  915,  638,  748,  944,  869,  491,  699,  754,  858,  478,  968,  383,  910,
+     815,  976,  870,  917,  727,  493,  873,  701,  931,  756,  860,  499,  731,  823,  922,  874,
+     918,  502,  933,  743,  760,  881,  494,  702,  921,  501,  876,  847,  992,  447,  733,  827,
+     934,  882,  937,  963,  747,  505,  855,  924,  734,  829,  965,  938,  884,  506,  749,  945,
+     966,  755,  859,  940,  830,  911,  871,  639,  888,  479,  946,  750,  969,  508,  861,  757,
+     970,  919,  875,  862,  758,  948,  977,  923,  972,  761,  877,  952,  495,  703,  935,  978,
+     883,  762,  503,  925,  878,  735,  993,  885,  939,  994,  980,  926,  764,  941,  967,  886,
+     831,  947,  507,  889,  984,  751,  942,  996,  971,  890,  509,  949,  973, 1000,  892,  950,
+     863,  759, 1008,  510,  979,  953,  763,  974,  954,  879,  981,  982,  927,  995,  765,  956,
+     887,  985,  997,  986,  943,  891,  998,  766,  511,  988, 1001,  951, 1002,  893,  975,  894,
+    1009,  955, 1004, 1010,  957,  983,  958,  987, 1012,  999, 1016,  767,  989, 1003,  990, 1005,
+     959, 1011, 1013,  895, 1006, 1014, 1017, 1018,  991, 1020, 1007, 1015, 1019, 1021, 1022, 1023,
+];
+
+/// A polar code per 3GPP TS 38.212 §5.3.1 with the rate matching per §5.4.1.
+///
+/// The mother code is `x = u G_N` where `G_N` is the `n`-th Kronecker power of `[[1, 0], [1, 1]]`.
+/// The frozen set is constructed from a reliability sequence `Q_0^(N_max - 1)` in increasing order of reliability,
+/// which for NR is [`RELIABILITY_SEQUENCE`].
+#[derive(Clone, Copy)]
+pub struct PolarCode {
+    log2: usize,
+    source_bits: usize,
+    output_bits: usize,
+    information: BitSet,
+    parity_check: BitSet,
+}
+
+impl PolarCode {
+    /// Create a new polar code
+    ///
+    /// # Arguments
+    ///
+    /// * `reliability` - The reliability sequence for `N_max` or larger, in increasing order of reliability.
+    /// * `source_bits` - The number of bits `K` including the crc.
+    /// * `output_bits` - The number of rate matched bits `E`.
+    /// * `max_log2` - The maximum mother code length exponent `n_max`, i.e. 9 for downlink and 10 for uplink.
+    /// * `parity_check_bits` - The number of parity check bits `n_PC`, i.e. 3 for some uplink control information, otherwise 0.
+    /// * `weighted_parity_check_bits` - The number of parity check bits `n_PC^wm` placed at the minimum row weight.
+    pub fn new(
+        reliability: &[u16],
+        source_bits: usize,
+        output_bits: usize,
+        max_log2: usize,
+        parity_check_bits: usize,
+        weighted_parity_check_bits: usize,
+    ) -> Self {
+        assert!(max_log2 >= MIN_LOG2 && (1 << max_log2) <= MAX_BLOCK_BITS);
+        assert!(reliability.len() >= 1 << max_log2);
+        assert!(weighted_parity_check_bits <= parity_check_bits && parity_check_bits <= 8);
+
+        let log2 = Self::get_log2(source_bits, output_bits, max_log2);
+        let n = 1 << log2;
+        assert!(source_bits + parity_check_bits <= n);
+
+        let mut code = Self {
+            log2,
+            source_bits,
+            output_bits,
+            information: BitSet::ZERO,
+            parity_check: BitSet::ZERO,
+        };
+
+        // The bits that are frozen due to puncturing or shortening
+        let mut frozen = BitSet::ZERO;
+        if output_bits < n {
+            if 16 * source_bits <= 7 * output_bits {
+                for index in 0..n - output_bits {
+                    frozen.set(code.subblock_interleave(index), true);
+                }
+                let end = if 4 * output_bits >= 3 * n {
+                    (3 * n - 2 * output_bits).div_ceil(4)
+                } else {
+                    (9 * n - 4 * output_bits).div_ceil(16)
+                };
+                frozen[..end].fill(true);
+            } else {
+                for index in output_bits..n {
+                    frozen.set(code.subblock_interleave(index), true);
+                }
+            }
+        }
+
+        // The information and parity check bits are the K + n_PC most reliable bits that are not frozen
+        let mut selected = 0;
+        let mut least_reliable = [0; 8];
+        for index in reliability.iter().rev().map(|index| *index as usize) {
+            if selected == source_bits + parity_check_bits {
+                break;
+            }
+            if index < n && !frozen[index] {
+                code.information.set(index, true);
+                if selected >= source_bits + weighted_parity_check_bits {
+                    least_reliable[selected - source_bits - weighted_parity_check_bits] = index;
+                }
+                selected += 1;
+            }
+        }
+        assert_eq!(source_bits + parity_check_bits, selected);
+
+        for index in &least_reliable[..parity_check_bits - weighted_parity_check_bits] {
+            code.parity_check.set(*index, true);
+        }
+
+        // The weighted parity check bits are placed at the minimum row weight among the K most reliable bits,
+        // where the most reliable bit is used for ties
+        for _ in 0..weighted_parity_check_bits {
+            let mut best = None::<usize>;
+            let mut remaining = source_bits;
+            for index in reliability.iter().rev().map(|index| *index as usize) {
+                if remaining == 0 {
+                    break;
+                }
+                if index < n && code.information[index] && !code.parity_check[index] {
+                    remaining -= 1;
+                    if best.is_none_or(|best| index.count_ones() < best.count_ones()) {
+                        best = Some(index);
+                    }
+                }
+            }
+            code.parity_check.set(best.unwrap(), true);
+        }
+
+        code
+    }
+
+    /// Get the mother code length exponent `n` per §5.3.1.
+    fn get_log2(source_bits: usize, output_bits: usize, max_log2: usize) -> usize {
+        let ceil_log2 = output_bits.next_power_of_two().ilog2() as usize;
+        let first = if 8 * output_bits <= 9 * (1 << (ceil_log2 - 1))
+            && 16 * source_bits < 9 * output_bits
+        {
+            ceil_log2 - 1
+        } else {
+            ceil_log2
+        };
+
+        // The minimum code rate is 1/8
+        let second = (8 * source_bits).next_power_of_two().ilog2() as usize;
+
+        first.min(second).min(max_log2).max(MIN_LOG2)
+    }
+
+    /// The mother code length `N`.
+    pub const fn block_bits(&self) -> usize {
+        1 << self.log2
+    }
+
+    /// The number of source bits `K` including the crc.
+    pub const fn source_bits(&self) -> usize {
+        self.source_bits
+    }
+
+    /// The number of rate matched bits `E`.
+    pub const fn output_bits(&self) -> usize {
+        self.output_bits
+    }
+
+    /// Get whether bit `index` of `u` is frozen to zero.
+    pub fn is_frozen(&self, index: usize) -> bool {
+        !self.information[index]
+    }
+
+    /// Get whether bit `index` of `u` is a parity check bit.
+    pub fn is_parity_check(&self, index: usize) -> bool {
+        self.parity_check[index]
+    }
+
+    /// Get the subblock interleaver index `J(n)` per §5.4.1.1.
+    pub fn subblock_interleave(&self, index: usize) -> usize {
+        let n = self.block_bits();
+        let subblock = n / 32;
+        SUBBLOCK_PATTERN[index / subblock] * subblock + index % subblock
+    }
+
+    /// Get an iterator that produces the encoded bit index `J(k mod N)` for each rate matched bit,
+    /// where the bit selection is repetition, puncturing or shortening per §5.4.1.2.
+    fn selection(&self) -> impl Iterator<Item = usize> + '_ {
+        let n = self.block_bits();
+        let offset = if self.output_bits < n && 16 * self.source_bits <= 7 * self.output_bits {
+            n - self.output_bits
+        } else {
+            0
+        };
+        (0..self.output_bits).map(move |k| self.subblock_interleave((k + offset) % n))
+    }
+
+    /// Rate match the `N` encoded bits into the `E` output bits.
+    pub fn rate_match(&self, encoded: &BitSlice<u8, Msb0>, output: &mut BitSlice<u8, Msb0>) {
+        assert!(encoded.len() >= self.block_bits());
+        assert!(output.len() >= self.output_bits);
+        for (k, index) in self.selection().enumerate() {
+            output.set(k, encoded[index]);
+        }
+    }
+
+    /// Reverse the rate matching of the `E` received llrs into the `N` llrs of the encoded bits.
+    ///
+    /// The llrs of repeated bits are summed with saturation, punctured bits are erasures,
+    /// and shortened bits are a certain zero.
+    pub fn dematch(&self, input: &[Llr], output: &mut [Llr]) {
+        let n = self.block_bits();
+        assert_eq!(self.output_bits, input.len());
+        assert!(output.len() >= n);
+
+        let shortened = self.output_bits < n && 16 * self.source_bits > 7 * self.output_bits;
+        output[..n].fill(if shortened { -Llr::MAX } else { 0 });
+        let mut written = BitSet::ZERO;
+        for (llr, index) in input.iter().zip(self.selection()) {
+            output[index] = if written[index] {
+                output[index].saturating_add(*llr)
+            } else {
+                *llr
+            };
+            written.set(index, true);
+        }
+    }
+}
+
+/// Write the polarization weight (PW) reliability sequence for `N = sequence.len()`
+/// in increasing order of reliability.
+///
+/// The weight of index `i` is the sum of `2^(j/4)` over the set bits `j` of `i`.
+/// This is an approximation that agrees with the start of the NR reliability sequence,
+/// but it is not identical to [`RELIABILITY_SEQUENCE`].
+pub fn polarization_weight_sequence(sequence: &mut [u16]) {
+    assert!(sequence.len().is_power_of_two() && sequence.len() <= MAX_BLOCK_BITS);
+
+    // 2^(1/4)
+    const BETA: f64 = 1.189_207_115_002_721;
+    let weight = |index: u16| {
+        let mut weight = 0.0;
+        let mut power = 1.0;
+        for bit in 0..16 {
+            if index & (1 << bit) != 0 {
+                weight += power;
+            }
+            power *= BETA;
+        }
+        weight
+    };
+
+    for (index, value) in sequence.iter_mut().enumerate() {
+        *value = index as u16;
+    }
+    sequence.sort_unstable_by(|a, b| weight(*a).total_cmp(&weight(*b)).then(a.cmp(b)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(n: usize) -> Vec<u16> {
+        let mut sequence = vec![0; n];
+        polarization_weight_sequence(&mut sequence);
+        sequence
+    }
+
+    #[test]
+    fn can_create_sequence() {
+        // Given
+        let sequence = sequence(1024);
+
+        // When
+        let mut sorted = sequence.clone();
+        sorted.sort();
+
+        // Then
+        assert_eq!(
+            [0, 1, 2, 4, 8, 16, 3, 32, 5, 6, 9, 64].to_vec(),
+            sequence[..12]
+        );
+        assert_eq!((0..1024).collect::<Vec<u16>>(), sorted);
+    }
+
+    #[test]
+    fn reliability_sequence_is_nested_permutation() {
+        // Given
+        let mut sorted = RELIABILITY_SEQUENCE;
+
+        // When
+        sorted.sort();
+
+        // Then
+        assert_eq!(core::array::from_fn(|index| index as u16), sorted);
+        assert_eq!(
+            [0, 1, 2, 4, 8, 16, 32, 3, 5, 64, 9, 6, 17, 10, 18, 128],
+            RELIABILITY_SEQUENCE[..16]
+        );
+        assert_eq!([1019, 1021, 1022, 1023], RELIABILITY_SEQUENCE[1020..]);
+        // An index is never more reliable than an index with a superset of its bits
+        let mut position = [0; MAX_BLOCK_BITS];
+        for (rank, index) in RELIABILITY_SEQUENCE.iter().enumerate() {
+            position[*index as usize] = rank;
+        }
+        for index in 0..MAX_BLOCK_BITS {
+            for bit in (0..10).filter(|bit| index & (1 << bit) == 0) {
+                assert!(position[index] < position[index | (1 << bit)]);
+            }
+        }
+    }
+
+    #[test]
+    fn can_construct_frozen_set_from_nr_sequence() {
+        // Given
+        // PBCH with K = 56 and E = 864 uses N = 512
+        let code = PolarCode::new(&RELIABILITY_SEQUENCE, 56, 864, 9, 0, 0);
+
+        // When
+        let information: Vec<usize> = (0..512).filter(|index| !code.is_frozen(*index)).collect();
+
+        // Then
+        // The 56 information bits are the last 56 indices less than 512 in the sequence
+        let mut expected: Vec<usize> = RELIABILITY_SEQUENCE
+            .iter()
+            .rev()
+            .map(|index| *index as usize)
+            .filter(|index| *index < 512)
+            .take(56)
+            .collect();
+        expected.sort();
+        assert_eq!(expected, information);
+        assert_eq!(511, information[55]);
+    }
+
+    #[test]
+    fn can_select_block_bits() {
+        let reliability = sequence(512);
+
+        // PBCH with K = 56 and E = 864
+        assert_eq!(
+            512,
+            PolarCode::new(&reliability, 56, 864, 9, 0, 0).block_bits()
+        );
+
+        // E = 288 <= 9/8 * 256 with K/E < 9/16 gives N = 256
+        assert_eq!(
+            256,
+            PolarCode::new(&reliability, 40, 288, 9, 0, 0).block_bits()
+        );
+
+        // The code rate is at least 1/8
+        assert_eq!(
+            128,
+            PolarCode::new(&reliability, 16, 1000, 9, 0, 0).block_bits()
+        );
+        assert_eq!(
+            32,
+            PolarCode::new(&reliability, 1, 20, 9, 0, 0).block_bits()
+        );
+    }
+
+    #[test]
+    fn can_construct_frozen_set() {
+        // Given
+        let reliability = sequence(1024);
+
+        // When
+        let code = PolarCode::new(&reliability, 19, 100, 10, 3, 1);
+
+        // Then
+        let n = code.block_bits();
+        assert_eq!(128, n);
+        let information = (0..n).filter(|index| !code.is_frozen(*index)).count();
+        let parity_check = (0..n).filter(|index| code.is_parity_check(*index)).count();
+        assert_eq!(22, information);
+        assert_eq!(3, parity_check);
+
+        // The 28 punctured bits and the first 3N/4 - E/2 = 46 bits are frozen
+        assert!((0..46).all(|index| code.is_frozen(index)));
+        assert!((0..28).all(|index| code.is_frozen(code.subblock_interleave(index))));
+    }
+
+    #[test]
+    fn is_subblock_permutation() {
+        // Given
+        let reliability = sequence(512);
+        let code = PolarCode::new(&reliability, 100, 400, 9, 0, 0);
+
+        // When
+        let mut indices: Vec<usize> = (0..512)
+            .map(|index| code.subblock_interleave(index))
+            .collect();
+
+        // Then
+        assert_eq!([0, 1, 2, 3, 4, 5, 6, 7].to_vec(), indices[..8]);
+        assert_eq!(64, indices[48]);
+        assert_eq!(48, indices[64]);
+        indices.sort();
+        assert_eq!((0..512).collect::<Vec<usize>>(), indices);
+    }
+
+    #[test]
+    fn can_dematch() {
+        // Given
+        let reliability = sequence(512);
+        let repeated = PolarCode::new(&reliability, 20, 300, 9, 0, 0);
+        let punctured = PolarCode::new(&reliability, 40, 200, 9, 0, 0);
+        let shortened = PolarCode::new(&reliability, 120, 200, 9, 0, 0);
+
+        for (code, erased) in [(repeated, 0), (punctured, 0), (shortened, -Llr::MAX)] {
+            let n = code.block_bits();
+            let input = vec![1; code.output_bits()];
+            let mut output = vec![0; n];
+
+            // When
+            code.dematch(&input, &mut output);
+
+            // Then
+            let transmitted = output.iter().filter(|llr| **llr > 0).count();
+            assert_eq!(code.output_bits().min(n), transmitted);
+            let repetitions = output.iter().filter(|llr| **llr == 2).count();
+            assert_eq!(code.output_bits().saturating_sub(n), repetitions);
+            assert!(output.iter().all(|llr| *llr > 0 || *llr == erased));
+        }
+    }
+}
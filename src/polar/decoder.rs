@@ -0,0 +1,378 @@
+use bitvec::prelude::*;
+use heapless::Vec;
+
+use crate::{crc::Crc, Llr};
+
+use super::{encoder::next_register, PolarCode};
+
+/// The maximum list size.
+const MAX_LIST_SIZE: usize = 8;
+
+/// Successive cancellation list (SCL) decoder for polar codes.
+///
+/// The list size 1 gives the plain successive cancellation (SC) decoder.
+/// When a crc is given, the decoded bits are taken from the most likely path that passes the crc (CA-SCL).
+/// The llrs of each path are stored for the nodes of the decoding tree, so `MAX_N` must be at least the mother code length `N`.
+pub struct PolarDecoder<const MAX_N: usize> {
+    code: PolarCode,
+    list_size: usize,
+    crc: Option<Crc>,
+}
+
+#[derive(Clone)]
+struct Path<const MAX_N: usize> {
+    /// The llrs of the node at each layer, where the node of length `l` is stored at `l..2l`.
+    llrs: [Llr; MAX_N],
+    /// The re-encoded bits of the latest left child node at each layer, stored as the llrs.
+    partial: [u8; MAX_N],
+    /// The decided bits of `u`.
+    bits: [u8; MAX_N],
+    metric: u32,
+    register: u8,
+}
+
+impl<const MAX_N: usize> PolarDecoder<MAX_N> {
+    /// Create a new decoder
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The polar code.
+    /// * `list_size` - The list size `L` between 1 and 8.
+    /// * `crc` - The crc appended to the source bits, if any.
+    pub fn new(code: PolarCode, list_size: usize, crc: Option<Crc>) -> Self {
+        assert!(code.block_bits() <= MAX_N);
+        assert!(list_size > 0 && list_size <= MAX_LIST_SIZE);
+        Self {
+            code,
+            list_size,
+            crc,
+        }
+    }
+
+    /// Decode the `N` llrs of the encoded bits into the `K` source bits.
+    ///
+    /// Returns whether the crc of the decoded bits is valid, which is always true when there is no crc.
+    pub fn decode(&self, input: &[Llr], output: &mut BitSlice<u8, Msb0>) -> bool {
+        let code = &self.code;
+        let n = code.block_bits();
+        let log2 = n.ilog2() as usize;
+        assert_eq!(n, input.len());
+        assert!(output.len() >= code.source_bits());
+
+        // The llrs are limited to be symmetric so that they can always be negated
+        let mut channel = [0; MAX_N];
+        for (channel, llr) in channel.iter_mut().zip(input) {
+            *channel = (*llr).max(-Llr::MAX);
+        }
+        let channel = &channel[..n];
+
+        let mut paths = Vec::<Path<MAX_N>, MAX_LIST_SIZE>::new();
+        paths
+            .push(Path {
+                llrs: [0; MAX_N],
+                partial: [0; MAX_N],
+                bits: [0; MAX_N],
+                metric: 0,
+                register: 0,
+            })
+            .ok()
+            .unwrap();
+        let mut scratch = [0; MAX_N];
+
+        for index in 0..n {
+            for path in paths.iter_mut() {
+                path.update_llrs(channel, index, log2, n);
+            }
+
+            if code.is_frozen(index) || code.is_parity_check(index) {
+                for path in paths.iter_mut() {
+                    let information = code.is_frozen(index).then_some(false);
+                    let bit = next_register(&mut path.register, information);
+                    path.decide(index, bit);
+                }
+            } else {
+                self.split(&mut paths, index);
+            }
+
+            for path in paths.iter_mut() {
+                path.update_partial(index, log2, n, &mut scratch);
+            }
+        }
+
+        // Select the most likely path, preferring the paths that pass the crc
+        let mut best = None::<(bool, &Path<MAX_N>)>;
+        for path in paths.iter() {
+            self.write_source(path, output);
+            let valid = self
+                .crc
+                .is_none_or(|crc| crc.check(&output[..code.source_bits()]));
+            let better = match best {
+                None => true,
+                Some((best_valid, best)) => {
+                    (valid && !best_valid) || (valid == best_valid && path.metric < best.metric)
+                }
+            };
+            if better {
+                best = Some((valid, path));
+            }
+        }
+
+        let (valid, path) = best.unwrap();
+        self.write_source(path, output);
+        valid
+    }
+
+    /// Split each path on an information bit and keep the `L` most likely paths.
+    fn split(&self, paths: &mut Vec<Path<MAX_N>, MAX_LIST_SIZE>, index: usize) {
+        // The candidates are (metric, path, bit)
+        let mut candidates = Vec::<(u32, usize, bool), { 2 * MAX_LIST_SIZE }>::new();
+        for (position, path) in paths.iter().enumerate() {
+            for bit in [false, true] {
+                candidates
+                    .push((path.metric + path.penalty(bit), position, bit))
+                    .unwrap();
+            }
+        }
+        candidates.sort_unstable();
+        candidates.truncate(self.list_size);
+
+        // Find the surviving bits of each path
+        let mut survivors = [[false; 2]; MAX_LIST_SIZE];
+        for (_, position, bit) in candidates.iter() {
+            survivors[*position][*bit as usize] = true;
+        }
+
+        // Remove the paths without surviving children, and clone the paths where both children survive
+        for position in (0..paths.len()).rev() {
+            if survivors[position] == [false, false] {
+                paths.swap_remove(position);
+                survivors[position] = survivors[paths.len()];
+            }
+        }
+        for position in 0..paths.len() {
+            match survivors[position] {
+                [true, true] => {
+                    let mut clone = paths[position].clone();
+                    clone.decide_information(index, true);
+                    paths.push(clone).ok().unwrap();
+                    paths[position].decide_information(index, false);
+                }
+                [zero, _] => paths[position].decide_information(index, !zero),
+            }
+        }
+    }
+
+    /// Write the source bits from the information bits of a path.
+    fn write_source(&self, path: &Path<MAX_N>, output: &mut BitSlice<u8, Msb0>) {
+        let code = &self.code;
+        let information = (0..code.block_bits())
+            .filter(|index| !code.is_frozen(*index) && !code.is_parity_check(*index));
+        for (k, index) in information.enumerate() {
+            output.set(k, path.bits[index] != 0);
+        }
+    }
+}
+
+impl<const MAX_N: usize> Path<MAX_N> {
+    /// Compute the llr of bit `index` of `u` at the leaf, i.e. at `llrs[1]`.
+    fn update_llrs(&mut self, channel: &[Llr], index: usize, log2: usize, n: usize) {
+        // The nodes below the lowest common ancestor of bit `index - 1` and `index` are updated,
+        // where the first of these nodes is a right child
+        let first_layer = if index == 0 {
+            1
+        } else {
+            log2 - index.trailing_zeros() as usize
+        };
+
+        for layer in first_layer..=log2 {
+            let len = n >> layer;
+            for j in 0..len {
+                let (a, b) = if layer == 1 {
+                    (channel[j], channel[j + len])
+                } else {
+                    (self.llrs[2 * len + j], self.llrs[3 * len + j])
+                };
+
+                self.llrs[len + j] = if layer == first_layer && index != 0 {
+                    // g: the left child bits are known
+                    if self.partial[len + j] != 0 {
+                        b.saturating_sub(a)
+                    } else {
+                        b.saturating_add(a)
+                    }
+                } else {
+                    // f: a positive llr is a 1 bit, so the sign is inverted compared to the usual min-sum
+                    let magnitude = a.abs().min(b.abs());
+                    if (a ^ b) < 0 {
+                        magnitude
+                    } else {
+                        -magnitude
+                    }
+                }
+                .max(-Llr::MAX);
+            }
+        }
+    }
+
+    /// Get the path metric penalty for deciding `bit` at the leaf.
+    fn penalty(&self, bit: bool) -> u32 {
+        let llr = self.llrs[1];
+        if (llr > 0) != bit && llr != 0 {
+            llr.unsigned_abs() as u32
+        } else {
+            0
+        }
+    }
+
+    fn decide(&mut self, index: usize, bit: bool) {
+        self.metric += self.penalty(bit);
+        self.bits[index] = bit as u8;
+    }
+
+    fn decide_information(&mut self, index: usize, bit: bool) {
+        self.decide(index, bit);
+        next_register(&mut self.register, Some(bit));
+    }
+
+    /// Combine the decided bit with the completed left siblings, and store the re-encoded left child.
+    fn update_partial(&mut self, index: usize, log2: usize, n: usize, scratch: &mut [u8]) {
+        let mut layer = log2;
+        let mut node = index;
+        scratch[0] = self.bits[index];
+        while layer > 0 && node % 2 == 1 {
+            // The parent is [left ^ right, right]
+            let len = n >> layer;
+            scratch.copy_within(..len, len);
+            for j in 0..len {
+                scratch[j] = self.partial[len + j] ^ scratch[len + j];
+            }
+            layer -= 1;
+            node /= 2;
+        }
+
+        if layer > 0 {
+            let len = n >> layer;
+            self.partial[len..2 * len].copy_from_slice(&scratch[..len]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog,
+        polar::{polarization_weight_sequence, PolarEncoder, RELIABILITY_SEQUENCE},
+        LlrMul,
+    };
+
+    use super::*;
+
+    fn create_code(source_bits: usize, output_bits: usize) -> PolarCode {
+        let mut reliability = [0; 512];
+        polarization_weight_sequence(&mut reliability);
+        PolarCode::new(&reliability, source_bits, output_bits, 9, 0, 0)
+    }
+
+    fn encode(code: &PolarCode, crc: Option<Crc>) -> ([u8; 8], std::vec::Vec<Llr>) {
+        let mut source = [0u8; 8];
+        for (index, byte) in source.iter_mut().enumerate() {
+            *byte = (index as u8).wrapping_mul(0x4D) ^ 0x36;
+        }
+        let source_bits = &mut source.view_bits_mut::<Msb0>()[..code.source_bits()];
+        if let Some(crc) = crc {
+            crc.append(source_bits);
+        }
+
+        let mut encoded = [0u8; 64];
+        PolarEncoder::new(*code).encode(source_bits, encoded.view_bits_mut());
+        let llrs = encoded.view_bits::<Msb0>()[..code.block_bits()]
+            .iter()
+            .map(|bit| bit.mul(6))
+            .collect();
+        (source, llrs)
+    }
+
+    #[test]
+    fn can_decode_parity_check_bits() {
+        // Given
+        // The reference codeword of the UCI code with K = 18, E = 208, n_PC = 3 and n_PC_wm = 1,
+        // see the encoder tests
+        let code = PolarCode::new(&RELIABILITY_SEQUENCE, 18, 208, 10, 3, 1);
+        let encoded = [
+            0xBEu8, 0x28, 0x72, 0xE4, 0xEB, 0x7D, 0x27, 0xB1, 0xBE, 0x28, 0x72, 0xE4, 0xEB, 0x7D,
+            0x27, 0xB1, 0x41, 0xD7, 0x8D, 0x1B, 0x14, 0x82, 0xD8, 0x4E, 0x41, 0xD7, 0x8D, 0x1B,
+            0x14, 0x82, 0xD8, 0x4E,
+        ];
+        let input: std::vec::Vec<Llr> = encoded
+            .view_bits::<Msb0>()
+            .iter()
+            .map(|bit| bit.mul(8))
+            .collect();
+        let decoder = PolarDecoder::<256>::new(code, 1, None);
+        let mut output = [0u8; 3];
+
+        // When
+        decoder.decode(&input, output.view_bits_mut());
+
+        // Then
+        assert_eq!([0xB4, 0xE5, 0x80], output);
+    }
+
+    #[test]
+    fn can_decode_sc() {
+        // Given
+        let code = create_code(40, 128);
+        let (source, mut input) = encode(&code, None);
+        for index in (5..128).step_by(19) {
+            input[index] = -input[index];
+        }
+        let decoder = PolarDecoder::<128>::new(code, 1, None);
+        let mut output = [0u8; 5];
+
+        // When
+        let valid = decoder.decode(&input, output.view_bits_mut());
+
+        // Then
+        assert!(valid);
+        assert_eq!(source[..5], output);
+    }
+
+    #[test]
+    fn can_decode_ca_scl() {
+        // Given
+        let crc = catalog::NR_CRC11;
+        let code = create_code(64, 256);
+        let (source, mut input) = encode(&code, Some(crc));
+        for index in (3..256).step_by(9) {
+            input[index] = -input[index] / 3;
+        }
+        let mut output = [0u8; 8];
+
+        // When
+        let decoder = PolarDecoder::<256>::new(code, 8, Some(crc));
+        let valid = decoder.decode(&input, output.view_bits_mut());
+
+        // Then
+        assert!(valid);
+        assert_eq!(source, output);
+    }
+
+    #[test]
+    fn can_detect_crc_failure() {
+        // Given
+        let crc = catalog::NR_CRC6;
+        let code = create_code(32, 64);
+        let (_, mut input) = encode(&code, Some(crc));
+        for llr in input.iter_mut().step_by(2) {
+            *llr = -*llr;
+        }
+        let decoder = PolarDecoder::<64>::new(code, 4, Some(crc));
+        let mut output = [0u8; 4];
+
+        // When
+        let valid = decoder.decode(&input, output.view_bits_mut());
+
+        // Then
+        assert!(!valid);
+    }
+}
@@ -0,0 +1,117 @@
+use bitvec::prelude::*;
+
+use super::{PolarCode, MAX_BLOCK_BITS};
+
+/// Polar encoder per 38.212 §5.3.1.2.
+pub struct PolarEncoder {
+    code: PolarCode,
+}
+
+impl PolarEncoder {
+    pub const fn new(code: PolarCode) -> Self {
+        Self { code }
+    }
+
+    /// Encode the `K` source bits into the `N` encoded bits `d`.
+    ///
+    /// The source bits are placed at the information bits of `u` in increasing index order,
+    /// and the parity check bits are computed with the cyclic shift register of length 5.
+    pub fn encode(&self, source: &BitSlice<u8, Msb0>, encoded: &mut BitSlice<u8, Msb0>) {
+        let code = &self.code;
+        let n = code.block_bits();
+        assert_eq!(code.source_bits(), source.len());
+        assert!(encoded.len() >= n);
+
+        let mut source = source.iter().by_vals();
+        let mut register = 0;
+        for index in 0..n {
+            let bit = if code.is_frozen(index) {
+                next_register(&mut register, Some(false))
+            } else if code.is_parity_check(index) {
+                next_register(&mut register, None)
+            } else {
+                next_register(&mut register, source.next())
+            };
+            encoded.set(index, bit);
+        }
+
+        transform(&mut encoded[..n]);
+    }
+}
+
+/// Cycle the parity check shift register `y0..y4` at an index of `u` and get the bit,
+/// where `y0` is bit 0 of the register.
+///
+/// The register is cycled at every index, where `information` is `Some(false)` for a frozen bit
+/// and `None` for a parity check bit.
+pub(crate) fn next_register(register: &mut u8, information: Option<bool>) -> bool {
+    *register = (*register >> 1) | ((*register & 1) << 4);
+    match information {
+        Some(bit) => {
+            *register ^= bit as u8;
+            bit
+        }
+        None => *register & 1 != 0,
+    }
+}
+
+/// Multiply the bits by the generator matrix `G_N` in place.
+pub(crate) fn transform(bits: &mut BitSlice<u8, Msb0>) {
+    let n = bits.len();
+    assert!(n.is_power_of_two() && n <= MAX_BLOCK_BITS);
+    let mut half = 1;
+    while half < n {
+        for block in (0..n).step_by(2 * half) {
+            for index in block..block + half {
+                let bit = bits[index] ^ bits[index + half];
+                bits.set(index, bit);
+            }
+        }
+        half *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::polar::RELIABILITY_SEQUENCE;
+
+    use super::*;
+
+    #[test]
+    fn can_transform() {
+        // Given
+        let mut bits = bitarr![u8, Msb0; 0, 0, 0, 1, 0, 0, 0, 0];
+
+        // When
+        transform(&mut bits[..]);
+
+        // Then
+        // Row 3 of G_8 has ones in the columns whose index bits are a subset of the bits in 3
+        assert_eq!(bitarr![u8, Msb0; 1, 1, 1, 1, 0, 0, 0, 0], bits);
+    }
+
+    #[test]
+    fn can_encode_parity_check_bits() {
+        // Given
+        // UCI with A = 12 and the CRC6, so K = 18, n_PC = 3 and n_PC_wm = 1 for E = 208, giving N = 256
+        let code = PolarCode::new(&RELIABILITY_SEQUENCE, 18, 208, 10, 3, 1);
+        let encoder = PolarEncoder::new(code);
+        let source = [0xB4u8, 0xE5, 0x80];
+        let mut encoded = [0u8; 32];
+
+        // When
+        encoder.encode(&source.view_bits()[..18], encoded.view_bits_mut());
+
+        // Then
+        // The reference is a direct transcription of §5.3.1.2 and §5.4.1.1 with an explicit G_N,
+        // where the register is also cycled at the frozen bits, e.g. 240 to 242 and 248 before the parity check bit 252
+        assert_eq!(
+            [
+                0xBE, 0x28, 0x72, 0xE4, 0xEB, 0x7D, 0x27, 0xB1, 0xBE, 0x28, 0x72, 0xE4, 0xEB, 0x7D,
+                0x27, 0xB1, 0x41, 0xD7, 0x8D, 0x1B, 0x14, 0x82, 0xD8, 0x4E, 0x41, 0xD7, 0x8D, 0x1B,
+                0x14, 0x82, 0xD8, 0x4E
+            ],
+            encoded
+        );
+    }
+}
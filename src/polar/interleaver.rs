@@ -0,0 +1,104 @@
+use crate::interleaver::{Interleaver, InterleaverMapping};
+
+/// The largest number of source bits `K_IL_max` for the input bit interleaver.
+const MAX_SOURCE_BITS: usize = 164;
+
+/// The interleaving pattern `Π_IL_max` per 38.212 Table 5.3.1.1-1.
+#[rustfmt::skip]
+const PATTERN: [u8; MAX_SOURCE_BITS] = [
+      0,   2,   4,   7,   9,  14,  19,  20,  24,  25,  26,  28,  31,  34,  42,  45,
+     49,  50,  51,  53,  54,  56,  58,  59,  61,  62,  65,  66,  67,  69,  70,  71,
+     72,  76,  77,  81,  82,  83,  87,  88,  89,  91,  93,  95,  98, 101, 104, 106,
+    108, 110, 111, 113, 115, 118, 119, 120, 122, 123, 126, 127, 129, 132, 134, 138,
+    139, 140,   1,   3,   5,   8,  10,  15,  21,  27,  29,  32,  35,  43,  46,  52,
+     55,  57,  60,  63,  68,  73,  78,  84,  90,  92,  94,  96,  99, 102, 105, 107,
+    109, 112, 114, 116, 121, 124, 128, 130, 133, 135, 141,   6,  11,  16,  22,  30,
+     33,  36,  44,  47,  64,  74,  79,  85,  97, 100, 103, 117, 125, 131, 136, 142,
+     12,  17,  23,  37,  48,  75,  80,  86, 137, 143,  13,  18,  38, 144,  39, 145,
+     40, 146,  41, 147, 148, 149, 150, 151, 152, 153, 154, 155, 156, 157, 158, 159,
+    160, 161, 162, 163,
+];
+
+/// The polar input bit interleaver per 38.212 §5.3.1.1, used with `I_IL = 1` for the downlink.
+///
+/// Interleaved bit `k` takes source bit `Π(k)`, where `Π` is the subsequence of the entries in
+/// `Π_IL_max` that are at least `K_IL_max - K`, reduced by `K_IL_max - K`.
+/// This distributes the crc bits over the source bits for early termination.
+#[derive(Clone, Copy)]
+pub struct InputInterleaver {
+    /// The number of source bits `K` including the crc.
+    length: usize,
+}
+
+impl InputInterleaver {
+    /// Create a new interleaver for `length` source bits, at most 164.
+    pub const fn new(length: usize) -> Self {
+        assert!(length > 0 && length <= MAX_SOURCE_BITS);
+        Self { length }
+    }
+}
+
+impl Interleaver for InputInterleaver {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn get(&self, i: usize) -> usize {
+        self.iter().nth(i).unwrap().1
+    }
+
+    fn iter(&self) -> impl Iterator<Item = InterleaverMapping> {
+        let offset = MAX_SOURCE_BITS - self.length;
+        PATTERN
+            .iter()
+            .map(|index| *index as usize)
+            .filter(move |index| *index >= offset)
+            .enumerate()
+            .map(move |(i, index)| InterleaverMapping(i, index - offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_interleave() {
+        // Given
+        let interleaver = InputInterleaver::new(40);
+
+        // When
+        let permuted: Vec<usize> = interleaver.iter().map(|mapping| *mapping).collect();
+
+        // Then
+        // The entries of at least 124 in the pattern, reduced by 124
+        assert_eq!(
+            [
+                2, 3, 5, 8, 10, 14, 15, 16, 0, 4, 6, 9, 11, 17, 1, 7, 12, 18, 13, 19, 20, 21, 22,
+                23, 24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39
+            ]
+            .to_vec(),
+            permuted
+        );
+        assert_eq!(14, interleaver.get(5));
+    }
+
+    #[test]
+    fn is_permutation() {
+        for length in 1..=MAX_SOURCE_BITS {
+            // Given
+            let interleaver = InputInterleaver::new(length);
+            let mut used = [false; MAX_SOURCE_BITS];
+
+            // When
+            for mapping in interleaver.iter() {
+                assert!(!used[*mapping]);
+                used[*mapping] = true;
+            }
+
+            // Then
+            assert!(used[..length].iter().all(|used| *used));
+            assert_eq!(length, interleaver.iter().count());
+        }
+    }
+}
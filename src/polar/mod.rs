@@ -0,0 +1,9 @@
+mod code;
+mod decoder;
+mod encoder;
+mod interleaver;
+
+pub use code::{polarization_weight_sequence, PolarCode, MAX_BLOCK_BITS, RELIABILITY_SEQUENCE};
+pub use decoder::PolarDecoder;
+pub use encoder::PolarEncoder;
+pub use interleaver::InputInterleaver;
@@ -0,0 +1,465 @@
+use crate::{
+    convolutional::{bcjr::BcjrSymbol, EncoderOutput},
+    turbo::TurboSymbol,
+    CodeRate, Llr,
+};
+
+/// Puncturing pattern across the `n` output streams of a rate 1/n encoder.
+///
+/// The pattern is repeated every `period` encoder outputs.
+/// The unpunctured bits are transmitted in time order, and
+/// in stream order for the bits within the same encoder output.
+#[derive(Clone, Copy, Debug)]
+pub struct PuncturingMatrix {
+    /// The number of encoder outputs in the puncturing period.
+    pub period: usize,
+    /// The puncturing pattern for each output stream.
+    /// Bit `i` (LSB first) in row `j` is set if output bit `j` of encoder output `i` is punctured.
+    pub rows: &'static [usize],
+}
+
+impl PuncturingMatrix {
+    /// Create a new puncturing matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - The number of encoder outputs in the puncturing period.
+    /// * `rows` - The puncturing pattern for each output stream.
+    ///   At most 255 bits may be transmitted in a period, so that the rate fits in a [`CodeRate`].
+    ///
+    /// # Examples
+    /// Rows `[0b10, 0b00]` (period: 2) punctures the first stream in every second encoder output,
+    /// and gives a rate 2/3 code from a rate 1/2 code.
+    pub const fn new(period: usize, rows: &'static [usize]) -> Self {
+        assert!(period > 0 && period < usize::BITS as usize);
+        assert!(!rows.is_empty());
+        let matrix = Self { period, rows };
+        assert!(
+            matrix.transmitted_per_period() <= u8::MAX as usize,
+            "The transmitted bits per period must fit in the code rate"
+        );
+        matrix
+    }
+
+    /// The number of output streams.
+    pub const fn streams(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Get whether bit `stream` of encoder output `index` is punctured.
+    pub const fn is_punctured(&self, stream: usize, index: usize) -> bool {
+        self.rows[stream] & (1 << (index % self.period)) != 0
+    }
+
+    /// The number of transmitted bits in a puncturing period.
+    pub const fn transmitted_per_period(&self) -> usize {
+        let mask = (1 << self.period) - 1;
+        let mut transmitted = 0;
+        let mut stream = 0;
+        while stream < self.rows.len() {
+            transmitted += self.period - (self.rows[stream] & mask).count_ones() as usize;
+            stream += 1;
+        }
+        transmitted
+    }
+
+    /// The code rate after puncturing a rate 1/n code.
+    pub const fn rate(&self) -> CodeRate {
+        CodeRate {
+            k: self.period as u8,
+            n: self.transmitted_per_period() as u8,
+        }
+    }
+}
+
+/// Rate-Matching puncturer for the outputs of a rate 1/n encoder.
+///
+/// The puncturer works on whole encoder outputs, e.g. [`EncoderOutput`] words from a convolutional encoder
+/// or the systematic and parity output words from a turbo encoder.
+pub struct MatrixPuncturer {
+    matrix: PuncturingMatrix,
+    index: usize,
+}
+
+impl MatrixPuncturer {
+    /// Create a new puncturer.
+    pub const fn new(matrix: PuncturingMatrix) -> Self {
+        Self { matrix, index: 0 }
+    }
+
+    /// Get which bits of the next encoder output should be output, i.e. not punctured.
+    /// Bit `j` is set if output bit `j` should be output.
+    pub fn read_outputs(&mut self) -> EncoderOutput {
+        let mut outputs = 0;
+        for stream in 0..self.matrix.streams() {
+            if !self.matrix.is_punctured(stream, self.index) {
+                outputs |= 1 << stream;
+            }
+        }
+        self.index = (self.index + 1) % self.matrix.period;
+        outputs
+    }
+
+    /// Puncture the next encoder output.
+    ///
+    /// `write` is invoked for each bit that is not punctured in stream order,
+    /// and the number of written bits is returned.
+    pub fn puncture(&mut self, output: EncoderOutput, mut write: impl FnMut(bool)) -> usize {
+        let outputs = self.read_outputs();
+        for stream in 0..self.matrix.streams() {
+            if outputs & (1 << stream) != 0 {
+                write(output & (1 << stream) != 0);
+            }
+        }
+        outputs.count_ones() as usize
+    }
+}
+
+/// Rate-Matching depuncturer that expands received llrs to the full rate of a rate 1/n encoder.
+///
+/// An erasure, i.e. llr 0, is inserted for each punctured bit.
+pub struct MatrixDepuncturer {
+    matrix: PuncturingMatrix,
+    index: usize,
+}
+
+impl MatrixDepuncturer {
+    /// Create a new depuncturer.
+    pub const fn new(matrix: PuncturingMatrix) -> Self {
+        Self { matrix, index: 0 }
+    }
+
+    /// Get the number of received llrs needed to depuncture the next `outputs` encoder outputs.
+    pub fn input_len(&self, outputs: usize) -> usize {
+        let mut len = 0;
+        for index in self.index..self.index + outputs {
+            for stream in 0..self.matrix.streams() {
+                if !self.matrix.is_punctured(stream, index) {
+                    len += 1;
+                }
+            }
+        }
+        len
+    }
+
+    /// Depuncture received llrs.
+    ///
+    /// `output` receives `n` llrs for each encoder output, so its length must be a multiple of `n`.
+    /// `input` must hold at least [`input_len`](Self::input_len) llrs for the encoder outputs.
+    /// Returns the number of llrs consumed from `input`.
+    pub fn depuncture(&mut self, input: &[Llr], output: &mut [Llr]) -> usize {
+        let streams = self.matrix.streams();
+        assert_eq!(0, output.len() % streams);
+        assert!(
+            input.len() >= self.input_len(output.len() / streams),
+            "The input is too short for the output"
+        );
+
+        let mut consumed = 0;
+        for llrs in output.chunks_exact_mut(streams) {
+            for (stream, llr) in llrs.iter_mut().enumerate() {
+                *llr = if self.matrix.is_punctured(stream, self.index) {
+                    0
+                } else {
+                    consumed += 1;
+                    input[consumed - 1]
+                };
+            }
+            self.index = (self.index + 1) % self.matrix.period;
+        }
+
+        consumed
+    }
+
+    /// Depuncture received llrs from a rate 1/2 encoder into bcjr symbols.
+    ///
+    /// Returns the number of llrs consumed from `input`.
+    pub fn depuncture_bcjr(&mut self, input: &[Llr], output: &mut [BcjrSymbol]) -> usize {
+        assert_eq!(2, self.matrix.streams());
+        assert!(
+            input.len() >= self.input_len(output.len()),
+            "The input is too short for the output"
+        );
+
+        let mut consumed = 0;
+        for symbol in output {
+            let mut llrs = [0; 2];
+            consumed += self.depuncture(&input[consumed..], &mut llrs);
+            *symbol = BcjrSymbol::new(llrs[0], llrs[1]);
+        }
+        consumed
+    }
+
+    /// Depuncture received llrs from a turbo encoder into turbo symbols.
+    ///
+    /// Returns the number of llrs consumed from `input`.
+    pub fn depuncture_turbo(&mut self, input: &[Llr], output: &mut [TurboSymbol]) -> usize {
+        assert_eq!(3, self.matrix.streams());
+        assert!(
+            input.len() >= self.input_len(output.len()),
+            "The input is too short for the output"
+        );
+
+        let mut consumed = 0;
+        for symbol in output {
+            let mut llrs = [0; 3];
+            consumed += self.depuncture(&input[consumed..], &mut llrs);
+            *symbol = TurboSymbol::new(llrs[0], llrs[1], llrs[2]);
+        }
+        consumed
+    }
+}
+
+/// Puncturing and repetition pattern for the termination outputs of a rate 1/n encoder.
+///
+/// Bit `j` of each termination output is transmitted `repetitions[j]` times in a row,
+/// where a count of zero punctures the bit.
+/// The transmitted bits are in stream order within each termination output.
+#[derive(Clone, Copy, Debug)]
+pub struct TailPuncturing {
+    /// The number of times each output stream is transmitted.
+    pub repetitions: &'static [usize],
+}
+
+impl TailPuncturing {
+    /// Create a new tail puncturing pattern.
+    ///
+    /// # Arguments
+    ///
+    /// * `repetitions` - The number of times each output stream is transmitted.
+    pub const fn new(repetitions: &'static [usize]) -> Self {
+        assert!(!repetitions.is_empty());
+        Self { repetitions }
+    }
+
+    /// The number of output streams.
+    pub const fn streams(&self) -> usize {
+        self.repetitions.len()
+    }
+
+    /// The number of transmitted bits for each termination output.
+    pub const fn transmitted_per_output(&self) -> usize {
+        let mut transmitted = 0;
+        let mut stream = 0;
+        while stream < self.repetitions.len() {
+            transmitted += self.repetitions[stream];
+            stream += 1;
+        }
+        transmitted
+    }
+
+    /// Puncture and repeat a termination output.
+    ///
+    /// `write` is invoked for each transmitted bit in stream order,
+    /// and the number of written bits is returned.
+    pub fn puncture(&self, output: EncoderOutput, mut write: impl FnMut(bool)) -> usize {
+        for (stream, repetitions) in self.repetitions.iter().enumerate() {
+            for _ in 0..*repetitions {
+                write(output & (1 << stream) != 0);
+            }
+        }
+        self.transmitted_per_output()
+    }
+
+    /// Depuncture the received llrs for a termination output.
+    ///
+    /// The repeated llrs of each stream are combined by addition,
+    /// and an erasure, i.e. llr 0, is inserted for each punctured stream.
+    /// Returns the number of llrs consumed from `input`.
+    pub fn depuncture(&self, input: &[Llr], output: &mut [Llr]) -> usize {
+        assert_eq!(self.streams(), output.len());
+        assert!(
+            input.len() >= self.transmitted_per_output(),
+            "The input is too short for the output"
+        );
+
+        let mut consumed = 0;
+        for (llr, repetitions) in output.iter_mut().zip(self.repetitions) {
+            let combined: isize = input[consumed..consumed + repetitions]
+                .iter()
+                .map(|llr| *llr as isize)
+                .sum();
+            *llr = combined.clamp(Llr::MIN as isize, Llr::MAX as isize) as Llr;
+            consumed += repetitions;
+        }
+
+        consumed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog,
+        convolutional::{viterbi::ViterbiDecoder, ConvolutionalCodeExt, ConvolutionalEncoder},
+        LlrMul,
+    };
+
+    use super::*;
+
+    #[test]
+    fn can_get_rate() {
+        // Given
+        let matrix = PuncturingMatrix::new(3, &[0b010, 0b100]);
+
+        // When
+        let rate = matrix.rate();
+
+        // Then
+        assert_eq!(3, rate.k);
+        assert_eq!(4, rate.n);
+        assert!(!matrix.is_punctured(0, 0));
+        assert!(matrix.is_punctured(0, 1));
+        assert!(matrix.is_punctured(1, 2));
+        assert!(matrix.is_punctured(0, 4));
+    }
+
+    #[test]
+    fn can_puncture() {
+        // Given
+        let mut puncturer = MatrixPuncturer::new(catalog::CCSDS_RATE_3_4);
+        let mut written = Vec::new();
+
+        // When
+        let count: usize = [0b11, 0b10, 0b01, 0b11]
+            .iter()
+            .map(|output| puncturer.puncture(*output, |bit| written.push(bit)))
+            .sum();
+
+        // Then
+        assert_eq!(6, count);
+        assert_eq!([true; 6].to_vec(), written);
+    }
+
+    #[test]
+    fn can_depuncture() {
+        // Given
+        let mut depuncturer = MatrixDepuncturer::new(catalog::CCSDS_RATE_3_4);
+        let input = [1, 2, 3, 4, 5, 6, 7];
+        let mut output = [-1; 8];
+
+        // When
+        let consumed = depuncturer.depuncture(&input, &mut output);
+
+        // Then
+        assert_eq!(6, consumed);
+        assert_eq!([1, 2, 0, 3, 4, 0, 5, 6], output);
+        assert_eq!(2, depuncturer.input_len(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "The transmitted bits per period must fit in the code rate")]
+    fn rejects_rate_that_does_not_fit() {
+        // Given
+        let rows = &[0; 5];
+
+        // When
+        PuncturingMatrix::new(63, rows);
+
+        // Then
+    }
+
+    #[test]
+    #[should_panic(expected = "The input is too short for the output")]
+    fn cannot_depuncture_short_input() {
+        // Given
+        let mut depuncturer = MatrixDepuncturer::new(catalog::CCSDS_RATE_3_4);
+        let input = [1, 2, 3, 4, 5];
+        let mut output = [-1; 8];
+
+        // When
+        depuncturer.depuncture(&input, &mut output);
+
+        // Then
+    }
+
+    #[test]
+    fn can_depuncture_turbo() {
+        // Given
+        let matrix = PuncturingMatrix::new(2, &[0b00, 0b10, 0b01]);
+        let mut depuncturer = MatrixDepuncturer::new(matrix);
+        let input = [1, 2, 3, 4];
+        let mut output = [TurboSymbol::new(-1, -1, -1); 2];
+
+        // When
+        let consumed = depuncturer.depuncture_turbo(&input, &mut output);
+
+        // Then
+        assert_eq!(4, consumed);
+        assert_eq!(
+            (1, 2, 0),
+            (
+                output[0].systematic,
+                output[0].first_parity,
+                output[0].second_parity
+            )
+        );
+        assert_eq!(
+            (3, 0, 4),
+            (
+                output[1].systematic,
+                output[1].first_parity,
+                output[1].second_parity
+            )
+        );
+    }
+
+    #[test]
+    fn can_puncture_tail() {
+        // Given
+        let tail = TailPuncturing::new(&[2, 1, 0]);
+        let mut written = Vec::new();
+
+        // When
+        let count = tail.puncture(0b101, |bit| written.push(bit));
+
+        // Then
+        assert_eq!(3, count);
+        assert_eq!([true, true, false].to_vec(), written);
+    }
+
+    #[test]
+    fn can_depuncture_tail() {
+        // Given
+        let tail = TailPuncturing::new(&[2, 1, 0]);
+        let input = [100, 50, -3, 7];
+        let mut output = [-1; 3];
+
+        // When
+        let consumed = tail.depuncture(&input, &mut output);
+
+        // Then
+        assert_eq!(3, consumed);
+        assert_eq!([127, -3, 0], output);
+    }
+
+    #[test]
+    fn can_decode_punctured_ccsds() {
+        // Given
+        let source: Vec<bool> = (0..42).map(|i| (i * 3 + i / 5) % 4 == 1).collect();
+        let mut encoder = ConvolutionalEncoder::<catalog::CCSDS_PUNCTURED>::default();
+        let mut puncturer = MatrixPuncturer::new(catalog::CCSDS_RATE_3_4);
+        let mut transmitted = Vec::new();
+        for bit in source.iter() {
+            puncturer.puncture(encoder.get_output(*bit), |bit| transmitted.push(bit.mul(8)));
+        }
+        for _ in 0..catalog::CCSDS_PUNCTURED::mem() {
+            puncturer.puncture(encoder.get_termination_output(), |bit| {
+                transmitted.push(bit.mul(8))
+            });
+        }
+        transmitted[10] = -transmitted[10];
+        let mut depuncturer = MatrixDepuncturer::new(catalog::CCSDS_RATE_3_4);
+        let mut received = [0; 96];
+        let decoder = ViterbiDecoder::<catalog::CCSDS_PUNCTURED, 64, 48>::new(true);
+        let mut output = [false; 48];
+
+        // When
+        let consumed = depuncturer.depuncture(&transmitted, &mut received);
+        decoder.decode(&received, &mut output);
+
+        // Then
+        assert_eq!(transmitted.len(), consumed);
+        assert_eq!(source, output[..42]);
+    }
+}
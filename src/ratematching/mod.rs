@@ -1,3 +1,6 @@
+mod matrix;
 mod puncturer;
+pub mod umts;
 
+pub use matrix::{MatrixDepuncturer, MatrixPuncturer, PuncturingMatrix, TailPuncturing};
 pub use puncturer::Puncturer;
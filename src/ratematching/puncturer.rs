@@ -44,6 +44,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn default_puncturer_does_not_puncture() {
         // Given
         let mut puncturer = Puncturer::default();
@@ -56,6 +57,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn can_puncture() {
         // Given
         let mut puncturer = Puncturer::new(3, 0b101);
@@ -0,0 +1,272 @@
+use crate::Llr;
+
+/// UMTS rate matching per 3GPP TS 25.212 §4.2.7.5.
+///
+/// The rate matching pattern distributes the punctured or repeated bits evenly over the block
+/// using the error variable `e` which is updated with the parameters `e_ini`, `e_plus` and `e_minus`.
+/// A repeated bit is placed directly after the original bit.
+#[derive(Clone, Copy, Debug)]
+pub struct RateMatcher {
+    e_ini: isize,
+    e_plus: isize,
+    e_minus: isize,
+    repetition: bool,
+}
+
+impl RateMatcher {
+    /// Create a new rate matcher from the pattern parameters.
+    ///
+    /// # Arguments
+    ///
+    /// * `e_ini` - The initial value of the error variable.
+    /// * `e_plus` - The increment of the error variable.
+    /// * `e_minus` - The decrement of the error variable.
+    /// * `repetition` - Whether bits are repeated instead of punctured.
+    pub const fn new(e_ini: isize, e_plus: isize, e_minus: isize, repetition: bool) -> Self {
+        assert!(e_plus > 0);
+        assert!(e_minus >= 0);
+        Self {
+            e_ini,
+            e_plus,
+            e_minus,
+            repetition,
+        }
+    }
+
+    /// Create a rate matcher for a stream that is not a turbo parity stream,
+    /// e.g. a convolutionally coded block or turbo coded block that is repeated.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The number of bits before rate matching `X_i = N_ij`.
+    /// * `delta` - The number of bits to add (repetition) or remove (puncturing) `ΔN_ij`.
+    pub const fn for_stream(length: usize, delta: isize) -> Self {
+        Self::new(1, 2 * length as isize, 2 * delta.abs(), delta > 0)
+    }
+
+    /// Create the rate matchers for the first and second parity streams of a turbo coded block that is punctured.
+    /// The systematic stream is not punctured.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - The number of bits in each of the three streams before rate matching `X_i = N_ij/3`.
+    /// * `delta` - The total number of bits to remove from the block `ΔN_ij`, i.e. a negative number.
+    pub const fn for_turbo_parity(length: usize, delta: isize) -> (Self, Self) {
+        assert!(delta <= 0);
+        let length = length as isize;
+
+        // The first parity stream gets a = 2 and ⌊ΔN/2⌋ and the second parity stream a = 1 and ⌈ΔN/2⌉
+        let first_delta = delta.div_euclid(2);
+        let second_delta = delta - first_delta;
+        (
+            Self::new(length, 2 * length, 2 * first_delta.abs(), false),
+            Self::new(length, length, second_delta.abs(), false),
+        )
+    }
+
+    /// Get an iterator that produces the number of times each bit is transmitted,
+    /// i.e. 0 for a punctured bit, 1 for an unmodified bit and 2 or more for a repeated bit.
+    pub fn iter(&self, length: usize) -> RateMatchingIterator {
+        RateMatchingIterator {
+            matcher: *self,
+            e: self.e_ini,
+            remaining: length,
+        }
+    }
+
+    /// Get the number of bits after rate matching a block of `length` bits.
+    pub fn output_len(&self, length: usize) -> usize {
+        self.iter(length).sum()
+    }
+
+    /// Rate match a block.
+    ///
+    /// Returns the number of values written to `output`.
+    pub fn rate_match<T: Copy>(&self, input: &[T], output: &mut [T]) -> usize {
+        let mut written = 0;
+        for (value, count) in input.iter().zip(self.iter(input.len())) {
+            for _ in 0..count {
+                output[written] = *value;
+                written += 1;
+            }
+        }
+        written
+    }
+
+    /// Reverse the rate matching of a block of received llrs.
+    ///
+    /// The llrs of repeated bits are summed with saturation, and an erasure, i.e. llr 0, is inserted for each punctured bit.
+    /// `output` must have the length of the block before rate matching,
+    /// and `input` must hold at least [`output_len`](Self::output_len) llrs for that length.
+    /// Returns the number of llrs consumed from `input`.
+    pub fn dematch(&self, input: &[Llr], output: &mut [Llr]) -> usize {
+        assert!(
+            input.len() >= self.output_len(output.len()),
+            "The input is too short for the output"
+        );
+
+        let mut consumed = 0;
+        let pattern = self.iter(output.len());
+        for (llr, count) in output.iter_mut().zip(pattern) {
+            *llr = 0;
+            for _ in 0..count {
+                *llr = llr.saturating_add(input[consumed]);
+                consumed += 1;
+            }
+        }
+        consumed
+    }
+}
+
+pub struct RateMatchingIterator {
+    matcher: RateMatcher,
+    e: isize,
+    remaining: usize,
+}
+
+impl Iterator for RateMatchingIterator {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let RateMatcher {
+            e_plus,
+            e_minus,
+            repetition,
+            ..
+        } = self.matcher;
+
+        // Update the error and check whether the bit should be punctured or repeated
+        self.e -= e_minus;
+
+        if repetition {
+            let mut count = 1;
+            while self.e <= 0 {
+                count += 1;
+                self.e += e_plus;
+            }
+            Some(count)
+        } else if self.e <= 0 {
+            self.e += e_plus;
+            Some(0)
+        } else {
+            Some(1)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for RateMatchingIterator {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_puncture() {
+        // Given
+        let matcher = RateMatcher::for_stream(10, -2);
+
+        // When
+        let pattern: Vec<usize> = matcher.iter(10).collect();
+
+        // Then
+        assert_eq!([0, 1, 1, 1, 1, 0, 1, 1, 1, 1].to_vec(), pattern);
+        assert_eq!(8, matcher.output_len(10));
+    }
+
+    #[test]
+    fn can_repeat() {
+        // Given
+        let matcher = RateMatcher::for_stream(4, 2);
+        let input = [1, 2, 3, 4];
+        let mut output = [0; 6];
+
+        // When
+        let written = matcher.rate_match(&input, &mut output);
+
+        // Then
+        assert_eq!(6, written);
+        assert_eq!([1, 1, 2, 3, 3, 4], output);
+    }
+
+    #[test]
+    fn can_repeat_more_than_twice() {
+        // Given
+        let matcher = RateMatcher::for_stream(3, 7);
+
+        // When
+        let pattern: Vec<usize> = matcher.iter(3).collect();
+
+        // Then
+        assert_eq!(10, pattern.iter().sum::<usize>());
+        assert!(pattern.iter().all(|count| *count >= 3));
+    }
+
+    #[test]
+    fn has_exact_output_length() {
+        for length in 1..64 {
+            for delta in -(length as isize - 1)..(2 * length as isize) {
+                let matcher = RateMatcher::for_stream(length, delta);
+                assert_eq!(
+                    (length as isize + delta) as usize,
+                    matcher.output_len(length)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn can_puncture_turbo_parity() {
+        // Given
+        let (first, second) = RateMatcher::for_turbo_parity(40, -15);
+
+        // When
+        let first_len = first.output_len(40);
+        let second_len = second.output_len(40);
+
+        // Then
+        assert_eq!(40 - 8, first_len);
+        assert_eq!(40 - 7, second_len);
+    }
+
+    #[test]
+    fn can_dematch() {
+        // Given
+        let puncturing = RateMatcher::for_stream(10, -2);
+        let repetition = RateMatcher::for_stream(4, 2);
+        let mut punctured_output = [-1; 10];
+        let mut repeated_output = [-1; 4];
+
+        // When
+        let punctured_consumed =
+            puncturing.dematch(&[1, 2, 3, 4, 5, 6, 7, 8], &mut punctured_output);
+        let repeated_consumed = repetition.dematch(&[100, 100, 2, -3, 4, 5], &mut repeated_output);
+
+        // Then
+        assert_eq!(8, punctured_consumed);
+        assert_eq!([0, 1, 2, 3, 4, 0, 5, 6, 7, 8], punctured_output);
+        assert_eq!(6, repeated_consumed);
+        assert_eq!([127, 2, 1, 5], repeated_output);
+    }
+
+    #[test]
+    #[should_panic(expected = "The input is too short for the output")]
+    fn cannot_dematch_short_input() {
+        // Given
+        let repetition = RateMatcher::for_stream(4, 2);
+        let mut output = [-1; 4];
+
+        // When
+        repetition.dematch(&[100, 100, 2, -3, 4], &mut output);
+
+        // Then
+    }
+}
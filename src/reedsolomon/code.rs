@@ -0,0 +1,44 @@
+/// A Reed-Solomon code over GF(2^8).
+///
+/// The generator polynomial is `g(x) = (x - β^FIRST_ROOT)(x - β^(FIRST_ROOT + 1))...(x - β^(FIRST_ROOT + PARITY_LEN - 1))`,
+/// where `β = α^ROOT_SPACING` and `α` is a root of the field polynomial.
+/// Codes with `CODEWORD_LEN < 255` are shortened by virtually prepending zero symbols to the data.
+pub trait ReedSolomonCode: Default {
+    /// The primitive polynomial that defines the field, including the x^8 term, e.g. `0x11D` for x^8+x^4+x^3+x^2+1.
+    const FIELD_POLYNOMIAL: usize;
+
+    /// The exponent of `β` for the first consecutive root of the generator polynomial.
+    const FIRST_ROOT: usize;
+
+    /// The power of `α` used as the generator root `β`. Must be relatively prime to 255.
+    const ROOT_SPACING: usize;
+
+    /// The number of parity symbols `2t`.
+    const PARITY_LEN: usize;
+
+    /// The number of symbols in a codeword `n`.
+    const CODEWORD_LEN: usize;
+
+    /// Whether the symbols are represented in the CCSDS dual basis rather than the conventional basis.
+    /// The dual basis is only defined for the CCSDS field polynomial `0x187`.
+    const DUAL_BASIS: bool = false;
+}
+
+pub trait ReedSolomonCodeExt<C: ReedSolomonCode> {
+    /// The number of data symbols `k` in a codeword.
+    fn data_len() -> usize {
+        C::CODEWORD_LEN - C::PARITY_LEN
+    }
+
+    /// The number of symbol errors `t` that can be corrected when there are no erasures.
+    fn correctable_errors() -> usize {
+        C::PARITY_LEN / 2
+    }
+
+    /// The number of virtual zero symbols that are prepended to shortened codewords.
+    fn shortening() -> usize {
+        255 - C::CODEWORD_LEN
+    }
+}
+
+impl<C: ReedSolomonCode> ReedSolomonCodeExt<C> for C {}
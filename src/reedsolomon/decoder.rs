@@ -0,0 +1,413 @@
+use core::marker::PhantomData;
+
+use heapless::Vec;
+
+use crate::Llr;
+
+use super::{
+    field::{modnn, Field, A0, NN},
+    from_dual_basis, to_dual_basis, ReedSolomonCode, ReedSolomonCodeExt,
+};
+
+/// Reed-Solomon errors-and-erasures decoder.
+///
+/// The decoder computes the syndromes, finds the error and erasure locator polynomial
+/// with the Berlekamp-Massey algorithm initialized with the erasure locator,
+/// locates the errors with a Chien search and computes the error values with the Forney algorithm.
+/// A codeword with `e` errors and `f` erasures is corrected if `2e + f <= PARITY_LEN`.
+pub struct ReedSolomonDecoder<C: ReedSolomonCode> {
+    _code: PhantomData<C>,
+}
+
+impl<C: ReedSolomonCode> ReedSolomonDecoder<C> {
+    pub fn new() -> Self {
+        assert!(!C::DUAL_BASIS || C::FIELD_POLYNOMIAL == 0x187);
+        Self { _code: PhantomData }
+    }
+
+    /// Correct a codeword in place given the distinct positions of the erased symbols.
+    ///
+    /// Returns the number of corrected error and erasure positions,
+    /// or `None` if the codeword is uncorrectable in which case it is left unchanged.
+    pub fn decode(&self, codeword: &mut [u8], erasures: &[usize]) -> Option<usize> {
+        assert_eq!(C::CODEWORD_LEN, codeword.len());
+        if erasures.len() > C::PARITY_LEN {
+            return None;
+        }
+
+        if C::DUAL_BASIS {
+            for symbol in codeword.iter_mut() {
+                *symbol = from_dual_basis(*symbol);
+            }
+        }
+
+        let result = Self::decode_conventional(codeword, erasures);
+
+        if C::DUAL_BASIS {
+            for symbol in codeword.iter_mut() {
+                *symbol = to_dual_basis(*symbol);
+            }
+        }
+
+        result
+    }
+
+    /// Hard decide and correct a codeword from the llrs of its bits, msb first within each symbol.
+    ///
+    /// A symbol is erased if the magnitude of its least reliable bit llr is below `threshold`.
+    /// At most `PARITY_LEN` of the least reliable symbols are erased.
+    pub fn decode_llrs(&self, llrs: &[Llr], threshold: u8, codeword: &mut [u8]) -> Option<usize> {
+        assert_eq!(8 * C::CODEWORD_LEN, llrs.len());
+        assert_eq!(C::CODEWORD_LEN, codeword.len());
+
+        let mut candidates = Vec::<(u8, usize), NN>::new();
+        for (position, (symbol, llrs)) in codeword.iter_mut().zip(llrs.chunks_exact(8)).enumerate()
+        {
+            *symbol = llrs
+                .iter()
+                .fold(0, |symbol, llr| (symbol << 1) | (*llr > 0) as u8);
+
+            let reliability = llrs.iter().map(|llr| llr.unsigned_abs()).min().unwrap();
+            if reliability < threshold {
+                candidates.push((reliability, position)).unwrap();
+            }
+        }
+
+        candidates.sort_unstable();
+        candidates.truncate(C::PARITY_LEN);
+        let erasures: Vec<usize, NN> = candidates.iter().map(|(_, position)| *position).collect();
+
+        self.decode(codeword, &erasures)
+    }
+
+    fn decode_conventional(codeword: &mut [u8], erasures: &[usize]) -> Option<usize> {
+        let parity_len = C::PARITY_LEN;
+        let shortening = C::shortening();
+
+        // Evaluate the received polynomial at the roots of the generator polynomial
+        let mut syndromes = [0; NN];
+        let syndromes = &mut syndromes[..parity_len];
+        syndromes.fill(codeword[0]);
+        for symbol in &codeword[1..] {
+            for (i, syndrome) in syndromes.iter_mut().enumerate() {
+                *syndrome = if *syndrome == 0 {
+                    *symbol
+                } else {
+                    symbol
+                        ^ Field::<C>::exp(
+                            Field::<C>::log(*syndrome) as usize
+                                + (C::FIRST_ROOT + i) * C::ROOT_SPACING,
+                        )
+                };
+            }
+        }
+        if syndromes.iter().all(|syndrome| *syndrome == 0) {
+            return Some(0);
+        }
+        for syndrome in syndromes.iter_mut() {
+            *syndrome = Field::<C>::log(*syndrome);
+        }
+
+        // Initialize the locator to the erasure locator polynomial
+        let mut lambda = [0; NN + 1];
+        lambda[0] = 1;
+        for (i, position) in erasures.iter().enumerate() {
+            assert!(*position < C::CODEWORD_LEN);
+            let locator = C::ROOT_SPACING * (C::CODEWORD_LEN - 1 - position);
+            for j in (1..=i + 1).rev() {
+                let previous = Field::<C>::log(lambda[j - 1]);
+                if previous != A0 {
+                    lambda[j] ^= Field::<C>::exp(locator + previous as usize);
+                }
+            }
+        }
+
+        // Berlekamp-Massey, where `b` is the correction polynomial in index form
+        let mut b = [A0; NN + 1];
+        for (b, lambda) in b.iter_mut().zip(&lambda[..=parity_len]) {
+            *b = Field::<C>::log(*lambda);
+        }
+        let mut t = [0; NN + 1];
+        let erasure_count = erasures.len();
+        let mut el = erasure_count;
+        for r in erasure_count + 1..=parity_len {
+            let mut discrepancy = 0;
+            for i in 0..r {
+                if lambda[i] != 0 && syndromes[r - i - 1] != A0 {
+                    discrepancy ^= Field::<C>::exp(
+                        Field::<C>::log(lambda[i]) as usize + syndromes[r - i - 1] as usize,
+                    );
+                }
+            }
+            let discrepancy = Field::<C>::log(discrepancy) as usize;
+
+            if discrepancy != A0 as usize {
+                // T(x) = Λ(x) - Δ x B(x)
+                t[0] = lambda[0];
+                for i in 0..parity_len {
+                    t[i + 1] = if b[i] != A0 {
+                        lambda[i + 1] ^ Field::<C>::exp(discrepancy + b[i] as usize)
+                    } else {
+                        lambda[i + 1]
+                    };
+                }
+
+                if 2 * el < r + erasure_count {
+                    // B(x) = Λ(x) / Δ
+                    el = r + erasure_count - el;
+                    for (b, lambda) in b.iter_mut().zip(&lambda[..=parity_len]) {
+                        *b = if *lambda == 0 {
+                            A0
+                        } else {
+                            modnn(Field::<C>::log(*lambda) as usize + NN - discrepancy) as u8
+                        };
+                    }
+                } else {
+                    shift(&mut b[..=parity_len]);
+                }
+                lambda[..=parity_len].copy_from_slice(&t[..=parity_len]);
+            } else {
+                shift(&mut b[..=parity_len]);
+            }
+        }
+
+        // Convert the locator to index form
+        let mut degree = 0;
+        for (i, lambda) in lambda[..=parity_len].iter_mut().enumerate() {
+            *lambda = Field::<C>::log(*lambda);
+            if *lambda != A0 {
+                degree = i;
+            }
+        }
+        if degree == 0 {
+            return None;
+        }
+
+        // Chien search for the roots `α^root` of the locator, where each root is the inverse of an error locator
+        let mut register = lambda;
+        let mut roots = Vec::<(usize, usize), NN>::new();
+        let mut location = Field::<C>::INVERSE_SPACING - 1;
+        for root in 1..=NN {
+            let mut sum = 1;
+            for j in (1..=degree).rev() {
+                if register[j] != A0 {
+                    register[j] = modnn(register[j] as usize + j) as u8;
+                    sum ^= Field::<C>::exp(register[j] as usize);
+                }
+            }
+            if sum == 0 {
+                roots.push((root, location)).unwrap();
+                if roots.len() == degree {
+                    break;
+                }
+            }
+            location = modnn(location + Field::<C>::INVERSE_SPACING);
+        }
+
+        // The locator must have distinct roots within the (shortened) codeword
+        if roots.len() != degree || roots.iter().any(|(_, location)| *location < shortening) {
+            return None;
+        }
+
+        // Compute the evaluator Ω(x) = S(x)Λ(x) mod x^PARITY_LEN in index form
+        let mut omega = [A0; NN];
+        for (i, omega) in omega[..degree].iter_mut().enumerate() {
+            let mut sum = 0;
+            for j in 0..=i {
+                if syndromes[i - j] != A0 && lambda[j] != A0 {
+                    sum ^= Field::<C>::exp(syndromes[i - j] as usize + lambda[j] as usize);
+                }
+            }
+            *omega = Field::<C>::log(sum);
+        }
+
+        // Compute the error values with the Forney algorithm,
+        // e = X^(1 - FIRST_ROOT) Ω(X^-1) / Λ'(X^-1) where X^-1 = α^root
+        let mut values = Vec::<u8, NN>::new();
+        for (root, _) in roots.iter() {
+            let mut numerator = 0;
+            for (i, omega) in omega[..degree].iter().enumerate() {
+                if *omega != A0 {
+                    numerator ^= Field::<C>::exp(*omega as usize + i * root);
+                }
+            }
+            let scale = modnn(root * (C::FIRST_ROOT + NN - 1));
+
+            // Λ'(x) only has the odd terms of Λ(x)
+            let mut denominator = 0;
+            for i in (0..=degree.min(parity_len - 1)).step_by(2) {
+                if lambda[i + 1] != A0 {
+                    denominator ^= Field::<C>::exp(lambda[i + 1] as usize + i * root);
+                }
+            }
+            if denominator == 0 {
+                return None;
+            }
+
+            values
+                .push(if numerator != 0 {
+                    Field::<C>::exp(
+                        Field::<C>::log(numerator) as usize + scale + NN
+                            - Field::<C>::log(denominator) as usize,
+                    )
+                } else {
+                    0
+                })
+                .unwrap();
+        }
+
+        for ((_, location), value) in roots.iter().zip(values) {
+            codeword[location - shortening] ^= value;
+        }
+
+        Some(roots.len())
+    }
+}
+
+impl<C: ReedSolomonCode> Default for ReedSolomonDecoder<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Multiply an index form polynomial by x.
+fn shift(polynomial: &mut [u8]) {
+    polynomial.copy_within(..polynomial.len() - 1, 1);
+    polynomial[0] = A0;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{catalog, reedsolomon::ReedSolomonEncoder, LlrMul};
+
+    use super::*;
+
+    fn encode<C: ReedSolomonCode>() -> std::vec::Vec<u8> {
+        let data: std::vec::Vec<u8> = (0..C::data_len()).map(|i| (i * 37 + i / 5) as u8).collect();
+        let mut parity = vec![0; C::PARITY_LEN];
+        ReedSolomonEncoder::<C>::new().encode(&data, &mut parity);
+        [data, parity].concat()
+    }
+
+    #[test]
+    fn can_decode_without_errors() {
+        // Given
+        let codeword = encode::<catalog::DVB_RS_204_188>();
+        let mut received = codeword.clone();
+        let decoder = ReedSolomonDecoder::<catalog::DVB_RS_204_188>::new();
+
+        // When
+        let corrected = decoder.decode(&mut received, &[]);
+
+        // Then
+        assert_eq!(Some(0), corrected);
+        assert_eq!(codeword, received);
+    }
+
+    #[test]
+    fn can_correct_errors() {
+        // Given
+        let codeword = encode::<catalog::DVB_RS_204_188>();
+        let mut received = codeword.clone();
+        for position in [0, 17, 50, 99, 120, 150, 187, 203] {
+            received[position] ^= (position as u8).wrapping_mul(3) | 1;
+        }
+        let decoder = ReedSolomonDecoder::<catalog::DVB_RS_204_188>::new();
+
+        // When
+        let corrected = decoder.decode(&mut received, &[]);
+
+        // Then
+        assert_eq!(Some(8), corrected);
+        assert_eq!(codeword, received);
+    }
+
+    #[test]
+    fn can_correct_errors_and_erasures() {
+        // Given
+        let codeword = encode::<catalog::CCSDS_RS_255_223>();
+        let mut received = codeword.clone();
+        let erasures: std::vec::Vec<usize> = (3..255).step_by(17).collect();
+        for position in erasures.iter() {
+            received[*position] = 0;
+        }
+        for position in [1, 40, 100, 200, 222, 230, 254] {
+            received[position] ^= 0x5A;
+        }
+        assert!(erasures.len() + 2 * 7 <= 32);
+        let decoder = ReedSolomonDecoder::<catalog::CCSDS_RS_255_223>::new();
+
+        // When
+        let corrected = decoder.decode(&mut received, &erasures);
+
+        // Then
+        assert!(corrected.is_some());
+        assert_eq!(codeword, received);
+    }
+
+    #[test]
+    fn can_detect_uncorrectable() {
+        // Given
+        let codeword = encode::<catalog::DVB_RS_204_188>();
+        let mut received = codeword.clone();
+        for position in (0..204).step_by(12) {
+            received[position] ^= 0xA5;
+        }
+        let corrupted = received.clone();
+        let decoder = ReedSolomonDecoder::<catalog::DVB_RS_204_188>::new();
+
+        // When
+        let corrected = decoder.decode(&mut received, &[]);
+
+        // Then
+        assert_eq!(None, corrected);
+        assert_eq!(corrupted, received);
+    }
+
+    #[test]
+    fn can_decode_dual_basis() {
+        // Given
+        let codeword = encode::<catalog::CCSDS_RS_255_223_DUAL_BASIS>();
+        let mut received = codeword.clone();
+        for position in (5..255).step_by(16) {
+            received[position] ^= 0x81;
+        }
+        let decoder = ReedSolomonDecoder::<catalog::CCSDS_RS_255_223_DUAL_BASIS>::new();
+
+        // When
+        let corrected = decoder.decode(&mut received, &[]);
+
+        // Then
+        assert_eq!(Some(16), corrected);
+        assert_eq!(codeword, received);
+        let conventional: std::vec::Vec<u8> =
+            codeword.iter().map(|x| from_dual_basis(*x)).collect();
+        let decoder = ReedSolomonDecoder::<catalog::CCSDS_RS_255_223>::new();
+        assert_eq!(Some(0), decoder.decode(&mut conventional.clone(), &[]));
+    }
+
+    #[test]
+    fn can_decode_llrs() {
+        // Given
+        let codeword = encode::<catalog::DVB_RS_204_188>();
+        let mut llrs: std::vec::Vec<Llr> = codeword
+            .iter()
+            .flat_map(|symbol| (0..8).map(move |bit| (symbol & (0x80 >> bit) != 0).mul(20)))
+            .collect();
+        // 12 symbols are corrupted with weak bits, which exceeds the 8 correctable errors
+        for position in (7..204).step_by(17) {
+            llrs[8 * position + 3] = -llrs[8 * position + 3].signum() * 2;
+        }
+        let decoder = ReedSolomonDecoder::<catalog::DVB_RS_204_188>::new();
+        let mut received = [0; 204];
+        decoder.decode_llrs(&llrs, 0, &mut received);
+        assert_ne!(codeword, received);
+
+        // When
+        let corrected = decoder.decode_llrs(&llrs, 5, &mut received);
+
+        // Then
+        assert_eq!(Some(12), corrected);
+        assert_eq!(codeword, received);
+    }
+}
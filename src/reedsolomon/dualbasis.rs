@@ -0,0 +1,66 @@
+/// The CCSDS conventional to dual basis transformation matrix `Tα` per CCSDS 131.0-B §4.
+/// Row `r` is the dual basis representation of the conventional basis element `α^(7 - r)`.
+const T_ALPHA: [u8; 8] = [0x8D, 0xEF, 0xEC, 0x86, 0xFA, 0x99, 0xAF, 0x7B];
+
+/// The conventional to dual basis table.
+const TO_DUAL: [u8; 256] = create_to_dual();
+
+/// The dual to conventional basis table.
+const FROM_DUAL: [u8; 256] = create_from_dual();
+
+const fn create_to_dual() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut symbol = 0;
+    while symbol < 256 {
+        let mut bit = 0;
+        while bit < 8 {
+            if symbol & (1 << bit) != 0 {
+                table[symbol] ^= T_ALPHA[7 - bit];
+            }
+            bit += 1;
+        }
+        symbol += 1;
+    }
+    table
+}
+
+const fn create_from_dual() -> [u8; 256] {
+    let mut table = [0; 256];
+    let mut symbol = 0;
+    while symbol < 256 {
+        table[TO_DUAL[symbol] as usize] = symbol as u8;
+        symbol += 1;
+    }
+    table
+}
+
+/// Convert a CCSDS symbol from the conventional basis to the dual basis.
+pub const fn to_dual_basis(symbol: u8) -> u8 {
+    TO_DUAL[symbol as usize]
+}
+
+/// Convert a CCSDS symbol from the dual basis to the conventional basis.
+pub const fn from_dual_basis(symbol: u8) -> u8 {
+    FROM_DUAL[symbol as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn can_convert_dual_basis() {
+        for symbol in 0..=255 {
+            // Given
+            let dual = to_dual_basis(symbol);
+
+            // When
+            let conventional = from_dual_basis(dual);
+
+            // Then
+            assert_eq!(symbol, conventional);
+        }
+        assert_eq!(0x7B, to_dual_basis(0x01));
+        assert_eq!(0x8D, to_dual_basis(0x80));
+    }
+}
@@ -0,0 +1,120 @@
+use core::marker::PhantomData;
+
+use super::{
+    field::{Field, A0},
+    from_dual_basis, to_dual_basis, ReedSolomonCode, ReedSolomonCodeExt,
+};
+
+/// Systematic Reed-Solomon encoder.
+///
+/// The codeword is the data symbols followed by the parity symbols,
+/// where the first data symbol is the coefficient of the highest degree.
+pub struct ReedSolomonEncoder<C: ReedSolomonCode> {
+    _code: PhantomData<C>,
+}
+
+impl<C: ReedSolomonCode> ReedSolomonEncoder<C> {
+    pub fn new() -> Self {
+        assert!(!C::DUAL_BASIS || C::FIELD_POLYNOMIAL == 0x187);
+        Self { _code: PhantomData }
+    }
+
+    /// Compute the `PARITY_LEN` parity symbols for the `CODEWORD_LEN - PARITY_LEN` data symbols.
+    pub fn encode(&self, data: &[u8], parity: &mut [u8]) {
+        assert_eq!(C::data_len(), data.len());
+        assert_eq!(C::PARITY_LEN, parity.len());
+        let generator = Field::<C>::GENERATOR;
+        let parity_len = C::PARITY_LEN;
+
+        // Divide the data polynomial by the generator polynomial using a feedback shift register
+        parity.fill(0);
+        for symbol in data {
+            let symbol = if C::DUAL_BASIS {
+                from_dual_basis(*symbol)
+            } else {
+                *symbol
+            };
+
+            let feedback = Field::<C>::log(symbol ^ parity[0]);
+            if feedback != A0 {
+                for j in 1..parity_len {
+                    parity[j] ^=
+                        Field::<C>::exp(feedback as usize + generator[parity_len - j] as usize);
+                }
+            }
+            parity.copy_within(1.., 0);
+            parity[parity_len - 1] = if feedback != A0 {
+                Field::<C>::exp(feedback as usize + generator[0] as usize)
+            } else {
+                0
+            };
+        }
+
+        if C::DUAL_BASIS {
+            for symbol in parity.iter_mut() {
+                *symbol = to_dual_basis(*symbol);
+            }
+        }
+    }
+}
+
+impl<C: ReedSolomonCode> Default for ReedSolomonEncoder<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog;
+
+    use super::*;
+
+    #[test]
+    fn codeword_has_generator_roots() {
+        // Given
+        let encoder = ReedSolomonEncoder::<catalog::DVB_RS_204_188>::new();
+        let data: Vec<u8> = (0..188).map(|i| (i * 37 + i / 5) as u8).collect();
+        let mut parity = [0; 16];
+
+        // When
+        encoder.encode(&data, &mut parity);
+
+        // Then
+        // Evaluate the codeword at α^i for i = 0..16 using bitwise multiplication
+        let codeword = [&data[..], &parity[..]].concat();
+        let mut root = 1;
+        for _ in 0..16 {
+            let value = codeword
+                .iter()
+                .fold(0, |acc, symbol| multiply(acc, root, 0x11D) ^ symbol);
+            assert_eq!(0, value);
+            root = multiply(root, 2, 0x11D);
+        }
+    }
+
+    #[test]
+    fn can_encode_zero() {
+        // Given
+        let encoder = ReedSolomonEncoder::<catalog::CCSDS_RS_255_223>::new();
+        let mut parity = [0xFF; 32];
+
+        // When
+        encoder.encode(&[0; 223], &mut parity);
+
+        // Then
+        assert_eq!([0; 32], parity);
+    }
+
+    fn multiply(mut a: u8, mut b: u8, polynomial: usize) -> u8 {
+        let mut product = 0;
+        while b != 0 {
+            if b & 1 != 0 {
+                product ^= a;
+            }
+            a = (((a as usize) << 1) ^ if a & 0x80 != 0 { polynomial } else { 0 }) as u8;
+            b >>= 1;
+        }
+        product
+    }
+}
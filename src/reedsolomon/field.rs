@@ -0,0 +1,141 @@
+use core::marker::PhantomData;
+
+use super::ReedSolomonCode;
+
+/// The number of non-zero field elements.
+pub(crate) const NN: usize = 255;
+
+/// The logarithm of the zero element.
+pub(crate) const A0: u8 = NN as u8;
+
+/// The GF(2^8) tables for a code, all computed at compile time.
+pub(crate) struct Field<C: ReedSolomonCode> {
+    _code: PhantomData<C>,
+}
+
+impl<C: ReedSolomonCode> Field<C> {
+    /// The polynomial form `α^i` for each exponent `i < 255`.
+    const EXP: &'static [u8; 256] = &Self::create().0;
+
+    /// The logarithm for each element, where the logarithm of zero is [`A0`].
+    const LOG: &'static [u8; 256] = &Self::create().1;
+
+    /// The generator polynomial coefficients in index form, lowest degree first.
+    pub(crate) const GENERATOR: &'static [u8; 256] = &Self::create_generator();
+
+    /// The multiplicative inverse of the root spacing modulo 255.
+    pub(crate) const INVERSE_SPACING: usize = Self::create_inverse_spacing();
+
+    const fn create() -> ([u8; 256], [u8; 256]) {
+        assert!(C::FIELD_POLYNOMIAL >= 0x100 && C::FIELD_POLYNOMIAL < 0x200);
+        let mut exp = [0; 256];
+        let mut log = [A0; 256];
+
+        let mut element = 1;
+        let mut i = 0;
+        while i < NN {
+            // The polynomial must be primitive, so α generates all non-zero elements
+            assert!(log[element] == A0, "The field polynomial is not primitive");
+            exp[i] = element as u8;
+            log[element] = i as u8;
+            element <<= 1;
+            if element & 0x100 != 0 {
+                element ^= C::FIELD_POLYNOMIAL;
+            }
+            i += 1;
+        }
+        exp[NN] = exp[0];
+
+        (exp, log)
+    }
+
+    const fn create_generator() -> [u8; 256] {
+        assert!(C::PARITY_LEN > 0 && C::PARITY_LEN < C::CODEWORD_LEN);
+        assert!(C::CODEWORD_LEN <= NN);
+        let (exp, log) = Self::create();
+
+        // Multiply g(x) = 1 by (x + β^j) for each root
+        let mut generator = [0; 256];
+        generator[0] = 1;
+        let mut i = 0;
+        while i < C::PARITY_LEN {
+            let root = modnn((C::FIRST_ROOT + i) * C::ROOT_SPACING);
+            generator[i + 1] = 1;
+            let mut j = i;
+            while j > 0 {
+                generator[j] = if generator[j] != 0 {
+                    generator[j - 1] ^ exp[modnn(log[generator[j] as usize] as usize + root)]
+                } else {
+                    generator[j - 1]
+                };
+                j -= 1;
+            }
+            generator[0] = exp[modnn(log[generator[0] as usize] as usize + root)];
+            i += 1;
+        }
+
+        let mut i = 0;
+        while i <= C::PARITY_LEN {
+            generator[i] = log[generator[i] as usize];
+            i += 1;
+        }
+        generator
+    }
+
+    const fn create_inverse_spacing() -> usize {
+        let mut inverse = 1;
+        while inverse < NN {
+            if (inverse * C::ROOT_SPACING) % NN == 1 {
+                return inverse;
+            }
+            inverse += 1;
+        }
+        panic!("The root spacing must be relatively prime to 255")
+    }
+
+    /// Get `α^i`.
+    pub(crate) fn exp(i: usize) -> u8 {
+        Self::EXP[modnn(i)]
+    }
+
+    /// Get the logarithm of `x`, or [`A0`] if `x` is zero.
+    pub(crate) fn log(x: u8) -> u8 {
+        Self::LOG[x as usize]
+    }
+}
+
+/// Reduce an exponent modulo 255.
+pub(crate) const fn modnn(x: usize) -> usize {
+    x % NN
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::catalog;
+
+    use super::*;
+
+    #[test]
+    fn can_create_tables() {
+        // Given
+        type F = Field<catalog::DVB_RS_204_188>;
+
+        // When
+        let exp = F::EXP;
+        let log = F::LOG;
+
+        // Then
+        // α^8 = α^4 + α^3 + α^2 + 1 for x^8+x^4+x^3+x^2+1
+        assert_eq!([1, 2, 4, 8, 16, 32, 64, 128, 0x1D, 0x3A], exp[..10]);
+        for x in 1..=255u8 {
+            assert_eq!(x, exp[log[x as usize] as usize]);
+        }
+        assert_eq!(A0, log[0]);
+    }
+
+    #[test]
+    fn can_invert_root_spacing() {
+        assert_eq!(1, Field::<catalog::DVB_RS_204_188>::INVERSE_SPACING);
+        assert_eq!(116, Field::<catalog::CCSDS_RS_255_223>::INVERSE_SPACING);
+    }
+}
@@ -0,0 +1,10 @@
+mod code;
+mod decoder;
+mod dualbasis;
+mod encoder;
+mod field;
+
+pub use code::{ReedSolomonCode, ReedSolomonCodeExt};
+pub use decoder::ReedSolomonDecoder;
+pub use dualbasis::{from_dual_basis, to_dual_basis};
+pub use encoder::ReedSolomonEncoder;
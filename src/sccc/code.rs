@@ -0,0 +1,40 @@
+use crate::{
+    convolutional::{ConvolutionalCode, ConvolutionalCodeExt},
+    turbo::trellis_bits,
+};
+
+/// Serially concatenated convolutional code.
+///
+/// The code bits of the outer encoder are interleaved and encoded by the inner encoder.
+/// Both constituent codes must be systematic rate 1/2 codes, as the constituent decoders
+/// use the [`BcjrSymbol`](crate::convolutional::bcjr::BcjrSymbol) systematic llr for the input bit.
+/// Non-systematic outer codes such as the feed-forward (7, 5) code are not supported.
+pub trait SerialCode: Default {
+    type OuterCode: ConvolutionalCode;
+    type InnerCode: ConvolutionalCode;
+    const TERMINATE_OUTER: bool;
+    const TERMINATE_INNER: bool;
+}
+
+/// Get the number of outer code bits, i.e. the interleaver length, for a block of `block_bits` source bits.
+pub const fn interleaver_bits<C: SerialCode>(block_bits: usize) -> usize {
+    2 * trellis_bits::<C::OuterCode>(block_bits, C::TERMINATE_OUTER)
+}
+
+pub(super) fn assert_constituent_encoders<C: SerialCode>() {
+    let rate = C::OuterCode::rate();
+    assert!(rate.k == 1);
+    assert!(rate.n == 2);
+    assert!(
+        C::OuterCode::is_systematic(),
+        "Only systematic outer codes are supported"
+    );
+
+    let rate = C::InnerCode::rate();
+    assert!(rate.k == 1);
+    assert!(rate.n == 2);
+    assert!(
+        C::InnerCode::is_systematic(),
+        "Only systematic inner codes are supported"
+    );
+}
@@ -0,0 +1,266 @@
+use core::marker::PhantomData;
+
+use heapless::Vec;
+
+use crate::{
+    convolutional::bcjr::{umts::UmtsState, BcjrDecoder, BcjrParityState, BcjrSymbol},
+    interleaver::{Interleaver, InterleaverMapping},
+    turbo::trellis_bits,
+    Llr,
+};
+
+use super::{code::assert_constituent_encoders, SerialCode};
+
+/// Serially concatenated convolutional decoder.
+///
+/// Both constituent codes are decoded with a [`BcjrDecoder`] using the state `S`,
+/// where the outer decoder also emits the a-posteriori llrs of its parity bits.
+pub struct SerialDecoder<
+    C: SerialCode,
+    S: BcjrParityState,
+    const MAX_OUTER_TRELLIS_BITS: usize,
+    const MAX_INNER_TRELLIS_BITS: usize,
+> {
+    _code: PhantomData<C>,
+    _state: PhantomData<S>,
+}
+
+/// Serial decoder where both constituent codes use the UMTS trellis.
+///
+/// [`UmtsState`] only implements the 8-state UMTS trellis, so both constituent codes must be [`UMTS`](crate::catalog::UMTS).
+pub type UmtsSerialDecoder<
+    C,
+    const MAX_OUTER_TRELLIS_BITS: usize,
+    const MAX_INNER_TRELLIS_BITS: usize,
+> = SerialDecoder<C, UmtsState, MAX_OUTER_TRELLIS_BITS, MAX_INNER_TRELLIS_BITS>;
+
+impl<
+        C: SerialCode,
+        S: BcjrParityState,
+        const MAX_OUTER_TRELLIS_BITS: usize,
+        const MAX_INNER_TRELLIS_BITS: usize,
+    > SerialDecoder<C, S, MAX_OUTER_TRELLIS_BITS, MAX_INNER_TRELLIS_BITS>
+{
+    pub fn new() -> Self {
+        assert_constituent_encoders::<C>();
+        Self {
+            _code: PhantomData,
+            _state: PhantomData,
+        }
+    }
+
+    /// Start decoding a block.
+    ///
+    /// `input` holds the received symbols of the inner encoder, including the inner termination.
+    /// The interleaver length must be [`interleaver_bits`](super::interleaver_bits) for the block length.
+    pub fn decode<'a, I: Interleaver>(
+        &self,
+        input: &[BcjrSymbol],
+        interleaver: &'a I,
+    ) -> SerialDecoding<'a, C, S, I, MAX_OUTER_TRELLIS_BITS, MAX_INNER_TRELLIS_BITS> {
+        let outer_len = interleaver.len() / 2;
+        assert_eq!(0, interleaver.len() % 2);
+        assert_eq!(
+            input.len(),
+            trellis_bits::<C::InnerCode>(interleaver.len(), C::TERMINATE_INNER)
+        );
+
+        let mut inner_input = Vec::new();
+        for symbol in input {
+            inner_input
+                .push(BcjrSymbol::new(symbol.systematic, symbol.parity))
+                .unwrap();
+        }
+
+        let mut outer_input = Vec::new();
+        outer_input
+            .resize(outer_len, BcjrSymbol::new(0, 0))
+            .unwrap();
+
+        let mut inner_result = Vec::new();
+        inner_result.resize_default(input.len()).unwrap();
+        let mut outer_result = Vec::new();
+        outer_result.resize_default(outer_len).unwrap();
+        let mut outer_parity_result = Vec::new();
+        outer_parity_result.resize_default(outer_len).unwrap();
+
+        SerialDecoding {
+            _code: PhantomData,
+            outer_bcjr: BcjrDecoder::new(C::TERMINATE_OUTER),
+            inner_bcjr: BcjrDecoder::new(C::TERMINATE_INNER),
+            interleaver,
+            outer_input,
+            inner_input,
+            outer_result,
+            outer_parity_result,
+            inner_result,
+            block_len: outer_len - trellis_bits::<C::OuterCode>(0, C::TERMINATE_OUTER),
+        }
+    }
+}
+
+impl<
+        C: SerialCode,
+        S: BcjrParityState,
+        const MAX_OUTER_TRELLIS_BITS: usize,
+        const MAX_INNER_TRELLIS_BITS: usize,
+    > Default for SerialDecoder<C, S, MAX_OUTER_TRELLIS_BITS, MAX_INNER_TRELLIS_BITS>
+{
+    fn default() -> Self {
+        SerialDecoder::new()
+    }
+}
+
+pub struct SerialDecoding<
+    'a,
+    C: SerialCode,
+    S: BcjrParityState,
+    I: Interleaver,
+    const MAX_OUTER_TRELLIS_BITS: usize,
+    const MAX_INNER_TRELLIS_BITS: usize,
+> {
+    _code: PhantomData<C>,
+    outer_bcjr: BcjrDecoder<C::OuterCode, S, MAX_OUTER_TRELLIS_BITS>,
+    inner_bcjr: BcjrDecoder<C::InnerCode, S, MAX_INNER_TRELLIS_BITS>,
+    interleaver: &'a I,
+    outer_input: Vec<BcjrSymbol, MAX_OUTER_TRELLIS_BITS>,
+    inner_input: Vec<BcjrSymbol, MAX_INNER_TRELLIS_BITS>,
+    outer_result: Vec<Llr, MAX_OUTER_TRELLIS_BITS>,
+    outer_parity_result: Vec<Llr, MAX_OUTER_TRELLIS_BITS>,
+    inner_result: Vec<Llr, MAX_INNER_TRELLIS_BITS>,
+    block_len: usize,
+}
+
+impl<C, S, I, const MAX_OUTER_TRELLIS_BITS: usize, const MAX_INNER_TRELLIS_BITS: usize>
+    SerialDecoding<'_, C, S, I, MAX_OUTER_TRELLIS_BITS, MAX_INNER_TRELLIS_BITS>
+where
+    C: SerialCode,
+    S: BcjrParityState,
+    I: Interleaver,
+{
+    /// Get the a-posteriori llrs for the source bits from the latest iteration.
+    pub fn get_result(&self) -> &[Llr] {
+        &self.outer_result[0..self.block_len]
+    }
+
+    /// Run a single decode iteration
+    pub fn run_decode_iteration(&mut self) {
+        self.run_inner_decoder();
+        self.run_outer_decoder();
+    }
+
+    fn run_inner_decoder(&mut self) {
+        self.inner_bcjr
+            .decode(&self.inner_input, &mut self.inner_result);
+
+        // The extrinsic llr for each inner input bit includes the channel llr of the systematic bit,
+        // as the outer decoder only observes its code bits through the inner decoder.
+        // It is deinterleaved and used as the channel llr for the outer code bits.
+        for InterleaverMapping(i, ii) in self.interleaver.iter() {
+            let lapp = self.inner_result[i] as isize; // a-posteriori llr
+            let la = self.inner_input[i].apriori as isize; // a-priori llr
+            let extrinsic = (lapp - la).clamp(Llr::MIN as isize, Llr::MAX as isize) as Llr;
+
+            let symbol = &mut self.outer_input[ii / 2];
+            if ii % 2 == 0 {
+                symbol.systematic = extrinsic;
+            } else {
+                symbol.parity = extrinsic;
+            }
+        }
+    }
+
+    fn run_outer_decoder(&mut self) {
+        self.outer_bcjr.decode_with_parity(
+            &self.outer_input,
+            &mut self.outer_result,
+            &mut self.outer_parity_result,
+        );
+
+        // The extrinsic llr for each outer code bit is interleaved and used as the a-priori llr for the inner decoder.
+        for InterleaverMapping(i, ii) in self.interleaver.iter() {
+            let symbol = &self.outer_input[ii / 2];
+            let (lapp, lc) = if ii % 2 == 0 {
+                (self.outer_result[ii / 2], symbol.systematic)
+            } else {
+                (self.outer_parity_result[ii / 2], symbol.parity)
+            };
+            let extrinsic = lapp as isize - lc as isize;
+
+            self.inner_input[i].apriori =
+                extrinsic.clamp(Llr::MIN as isize, Llr::MAX as isize) as Llr;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog, convolutional::EncoderOutput, interleaver::qpp::QppInterleaver,
+        sccc::SerialEncoder, LlrMul,
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Code;
+
+    impl SerialCode for Code {
+        type OuterCode = catalog::UMTS;
+        type InnerCode = catalog::UMTS;
+        const TERMINATE_OUTER: bool = true;
+        const TERMINATE_INNER: bool = true;
+    }
+
+    #[test]
+    fn can_decode() {
+        // Given
+        let source: [bool; 29] = core::array::from_fn(|i| (i * 5 + i / 3) % 3 == 1);
+        let encoder = SerialEncoder::<Code, 64>::default();
+        let interleaver = QppInterleaver::new(64, 7, 16);
+        let mut encoded = [0; 67];
+        encoder.encode(&source, &interleaver, &mut encoded);
+        let mut input = encoded.map(to_symbol);
+        for index in (3..67).step_by(11) {
+            input[index].systematic = -input[index].systematic;
+        }
+        for index in (8..67).step_by(13) {
+            input[index].parity = -input[index].parity;
+        }
+        let decoder = UmtsSerialDecoder::<Code, 32, 67>::default();
+
+        // When
+        let mut decoding = decoder.decode(&input, &interleaver);
+        assert_eq!([0; 29], decoding.get_result());
+        for _ in 0..4 {
+            decoding.run_decode_iteration();
+        }
+
+        // Then
+        let result = decoding.get_result();
+        assert_eq!(29, result.len());
+        for (llr, bit) in result.iter().zip(source) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Only systematic outer codes are supported")]
+    fn rejects_non_systematic_outer_code() {
+        #[derive(Default)]
+        struct NonSystematicCode;
+
+        impl SerialCode for NonSystematicCode {
+            type OuterCode = catalog::CCSDS;
+            type InnerCode = catalog::UMTS;
+            const TERMINATE_OUTER: bool = true;
+            const TERMINATE_INNER: bool = true;
+        }
+
+        UmtsSerialDecoder::<NonSystematicCode, 32, 67>::new();
+    }
+
+    fn to_symbol(output: EncoderOutput) -> BcjrSymbol {
+        BcjrSymbol::new((output & 1 != 0).mul(4), (output & 2 != 0).mul(4))
+    }
+}
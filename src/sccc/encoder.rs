@@ -0,0 +1,133 @@
+use core::marker::PhantomData;
+
+use heapless::Vec;
+
+use crate::{
+    convolutional::{ConvolutionalCodeExt, ConvolutionalEncoder, EncoderOutput},
+    interleaver::{Interleaver, InterleaverMapping},
+    BitView,
+};
+
+use super::{code::assert_constituent_encoders, interleaver_bits, SerialCode};
+
+/// Serially concatenated convolutional encoder.
+///
+/// Outer code bit `2t + j` is output bit `j` of the outer encoder at trellis step `t`,
+/// and the inner encoder takes outer code bit `ii` as its input at position `i`
+/// for each [`InterleaverMapping`] `(i, ii)`.
+pub struct SerialEncoder<C: SerialCode, const MAX_INTERLEAVER_BITS: usize> {
+    _code: PhantomData<C>,
+}
+
+impl<C: SerialCode, const MAX_INTERLEAVER_BITS: usize> SerialEncoder<C, MAX_INTERLEAVER_BITS> {
+    pub fn new() -> Self {
+        assert_constituent_encoders::<C>();
+        Self { _code: PhantomData }
+    }
+
+    /// Encode a block.
+    ///
+    /// The interleaver length must be [`interleaver_bits`] for the source length.
+    /// The inner encoder outputs, including the inner termination, are written to `output`
+    /// and the number of written outputs is returned.
+    pub fn encode<S, I>(&self, source: S, interleaver: &I, output: &mut [EncoderOutput]) -> usize
+    where
+        S: BitView,
+        I: Interleaver,
+    {
+        assert_eq!(interleaver_bits::<C>(source.len2()), interleaver.len());
+
+        // Encode the outer code bits
+        let mut code_bits = Vec::<bool, MAX_INTERLEAVER_BITS>::new();
+        let mut outer_encoder = ConvolutionalEncoder::<C::OuterCode>::default();
+        for index in 0..source.len2() {
+            let outer_output = outer_encoder.get_output(source.get(index));
+            Self::push_code_bits(&mut code_bits, outer_output);
+        }
+        if C::TERMINATE_OUTER {
+            for _ in 0..C::OuterCode::mem() {
+                let outer_output = outer_encoder.get_termination_output();
+                Self::push_code_bits(&mut code_bits, outer_output);
+            }
+        }
+
+        // Encode the interleaved code bits with the inner encoder
+        let mut inner_encoder = ConvolutionalEncoder::<C::InnerCode>::default();
+        let mut written = 0;
+        for InterleaverMapping(_, ii) in interleaver.iter() {
+            output[written] = inner_encoder.get_output(code_bits[ii]);
+            written += 1;
+        }
+        if C::TERMINATE_INNER {
+            for _ in 0..C::InnerCode::mem() {
+                output[written] = inner_encoder.get_termination_output();
+                written += 1;
+            }
+        }
+
+        written
+    }
+
+    fn push_code_bits(code_bits: &mut Vec<bool, MAX_INTERLEAVER_BITS>, output: EncoderOutput) {
+        code_bits.push(output & 1 != 0).unwrap();
+        code_bits.push(output & 2 != 0).unwrap();
+    }
+}
+
+impl<C: SerialCode, const MAX_INTERLEAVER_BITS: usize> Default
+    for SerialEncoder<C, MAX_INTERLEAVER_BITS>
+{
+    fn default() -> Self {
+        SerialEncoder::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{catalog, interleaver::qpp::QppInterleaver};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Code;
+
+    impl SerialCode for Code {
+        type OuterCode = catalog::UMTS;
+        type InnerCode = catalog::UMTS;
+        const TERMINATE_OUTER: bool = true;
+        const TERMINATE_INNER: bool = true;
+    }
+
+    #[test]
+    fn can_encode() {
+        // Given
+        let source = [true, false, true, true, false];
+        let encoder = SerialEncoder::<Code, 16>::default();
+        let interleaver = QppInterleaver::new(16, 3, 4);
+        let mut output = [0; 19];
+
+        // When
+        let written = encoder.encode(&source, &interleaver, &mut output);
+
+        // Then
+        assert_eq!(19, written);
+
+        // The systematic inner bits are the interleaved outer code bits
+        let mut outer_encoder = ConvolutionalEncoder::<catalog::UMTS>::default();
+        let mut outer_outputs = [0; 8];
+        outer_encoder.encode(&source, &mut outer_outputs[..5]);
+        outer_encoder.flush(&mut outer_outputs[5..]);
+        for InterleaverMapping(i, ii) in interleaver.iter() {
+            let code_bit = outer_outputs[ii / 2] & (1 << (ii % 2)) != 0;
+            assert_eq!(code_bit, output[i] & 1 != 0);
+        }
+
+        // The inner encoder is terminated
+        let mut inner_encoder = ConvolutionalEncoder::<catalog::UMTS>::default();
+        let inner_input: Vec<bool, 16> = output[..16].iter().map(|o| o & 1 != 0).collect();
+        let mut inner_outputs = [0; 19];
+        inner_encoder.encode(&inner_input[..], &mut inner_outputs[..16]);
+        inner_encoder.flush(&mut inner_outputs[16..]);
+        assert_eq!(inner_outputs, output);
+    }
+}
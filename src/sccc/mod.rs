@@ -0,0 +1,11 @@
+mod code;
+mod decoder;
+mod encoder;
+
+pub use code::{interleaver_bits, SerialCode};
+pub use decoder::{SerialDecoder, SerialDecoding};
+pub use encoder::SerialEncoder;
+
+pub mod umts {
+    pub use super::decoder::UmtsSerialDecoder;
+}
@@ -0,0 +1,62 @@
+use crate::block::BlockCode;
+
+/// The maximum number of bits in a codeword of a component code.
+pub(crate) const MAX_COMPONENT_BITS: usize = 64;
+
+/// A product code of two systematic component block codes.
+///
+/// The `k2 x k1` data bits are arranged row by row in a matrix, where each row is encoded with the row code `(n1, k1)`
+/// and then each of the `n1` columns is encoded with the column code `(n2, k2)`.
+/// The codeword is the resulting `n2 x n1` matrix row by row, including the checks on checks in the last rows.
+#[derive(Clone, Copy, Debug)]
+pub struct ProductCode<R: BlockCode, C: BlockCode> {
+    row_code: R,
+    column_code: C,
+}
+
+impl<R: BlockCode, C: BlockCode> ProductCode<R, C> {
+    /// Create a new product code
+    ///
+    /// # Arguments
+    ///
+    /// * `row_code` - The code for the rows with at most 64 bits.
+    /// * `column_code` - The code for the columns with at most 64 bits.
+    pub fn new(row_code: R, column_code: C) -> Self {
+        assert!(row_code.codeword_bits() <= MAX_COMPONENT_BITS);
+        assert!(column_code.codeword_bits() <= MAX_COMPONENT_BITS);
+        Self {
+            row_code,
+            column_code,
+        }
+    }
+
+    /// Get the code for the rows.
+    pub fn row_code(&self) -> &R {
+        &self.row_code
+    }
+
+    /// Get the code for the columns.
+    pub fn column_code(&self) -> &C {
+        &self.column_code
+    }
+
+    /// Get the number of columns `n1`.
+    pub fn columns(&self) -> usize {
+        self.row_code.codeword_bits()
+    }
+
+    /// Get the number of rows `n2`.
+    pub fn rows(&self) -> usize {
+        self.column_code.codeword_bits()
+    }
+
+    /// Get the number of bits in a codeword `n1 n2`.
+    pub fn codeword_bits(&self) -> usize {
+        self.rows() * self.columns()
+    }
+
+    /// Get the number of data bits `k1 k2`.
+    pub fn data_bits(&self) -> usize {
+        self.row_code.data_bits() * self.column_code.data_bits()
+    }
+}
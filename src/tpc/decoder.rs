@@ -0,0 +1,384 @@
+use bitvec::prelude::*;
+use heapless::Vec;
+
+use crate::{block::BlockCode, Llr};
+
+use super::{code::MAX_COMPONENT_BITS, ProductCode};
+
+/// The maximum number of least reliable bits that are flipped in the test patterns.
+const MAX_TEST_BITS: usize = 5;
+
+/// The weights of the extrinsic information for each half iteration from Pyndiah in units of 1/8,
+/// i.e. approximately 0, 0.2, 0.3, 0.5, 0.7, 0.9 and 1.0.
+pub const PYNDIAH_ALPHA: &[u8] = &[0, 2, 2, 4, 6, 7, 8];
+
+type Word = BitArr!(for MAX_COMPONENT_BITS, in u8, Msb0);
+
+/// Iterative Chase-Pyndiah decoder for turbo product codes.
+///
+/// Each half iteration decodes all rows or all columns with a Chase-II decoder,
+/// which hard decodes the test patterns of the least reliable bits with the component decoder.
+/// The soft output of a bit is the metric difference between the decided codeword and the best competing codeword
+/// that differs in the bit, or `beta` if there is no competing codeword.
+/// The input of a half iteration is the channel llrs plus the extrinsic information of the previous half iteration
+/// weighted with `alpha`.
+pub struct TpcDecoder<R: BlockCode, C: BlockCode, const MAX_BITS: usize> {
+    code: ProductCode<R, C>,
+    test_bits: usize,
+    alpha: &'static [u8],
+    beta: &'static [Llr],
+}
+
+impl<R: BlockCode, C: BlockCode, const MAX_BITS: usize> TpcDecoder<R, C, MAX_BITS> {
+    /// Create a new decoder
+    ///
+    /// # Arguments
+    ///
+    /// * `code` - The product code.
+    /// * `test_bits` - The number of least reliable bits `p` in each row or column, giving `2^p` test patterns.
+    /// * `alpha` - The weight of the extrinsic information in units of 1/8 for each half iteration, e.g. [`PYNDIAH_ALPHA`].
+    ///   The last weight is used for the remaining half iterations.
+    /// * `beta` - The soft output magnitude for each half iteration when there is no competing codeword.
+    ///   The last value is used for the remaining half iterations.
+    pub fn new(
+        code: ProductCode<R, C>,
+        test_bits: usize,
+        alpha: &'static [u8],
+        beta: &'static [Llr],
+    ) -> Self {
+        assert!(code.codeword_bits() <= MAX_BITS);
+        assert!(test_bits > 0 && test_bits <= MAX_TEST_BITS);
+        assert!(test_bits <= code.rows().min(code.columns()));
+        assert!(!alpha.is_empty() && !beta.is_empty());
+        Self {
+            code,
+            test_bits,
+            alpha,
+            beta,
+        }
+    }
+
+    /// Start decoding the channel llrs of the `n1 n2` codeword bits.
+    pub fn decode(&self, input: &[Llr]) -> TpcDecoding<'_, R, C, MAX_BITS> {
+        assert_eq!(self.code.codeword_bits(), input.len());
+
+        // The llrs are limited to be symmetric so that they can always be negated
+        let channel: Vec<Llr, MAX_BITS> = input.iter().map(|llr| (*llr).max(-Llr::MAX)).collect();
+        let mut extrinsic = Vec::new();
+        extrinsic.resize_default(input.len()).unwrap();
+
+        TpcDecoding {
+            decoder: self,
+            result: channel.clone(),
+            channel,
+            extrinsic,
+            half_iteration: 0,
+        }
+    }
+}
+
+pub struct TpcDecoding<'a, R: BlockCode, C: BlockCode, const MAX_BITS: usize> {
+    decoder: &'a TpcDecoder<R, C, MAX_BITS>,
+    channel: Vec<Llr, MAX_BITS>,
+    extrinsic: Vec<Llr, MAX_BITS>,
+    result: Vec<Llr, MAX_BITS>,
+    half_iteration: usize,
+}
+
+impl<R: BlockCode, C: BlockCode, const MAX_BITS: usize> TpcDecoding<'_, R, C, MAX_BITS> {
+    /// Get the soft output llrs of the codeword bits from the latest half iteration.
+    pub fn get_result(&self) -> &[Llr] {
+        &self.result
+    }
+
+    /// Get whether the hard decisions of the soft output are codewords in all rows and columns.
+    pub fn is_codeword(&self) -> bool {
+        let code = &self.decoder.code;
+        let (n1, n2) = (code.columns(), code.rows());
+        let mut word = Word::ZERO;
+
+        (0..n2).all(|row| {
+            for column in 0..n1 {
+                word.set(column, self.result[row * n1 + column] > 0);
+            }
+            code.row_code().decode(&mut word[..n1]) == Some(0)
+        }) && (0..n1).all(|column| {
+            for row in 0..n2 {
+                word.set(row, self.result[row * n1 + column] > 0);
+            }
+            code.column_code().decode(&mut word[..n2]) == Some(0)
+        })
+    }
+
+    /// Run decode iterations until all rows and columns are codewords.
+    ///
+    /// Returns the number of iterations that were run, or `None` if the decisions were not codewords within `max_iterations`.
+    pub fn run(&mut self, max_iterations: usize) -> Option<usize> {
+        for iteration in 0..max_iterations {
+            if self.is_codeword() {
+                return Some(iteration);
+            }
+            self.run_decode_iteration();
+        }
+        self.is_codeword().then_some(max_iterations)
+    }
+
+    /// Run a single decode iteration, i.e. decode all rows and then all columns.
+    pub fn run_decode_iteration(&mut self) {
+        let code = &self.decoder.code;
+        let (n1, n2) = (code.columns(), code.rows());
+
+        self.run_half_iteration(code.row_code(), n2, n1, |row, column| row * n1 + column);
+        self.run_half_iteration(code.column_code(), n1, n2, |column, row| row * n1 + column);
+    }
+
+    /// Decode `lines` rows or columns of `len` bits, where `index` maps a line and position to the codeword bit.
+    fn run_half_iteration<B: BlockCode>(
+        &mut self,
+        code: &B,
+        lines: usize,
+        len: usize,
+        index: impl Fn(usize, usize) -> usize,
+    ) {
+        let decoder = self.decoder;
+        let step = self.half_iteration;
+        let alpha = decoder.alpha[step.min(decoder.alpha.len() - 1)] as i32;
+        let beta = decoder.beta[step.min(decoder.beta.len() - 1)] as i32;
+
+        let mut input = [0i32; MAX_COMPONENT_BITS];
+        let mut output = [0i32; MAX_COMPONENT_BITS];
+        for line in 0..lines {
+            for (position, input) in input[..len].iter_mut().enumerate() {
+                let bit = index(line, position);
+                *input = self.channel[bit] as i32 + (alpha * self.extrinsic[bit] as i32) / 8;
+            }
+
+            chase(
+                code,
+                decoder.test_bits,
+                beta,
+                &input[..len],
+                &mut output[..len],
+            );
+
+            for position in 0..len {
+                let bit = index(line, position);
+                let soft = output[position];
+                self.result[bit] = clamp(soft);
+                self.extrinsic[bit] = clamp(soft - input[position]);
+            }
+        }
+
+        self.half_iteration += 1;
+    }
+}
+
+/// Compute the soft output of a Chase-II decoder for the soft input of a component codeword.
+fn chase<B: BlockCode>(code: &B, test_bits: usize, beta: i32, input: &[i32], output: &mut [i32]) {
+    let len = input.len();
+
+    let mut hard = Word::ZERO;
+    for (position, value) in input.iter().enumerate() {
+        hard.set(position, *value > 0);
+    }
+
+    // Find the least reliable positions
+    let mut positions = Vec::<(i32, usize), MAX_COMPONENT_BITS>::new();
+    for (position, value) in input.iter().enumerate() {
+        positions.push((value.abs(), position)).unwrap();
+    }
+    positions.sort_unstable();
+    positions.truncate(test_bits);
+
+    // Decode the test patterns, where the metric of a candidate is the sum of the reliabilities of the bits that differ from the hard decision
+    let mut candidates = Vec::<(i32, Word), { 1 << MAX_TEST_BITS }>::new();
+    for pattern in 0..1usize << test_bits {
+        let mut candidate = hard;
+        for (bit, (_, position)) in positions.iter().enumerate() {
+            if pattern & (1 << bit) != 0 {
+                let value = candidate[*position];
+                candidate.set(*position, !value);
+            }
+        }
+        if code.decode(&mut candidate[..len]).is_none() {
+            continue;
+        }
+        if candidates.iter().any(|(_, other)| *other == candidate) {
+            continue;
+        }
+        let metric = (candidate ^ hard)[..len]
+            .iter_ones()
+            .map(|position| input[position].abs())
+            .sum();
+        candidates.push((metric, candidate)).unwrap();
+    }
+
+    let Some((decided_metric, decided)) = candidates.iter().min_by_key(|(metric, _)| *metric)
+    else {
+        // There is no information without a candidate codeword
+        output.copy_from_slice(input);
+        return;
+    };
+
+    for (position, output) in output.iter_mut().enumerate() {
+        let bit = decided[position];
+        let competitor = candidates
+            .iter()
+            .filter(|(_, candidate)| candidate[position] != bit)
+            .map(|(metric, _)| *metric)
+            .min();
+        let reliability = match competitor {
+            Some(metric) => metric - decided_metric,
+            None => beta,
+        };
+        *output = if bit { reliability } else { -reliability };
+    }
+}
+
+fn clamp(value: i32) -> Llr {
+    value.clamp(-Llr::MAX as i32, Llr::MAX as i32) as Llr
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        block::{Golay, Hamming, ParityCheck, Shortened},
+        tpc::TpcEncoder,
+        LlrMul,
+    };
+
+    use super::*;
+
+    fn encode<R: BlockCode + Copy, C: BlockCode + Copy>(
+        code: ProductCode<R, C>,
+    ) -> std::vec::Vec<bool> {
+        let data: std::vec::Vec<bool> = (0..code.data_bits())
+            .map(|index| (index * 7 + index / 3) % 5 < 2)
+            .collect();
+        let mut codeword = [0u8; 512];
+        TpcEncoder::new(code).encode(
+            data.as_slice(),
+            &mut codeword.view_bits_mut()[..code.codeword_bits()],
+        );
+        codeword.view_bits::<Msb0>()[..code.codeword_bits()]
+            .iter()
+            .map(|bit| *bit)
+            .collect()
+    }
+
+    #[test]
+    fn can_decode_without_errors() {
+        // Given
+        let code = ProductCode::new(Hamming::new(3, true), Hamming::new(3, true));
+        let codeword = encode(code);
+        let input: std::vec::Vec<Llr> = codeword.iter().map(|bit| bit.mul(10)).collect();
+        let decoder = TpcDecoder::<_, _, 64>::new(code, 2, PYNDIAH_ALPHA, &[10]);
+
+        // When
+        let mut decoding = decoder.decode(&input);
+        let iterations = decoding.run(4);
+
+        // Then
+        assert_eq!(Some(0), iterations);
+    }
+
+    #[test]
+    fn can_correct_errors() {
+        // Given
+        let code = ProductCode::new(Hamming::new(4, true), Hamming::new(4, true));
+        let codeword = encode(code);
+        let mut input: std::vec::Vec<Llr> = codeword.iter().map(|bit| bit.mul(16)).collect();
+        // Strong errors and weak errors, where some rows and columns have more errors than the hard decision capability
+        for position in [0, 1, 17, 18, 40, 100, 101, 102, 200, 255] {
+            input[position] = -input[position];
+        }
+        for position in (3..256).step_by(11) {
+            input[position] = -input[position] / 4;
+        }
+        let decoder = TpcDecoder::<_, _, 256>::new(code, 4, PYNDIAH_ALPHA, &[8, 16, 24, 32]);
+
+        // When
+        let mut decoding = decoder.decode(&input);
+        let iterations = decoding.run(8);
+
+        // Then
+        assert!(iterations.is_some());
+        for (llr, bit) in decoding.get_result().iter().zip(codeword) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+
+    #[test]
+    fn can_decode_golay_product() {
+        // Given
+        let code = ProductCode::new(Golay::new(), Hamming::new(3, true));
+        let codeword = encode(code);
+        let mut input: std::vec::Vec<Llr> = codeword.iter().map(|bit| bit.mul(12)).collect();
+        for position in (0..192).step_by(7) {
+            input[position] = -input[position] / 3;
+        }
+        let decoder = TpcDecoder::<_, _, 192>::new(code, 3, PYNDIAH_ALPHA, &[12, 24]);
+
+        // When
+        let mut decoding = decoder.decode(&input);
+        let iterations = decoding.run(8);
+
+        // Then
+        assert!(iterations.is_some());
+        for (llr, bit) in decoding.get_result().iter().zip(codeword) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+
+    #[test]
+    fn can_decode_ieee80216_product() {
+        // Given
+        // The (64,57) extended Hamming component code of the 802.16 block turbo code in both dimensions
+        let code = ProductCode::new(Hamming::new(6, true), Hamming::new(6, true));
+        let codeword = encode(code);
+        let mut input: std::vec::Vec<Llr> = codeword.iter().map(|bit| bit.mul(16)).collect();
+        for position in [0, 1, 2, 65, 130, 2000, 2001, 4095] {
+            input[position] = -input[position];
+        }
+        for position in (5..4096).step_by(37) {
+            input[position] = -input[position] / 4;
+        }
+        let decoder = TpcDecoder::<_, _, 4096>::new(code, 4, PYNDIAH_ALPHA, &[8, 16, 24, 32]);
+
+        // When
+        let mut decoding = decoder.decode(&input);
+        let iterations = decoding.run(8);
+
+        // Then
+        assert!(iterations.is_some());
+        for (llr, bit) in decoding.get_result().iter().zip(codeword) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+
+    #[test]
+    fn can_decode_shortened_product() {
+        // Given
+        // The (64,57) extended Hamming code shortened to (60,53) for the rows and the (8,7) parity check code for the columns
+        let code = ProductCode::new(
+            Shortened::new(Hamming::new(6, true), 4),
+            ParityCheck::new(8),
+        );
+        let codeword = encode(code);
+        let mut input: std::vec::Vec<Llr> = codeword.iter().map(|bit| bit.mul(16)).collect();
+        for position in [3, 70, 200, 333, 479] {
+            input[position] = -input[position] / 2;
+        }
+        let decoder = TpcDecoder::<_, _, 480>::new(code, 3, PYNDIAH_ALPHA, &[8, 16, 24, 32]);
+
+        // When
+        let mut decoding = decoder.decode(&input);
+        let iterations = decoding.run(8);
+
+        // Then
+        assert!(iterations.is_some());
+        for (llr, bit) in decoding.get_result().iter().zip(codeword) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use bitvec::prelude::*;
+
+use crate::{block::BlockCode, BitView};
+
+use super::{code::MAX_COMPONENT_BITS, ProductCode};
+
+/// Turbo product code encoder.
+pub struct TpcEncoder<R: BlockCode, C: BlockCode> {
+    code: ProductCode<R, C>,
+}
+
+impl<R: BlockCode, C: BlockCode> TpcEncoder<R, C> {
+    pub fn new(code: ProductCode<R, C>) -> Self {
+        Self { code }
+    }
+
+    /// Encode the `k1 k2` data bits row by row into the `n1 n2` codeword bits row by row.
+    pub fn encode<S: BitView>(&self, data: S, codeword: &mut BitSlice<u8, Msb0>) {
+        let code = &self.code;
+        let (k1, k2) = (code.row_code().data_bits(), code.column_code().data_bits());
+        let (n1, n2) = (code.columns(), code.rows());
+        assert_eq!(code.data_bits(), data.len2());
+        assert_eq!(code.codeword_bits(), codeword.len());
+
+        // Encode the data rows
+        let mut buffer = bitarr![u8, Msb0; 0; MAX_COMPONENT_BITS];
+        for row in 0..k2 {
+            for column in 0..k1 {
+                buffer.set(column, data.get(row * k1 + column));
+            }
+            code.row_code()
+                .encode(&buffer[..k1], &mut codeword[row * n1..(row + 1) * n1]);
+        }
+
+        // Encode all columns, including the parity columns of the rows
+        let mut encoded = bitarr![u8, Msb0; 0; MAX_COMPONENT_BITS];
+        for column in 0..n1 {
+            for row in 0..k2 {
+                buffer.set(row, codeword[row * n1 + column]);
+            }
+            code.column_code().encode(&buffer[..k2], &mut encoded[..n2]);
+            for row in k2..n2 {
+                codeword.set(row * n1 + column, encoded[row]);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::block::Hamming;
+
+    use super::*;
+
+    #[test]
+    fn rows_and_columns_are_codewords() {
+        // Given
+        let code = ProductCode::new(Hamming::new(3, true), Hamming::new(3, true));
+        let encoder = TpcEncoder::new(code);
+        let data = bits![u8, Msb0; 1, 0, 1, 1, 0, 1, 1, 0, 0, 0, 1, 0, 1, 1, 1, 1];
+        let mut codeword = bitarr![u8, Msb0; 0; 64];
+
+        // When
+        encoder.encode(data, &mut codeword[..64]);
+
+        // Then
+        assert_eq!(bits![u8, Msb0; 1, 0, 1, 1], codeword[..4]);
+        assert_eq!(bits![u8, Msb0; 0, 1, 1, 0], codeword[8..12]);
+        for row in 0..8 {
+            let mut bits = codeword;
+            let row_bits = &mut bits[row * 8..(row + 1) * 8];
+            assert_eq!(Some(0), code.row_code().decode(row_bits));
+        }
+        for column in 0..8 {
+            let mut bits = bitarr![u8, Msb0; 0; 8];
+            for row in 0..8 {
+                bits.set(row, codeword[row * 8 + column]);
+            }
+            assert_eq!(Some(0), code.column_code().decode(&mut bits[..8]));
+        }
+    }
+}
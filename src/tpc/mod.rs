@@ -0,0 +1,7 @@
+mod code;
+mod decoder;
+mod encoder;
+
+pub use code::ProductCode;
+pub use decoder::{TpcDecoder, TpcDecoding, PYNDIAH_ALPHA};
+pub use encoder::TpcEncoder;
@@ -1,5 +1,9 @@
 use crate::convolutional::{ConvolutionalCode, ConvolutionalCodeExt};
 
+/// Turbo code with two identical rate 1/n systematic constituent encoders.
+///
+/// The systematic output of the second encoder is not transmitted,
+/// so each input bit produces `2n - 1` output bits.
 #[const_trait]
 pub trait TurboCode: Default {
     type ConstituentEncoderCode: ConvolutionalCode;
@@ -10,6 +14,6 @@ pub trait TurboCode: Default {
 pub(super) fn assert_consituent_encoder<C: TurboCode>() {
     let rate = C::ConstituentEncoderCode::rate();
     assert!(rate.k == 1);
-    assert!(rate.n == 2);
+    assert!(rate.n >= 2);
     assert!(C::ConstituentEncoderCode::is_systematic());
 }
@@ -2,8 +2,8 @@ use core::marker::PhantomData;
 
 use crate::{
     convolutional::{
-        bcjr::{umts::UmtsState, BcjrDecoder, BcjrState, BcjrSymbol},
-        ConvolutionalCode,
+        bcjr::{umts::UmtsState, BcjrDecoder, BcjrSymbol},
+        ConvolutionalCode, ConvolutionalCodeExt, SisoDecoder,
     },
     interleaver::{Interleaver, InterleaverMapping},
     Llr,
@@ -12,18 +12,39 @@ use heapless::Vec;
 
 use super::{code::assert_consituent_encoder, TurboCode, TurboSymbol};
 
-pub struct TurboDecoder<
+/// Turbo decoder with a generic soft-in/soft-out constituent decoder.
+///
+/// The constituent decoder `D` can be any soft-in/soft-out decoder for the constituent code,
+/// e.g. a [`BcjrDecoder`] or a [`SovaDecoder`](crate::convolutional::sova::SovaDecoder).
+pub struct SisoTurboDecoder<
     C: TurboCode,
-    S: BcjrState,
+    D: SisoDecoder,
     const MAX_BLOCK_BITS: usize,
     const MAX_FIRST_TRELLIS_BITS: usize,
     const MAX_SECOND_TRELLIS_BITS: usize,
     const MAX_TRELLIS_BITS: usize,
 > {
     _code: PhantomData<C>,
-    _state: PhantomData<S>,
+    _decoder: PhantomData<D>,
 }
 
+/// Turbo decoder with [`BcjrDecoder`] constituent decoders using the BCJR state `S`.
+pub type TurboDecoder<
+    C,
+    S,
+    const MAX_BLOCK_BITS: usize,
+    const MAX_FIRST_TRELLIS_BITS: usize,
+    const MAX_SECOND_TRELLIS_BITS: usize,
+    const MAX_TRELLIS_BITS: usize,
+> = SisoTurboDecoder<
+    C,
+    BcjrDecoder<<C as TurboCode>::ConstituentEncoderCode, S, MAX_TRELLIS_BITS>,
+    MAX_BLOCK_BITS,
+    MAX_FIRST_TRELLIS_BITS,
+    MAX_SECOND_TRELLIS_BITS,
+    MAX_TRELLIS_BITS,
+>;
+
 pub type UmtsTurboDecoder<
     C,
     const MAX_BLOCK_BITS: usize,
@@ -41,15 +62,15 @@ pub type UmtsTurboDecoder<
 
 impl<
         C: TurboCode,
-        S: BcjrState,
+        D: SisoDecoder,
         const MAX_BLOCK_BITS: usize,
         const MAX_FIRST_TRELLIS_BITS: usize,
         const MAX_SECOND_TRELLIS_BITS: usize,
         const MAX_TRELLIS_BITS: usize,
     >
-    TurboDecoder<
+    SisoTurboDecoder<
         C,
-        S,
+        D,
         MAX_BLOCK_BITS,
         MAX_FIRST_TRELLIS_BITS,
         MAX_SECOND_TRELLIS_BITS,
@@ -58,6 +79,11 @@ impl<
 {
     pub fn new() -> Self {
         assert_consituent_encoder::<C>();
+        assert_eq!(
+            2,
+            C::ConstituentEncoderCode::rate().n,
+            "The constituent decoders only support a single parity output"
+        );
         assert_eq!(
             MAX_FIRST_TRELLIS_BITS,
             trellis_bits::<C::ConstituentEncoderCode>(MAX_BLOCK_BITS, C::TERMINATE_FIRST)
@@ -75,7 +101,7 @@ impl<
         );
         Self {
             _code: PhantomData,
-            _state: PhantomData,
+            _decoder: PhantomData,
         }
     }
 
@@ -88,7 +114,7 @@ impl<
     ) -> TurboDecoding<
         'a,
         C,
-        S,
+        D,
         I,
         MAX_BLOCK_BITS,
         MAX_FIRST_TRELLIS_BITS,
@@ -126,8 +152,8 @@ impl<
 
         TurboDecoding {
             _code: PhantomData,
-            first_bcjr: BcjrDecoder::new(C::TERMINATE_FIRST),
-            second_bcjr: BcjrDecoder::new(C::TERMINATE_SECOND),
+            first_siso: D::new(C::TERMINATE_FIRST),
+            second_siso: D::new(C::TERMINATE_SECOND),
             first_input,
             second_input,
             interleaver,
@@ -148,15 +174,15 @@ pub const fn trellis_bits<C: ConvolutionalCode>(block_bits: usize, terminated: b
 
 impl<
         C: TurboCode,
-        S: BcjrState,
+        D: SisoDecoder,
         const MAX_BLOCK_BITS: usize,
         const MAX_FIRST_TRELLIS_BITS: usize,
         const MAX_SECOND_TRELLIS_BITS: usize,
         const MAX_TRELLIS_BITS: usize,
     > Default
-    for TurboDecoder<
+    for SisoTurboDecoder<
         C,
-        S,
+        D,
         MAX_BLOCK_BITS,
         MAX_FIRST_TRELLIS_BITS,
         MAX_SECOND_TRELLIS_BITS,
@@ -164,14 +190,14 @@ impl<
     >
 {
     fn default() -> Self {
-        TurboDecoder::new()
+        Self::new()
     }
 }
 
 pub struct TurboDecoding<
     'a,
     C: TurboCode,
-    S: BcjrState,
+    D: SisoDecoder,
     I: Interleaver,
     const MAX_BLOCK_BITS: usize,
     const MAX_FIRST_TRELLIS_BITS: usize,
@@ -179,8 +205,8 @@ pub struct TurboDecoding<
     const MAX_TRELLIS_BITS: usize,
 > {
     _code: PhantomData<C>,
-    first_bcjr: BcjrDecoder<C::ConstituentEncoderCode, S, MAX_TRELLIS_BITS>,
-    second_bcjr: BcjrDecoder<C::ConstituentEncoderCode, S, MAX_TRELLIS_BITS>,
+    first_siso: D,
+    second_siso: D,
     interleaver: &'a I,
     first_input: Vec<BcjrSymbol, MAX_FIRST_TRELLIS_BITS>,
     second_input: Vec<BcjrSymbol, MAX_SECOND_TRELLIS_BITS>,
@@ -190,7 +216,7 @@ pub struct TurboDecoding<
 
 impl<
         C,
-        S,
+        D,
         I,
         const MAX_BLOCK_BITS: usize,
         const MAX_FIRST_TRELLIS_BITS: usize,
@@ -200,7 +226,7 @@ impl<
     TurboDecoding<
         '_,
         C,
-        S,
+        D,
         I,
         MAX_BLOCK_BITS,
         MAX_FIRST_TRELLIS_BITS,
@@ -209,7 +235,7 @@ impl<
     >
 where
     C: TurboCode,
-    S: BcjrState,
+    D: SisoDecoder,
     I: Interleaver,
 {
     pub fn get_result(&self) -> &[Llr] {
@@ -223,7 +249,7 @@ where
     }
 
     fn run_first_decoder(&mut self) {
-        self.first_bcjr
+        self.first_siso
             .decode(&self.first_input, &mut self.bcjr_result);
 
         // Compute the extrinsic information from the a-posteriori LLR (Lapp) from the first decoder,
@@ -244,7 +270,7 @@ where
     }
 
     fn run_second_decoder(&mut self) {
-        self.second_bcjr
+        self.second_siso
             .decode(&self.second_input, &mut self.bcjr_result);
 
         // Compute the extrinsic information from the a-posteriori LLR (Lapp) from second decoder,
@@ -273,7 +299,7 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::{catalog, interleaver::qpp::QppInterleaver};
+    use crate::{catalog, convolutional::sova::SovaDecoder, interleaver::qpp::QppInterleaver};
 
     use super::*;
 
@@ -343,4 +369,70 @@ mod tests {
             iteration_results[2]
         );
     }
+
+    #[test]
+    fn can_decode_excel_example_with_sova() {
+        // Given
+        let decoder = SisoTurboDecoder::<
+            catalog::UMTS,
+            SovaDecoder<catalog::UMTS, 8, 19>,
+            16,
+            19,
+            19,
+            19,
+        >::new();
+        let interleaver = QppInterleaver::new(16, 1, 4);
+
+        let input = [
+            TurboSymbol::new(-4, -4, -4),
+            TurboSymbol::new(-4, -4, -4),
+            TurboSymbol::new(-4, -4, -4),
+            TurboSymbol::new(4, 4, 4),
+            TurboSymbol::new(-4, 4, 4),
+            TurboSymbol::new(-4, 4, 4),
+            TurboSymbol::new(4, -4, -4),
+            TurboSymbol::new(4, -4, 4),
+            TurboSymbol::new(-4, -4, 4),
+            TurboSymbol::new(-4, 4, -4),
+            TurboSymbol::new(-4, 4, -4),
+            TurboSymbol::new(-4, 4, 4),
+            TurboSymbol::new(-4, -4, -4),
+            TurboSymbol::new(-4, -4, 4),
+            TurboSymbol::new(4, -4, -4),
+            TurboSymbol::new(-4, 4, 4),
+        ];
+        let first_termination = [
+            BcjrSymbol::new(4, 4),
+            BcjrSymbol::new(-4, 4),
+            BcjrSymbol::new(4, 4),
+        ];
+        let second_termination = [
+            BcjrSymbol::new(-4, -4),
+            BcjrSymbol::new(-4, -4),
+            BcjrSymbol::new(-4, -4),
+        ];
+
+        // When
+        let mut decoding = decoder.decode(
+            &input,
+            &interleaver,
+            &first_termination,
+            &second_termination,
+        );
+
+        for _ in 0..2 {
+            decoding.run_decode_iteration();
+        }
+
+        // Then
+        let decisions: std::vec::Vec<bool> = decoding.get_result().iter().map(|x| *x > 0).collect();
+        assert_eq!(
+            [
+                false, false, false, true, false, false, true, true, false, false, false, false,
+                false, false, true, false
+            ]
+            .to_vec(),
+            decisions
+        );
+    }
 }
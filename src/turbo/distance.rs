@@ -0,0 +1,536 @@
+//! Estimation of the low weight codewords of a turbo code with a given interleaver.
+//!
+//! The low weight codewords of a turbo code are caused by source patterns of low weight
+//! that are short in the input of both constituent encoders, so that both recursive encoders return to the zero state quickly.
+//! The estimate enumerates all source patterns of weight 1, 2 and 3 that span at most `span` bits
+//! in the input of either constituent encoder, and computes the exact weight of their codewords.
+//! The estimated distance is therefore an upper bound on the minimum distance of the code.
+
+use crate::{
+    convolutional::{ConvolutionalCode, ConvolutionalCodeExt, EncoderOutput},
+    interleaver::{qpp::QppInterleaver, Interleaver, InterleaverMapping},
+    turbo::code::assert_consituent_encoder,
+};
+
+use super::TurboCode;
+
+/// The maximum weight of the enumerated source patterns.
+pub const MAX_INPUT_WEIGHT: usize = 3;
+
+/// The estimated distance properties of a turbo code with a given interleaver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DistanceEstimate {
+    /// The smallest codeword weight found.
+    pub distance: usize,
+    /// The number of codewords found with the weight.
+    pub multiplicity: usize,
+    /// The total source weight of the codewords found with the weight.
+    pub information_weight: usize,
+}
+
+impl DistanceEstimate {
+    /// Get whether the estimate is better than `other`,
+    /// i.e. whether it has a larger distance, or fewer codewords or source errors at the same distance.
+    pub fn is_better_than(&self, other: &DistanceEstimate) -> bool {
+        (self.distance, other.multiplicity, other.information_weight)
+            > (other.distance, self.multiplicity, self.information_weight)
+    }
+}
+
+/// A low weight codeword.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LowWeightCodeword {
+    /// The codeword weight, including the termination.
+    pub weight: usize,
+    /// The number of source bits that are 1.
+    pub input_weight: usize,
+    /// The source positions of the bits that are 1 in increasing order.
+    /// Only the first `input_weight` positions are used.
+    pub positions: [usize; MAX_INPUT_WEIGHT],
+}
+
+/// Estimate the minimum distance of a turbo code with the interleaver.
+///
+/// `STATES` must be the number of states of the constituent code,
+/// and `MAX_BLOCK_BITS` must be at least the interleaver length.
+/// The cost grows with the interleaver length times the square of `span`.
+pub fn estimate_distance<C: TurboCode, const STATES: usize, const MAX_BLOCK_BITS: usize>(
+    interleaver: &impl Interleaver,
+    span: usize,
+) -> DistanceEstimate {
+    estimate::<C, STATES, MAX_BLOCK_BITS>(interleaver, span, None).unwrap()
+}
+
+/// Find the lowest weight codewords of a turbo code with the interleaver, see [`estimate_distance`].
+///
+/// The codewords are written to `codewords` in increasing weight order.
+/// Returns the number of codewords written.
+pub fn low_weight_codewords<C: TurboCode, const STATES: usize, const MAX_BLOCK_BITS: usize>(
+    interleaver: &impl Interleaver,
+    span: usize,
+    codewords: &mut [LowWeightCodeword],
+) -> usize {
+    let mut count = 0;
+    Search::<C, STATES, MAX_BLOCK_BITS>::new(interleaver).run(span, |codeword| {
+        // Insert the codeword in the sorted list if it is among the lowest weights
+        let index = codewords[..count].partition_point(|other| other.weight <= codeword.weight);
+        if index < codewords.len() {
+            count = (count + 1).min(codewords.len());
+            codewords.copy_within(index..count - 1, index + 1);
+            codewords[index] = codeword;
+        }
+        true
+    });
+    count
+}
+
+/// Search for the QPP interleaver of the block length that gives the best distance estimate, see [`estimate_distance`].
+///
+/// All coefficients that form a permutation polynomial with a non-zero quadratic coefficient are tried,
+/// except those that give the same permutation as other coefficients.
+/// Two polynomials give the same permutation exactly when their difference is `K/2 x^2 + K/2 x` for an even length `K`,
+/// so for an even length only `f2 < K/2` is tried, where `f2 = K/2` gives a linear permutation.
+/// `None` is returned if there are no such coefficients for the length, e.g. when it is a prime.
+///
+/// The distance is estimated for up to `K^2` coefficients, or `K^2 / 2` for an even length,
+/// and each estimate visits about `K span^2` source patterns, although most estimates stop early when they cannot improve the best.
+pub fn search_qpp<C: TurboCode, const STATES: usize, const MAX_BLOCK_BITS: usize>(
+    length: usize,
+    span: usize,
+) -> Option<(QppInterleaver, DistanceEstimate)> {
+    assert!(length <= u16::MAX as usize);
+
+    let max_f2 = if length % 2 == 0 { length / 2 } else { length };
+    let mut best: Option<(QppInterleaver, DistanceEstimate)> = None;
+    for f2 in 1..max_f2 as u16 {
+        for f1 in 1..length as u16 {
+            if !QppInterleaver::is_permutation(length, f1, f2) {
+                continue;
+            }
+
+            let interleaver = QppInterleaver::new(length, f1, f2);
+            let bound = best.map(|(_, estimate)| estimate);
+            if let Some(estimate) = estimate::<C, STATES, MAX_BLOCK_BITS>(&interleaver, span, bound)
+            {
+                if bound.is_none_or(|bound| estimate.is_better_than(&bound)) {
+                    best = Some((interleaver, estimate));
+                }
+            }
+        }
+    }
+    best
+}
+
+/// Estimate the distance, or return `None` as soon as the estimate is known to not be better than `bound`.
+fn estimate<C: TurboCode, const STATES: usize, const MAX_BLOCK_BITS: usize>(
+    interleaver: &impl Interleaver,
+    span: usize,
+    bound: Option<DistanceEstimate>,
+) -> Option<DistanceEstimate> {
+    let mut estimate = DistanceEstimate {
+        distance: usize::MAX,
+        multiplicity: 0,
+        information_weight: 0,
+    };
+    let completed = Search::<C, STATES, MAX_BLOCK_BITS>::new(interleaver).run(span, |codeword| {
+        if codeword.weight < estimate.distance {
+            estimate = DistanceEstimate {
+                distance: codeword.weight,
+                multiplicity: 0,
+                information_weight: 0,
+            };
+        }
+        if codeword.weight == estimate.distance {
+            estimate.multiplicity += 1;
+            estimate.information_weight += codeword.input_weight;
+        }
+
+        // The estimate can only get worse as more codewords are found
+        bound.is_none_or(|bound| {
+            (estimate.distance, bound.multiplicity) >= (bound.distance, estimate.multiplicity)
+        })
+    });
+    completed.then_some(estimate)
+}
+
+/// The enumeration of the low weight source patterns for a turbo code and an interleaver.
+struct Search<C: TurboCode, const STATES: usize, const MAX_BLOCK_BITS: usize> {
+    response: ZeroInputResponse<C, STATES>,
+    length: usize,
+    /// The source position of each input of the second encoder.
+    interleaved: [u16; MAX_BLOCK_BITS],
+    /// The input of the second encoder for each source position.
+    deinterleaved: [u16; MAX_BLOCK_BITS],
+}
+
+impl<C: TurboCode, const STATES: usize, const MAX_BLOCK_BITS: usize>
+    Search<C, STATES, MAX_BLOCK_BITS>
+{
+    fn new(interleaver: &impl Interleaver) -> Self {
+        assert_consituent_encoder::<C>();
+        let length = interleaver.len();
+        assert!(length <= MAX_BLOCK_BITS);
+        assert!(length <= u16::MAX as usize);
+
+        let mut interleaved = [0; MAX_BLOCK_BITS];
+        let mut deinterleaved = [0; MAX_BLOCK_BITS];
+        for InterleaverMapping(i, ii) in interleaver.iter() {
+            interleaved[i] = ii as u16;
+            deinterleaved[ii] = i as u16;
+        }
+
+        Self {
+            response: ZeroInputResponse::new(),
+            length,
+            interleaved,
+            deinterleaved,
+        }
+    }
+
+    /// Visit the codeword of each source pattern until `visit` returns `false`.
+    /// Returns whether all patterns were visited.
+    fn run(&self, span: usize, mut visit: impl FnMut(LowWeightCodeword) -> bool) -> bool {
+        let length = self.length;
+        let span = span.max(1);
+        for second in [false, true] {
+            for a in 0..length {
+                let end = length.min(a + span);
+                if !self.visit(&[a], second, span, &mut visit) {
+                    return false;
+                }
+                for b in a + 1..end {
+                    if !self.visit(&[a, b], second, span, &mut visit) {
+                        return false;
+                    }
+                    for c in b + 1..end {
+                        if !self.visit(&[a, b, c], second, span, &mut visit) {
+                            return false;
+                        }
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    /// Visit the codeword for the inputs of the first or `second` encoder in increasing order.
+    fn visit(
+        &self,
+        inputs: &[usize],
+        second: bool,
+        span: usize,
+        visit: &mut impl FnMut(LowWeightCodeword) -> bool,
+    ) -> bool {
+        let weight = inputs.len();
+        let mut positions = [0; MAX_INPUT_WEIGHT];
+        let mut other = [0; MAX_INPUT_WEIGHT];
+        for (index, input) in inputs.iter().enumerate() {
+            if second {
+                positions[index] = self.interleaved[*input] as usize;
+            } else {
+                positions[index] = *input;
+                other[index] = self.deinterleaved[*input] as usize;
+            }
+        }
+        if second {
+            positions[..weight].sort_unstable();
+            if positions[weight - 1] - positions[0] < span {
+                // The pattern is also short in the input of the first encoder, so it has already been visited
+                return true;
+            }
+            other[..weight].copy_from_slice(inputs);
+        } else {
+            other[..weight].sort_unstable();
+        }
+
+        visit(LowWeightCodeword {
+            weight: weight
+                + self
+                    .response
+                    .weight(&positions[..weight], self.length, C::TERMINATE_FIRST)
+                + self
+                    .response
+                    .weight(&other[..weight], self.length, C::TERMINATE_SECOND),
+            input_weight: weight,
+            positions,
+        })
+    }
+}
+
+/// The output of a constituent encoder for zero input from each state.
+///
+/// The zero input state sequence is periodic, so the parity weight of any number of zero inputs is found from a single period.
+struct ZeroInputResponse<C: TurboCode, const STATES: usize> {
+    /// The number of steps before the state repeats.
+    periods: [usize; STATES],
+    /// The state after each step within the period.
+    states: [[usize; STATES]; STATES],
+    /// The parity weight of the steps before each step within the period.
+    weights: [[usize; STATES]; STATES],
+    /// The parity weight of a full period.
+    period_weights: [usize; STATES],
+    _code: core::marker::PhantomData<C>,
+}
+
+impl<C: TurboCode, const STATES: usize> ZeroInputResponse<C, STATES> {
+    fn new() -> Self {
+        assert_eq!(STATES, 1 << C::ConstituentEncoderCode::mem());
+
+        let mut response = Self {
+            periods: [0; STATES],
+            states: [[0; STATES]; STATES],
+            weights: [[0; STATES]; STATES],
+            period_weights: [0; STATES],
+            _code: core::marker::PhantomData,
+        };
+        for start in 0..STATES {
+            let mut state = start;
+            let mut weight = 0;
+            let mut period = 0;
+            loop {
+                response.states[start][period] = state;
+                response.weights[start][period] = weight;
+                weight += Self::parity_weight(state, false);
+                state = C::ConstituentEncoderCode::get_next_state(state, false);
+                period += 1;
+                if state == start {
+                    break;
+                }
+                assert!(
+                    period < STATES,
+                    "The zero input state sequence must be periodic"
+                );
+            }
+            response.periods[start] = period;
+            response.period_weights[start] = weight;
+        }
+        response
+    }
+
+    /// Get the state and the parity weight after `steps` zero inputs.
+    fn run(&self, state: usize, steps: usize) -> (usize, usize) {
+        let period = self.periods[state];
+        let remainder = steps % period;
+        (
+            self.states[state][remainder],
+            (steps / period) * self.period_weights[state] + self.weights[state][remainder],
+        )
+    }
+
+    /// Get the parity weight of the encoder for a block with ones at the `inputs` in increasing order,
+    /// including the full weight of the termination if the encoder is `terminated`.
+    fn weight(&self, inputs: &[usize], length: usize, terminated: bool) -> usize {
+        let mut state = 0;
+        let mut weight = 0;
+        let mut time = 0;
+        for input in inputs {
+            let (next, run_weight) = self.run(state, input - time);
+            weight += run_weight + Self::parity_weight(next, true);
+            state = C::ConstituentEncoderCode::get_next_state(next, true);
+            time = input + 1;
+        }
+        let (mut state, run_weight) = self.run(state, length - time);
+        weight += run_weight;
+
+        if terminated {
+            for _ in 0..C::ConstituentEncoderCode::mem() {
+                let input = C::ConstituentEncoderCode::get_termination_input(state);
+                weight += Self::output_weight(state, input);
+                state = C::ConstituentEncoderCode::get_next_state(state, input);
+            }
+        }
+        weight
+    }
+
+    /// Get the weight of the parity outputs, i.e. excluding the systematic output.
+    fn parity_weight(state: usize, input: bool) -> usize {
+        Self::output_weight(state, input) - input as usize
+    }
+
+    /// Get the weight of all outputs, ignoring the inverted outputs.
+    fn output_weight(state: usize, input: bool) -> usize {
+        let output: EncoderOutput = C::ConstituentEncoderCode::get_output(state, input)
+            ^ C::ConstituentEncoderCode::INVERTED_OUTPUTS;
+        output.count_ones() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog,
+        interleaver::umts,
+        turbo::{TurboEncoder, TurboEncoderOutputWriter},
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct WeightWriter {
+        weight: usize,
+    }
+
+    impl TurboEncoderOutputWriter for WeightWriter {
+        fn write_output(&mut self, output: EncoderOutput) {
+            self.weight += output.count_ones() as usize;
+        }
+
+        fn write_termination_output(&mut self, _encoder_index: usize, output: EncoderOutput) {
+            self.weight += output.count_ones() as usize;
+        }
+    }
+
+    /// Get the distance by encoding all sources with at most three ones
+    /// that span less than `span` bits in the input of either encoder.
+    fn encoded_distance(interleaver: &QppInterleaver, span: usize) -> DistanceEstimate {
+        let encoder = TurboEncoder::<catalog::UMTS>::new();
+        let length = interleaver.len();
+        let mut deinterleaved = std::vec![0; length];
+        for InterleaverMapping(i, ii) in interleaver.iter() {
+            deinterleaved[ii] = i;
+        }
+        let mut estimate = DistanceEstimate {
+            distance: usize::MAX,
+            multiplicity: 0,
+            information_weight: 0,
+        };
+        for a in 0..length {
+            for b in a..length {
+                for c in b..length {
+                    let mut source = std::vec![false; length];
+                    source[a] = true;
+                    source[b] = true;
+                    source[c] = true;
+                    let input_weight = source.iter().filter(|bit| **bit).count();
+                    if (input_weight == 1 && (a != b || b != c)) || (input_weight == 2 && a != b) {
+                        // Only count each source once
+                        continue;
+                    }
+                    let second = [a, b, c].map(|position| deinterleaved[position]);
+                    let second_span = second.iter().max().unwrap() - second.iter().min().unwrap();
+                    if c - a >= span && second_span >= span {
+                        continue;
+                    }
+
+                    let mut writer = WeightWriter::default();
+                    encoder.encode(&source[..], interleaver, &mut writer);
+                    if writer.weight < estimate.distance {
+                        estimate.distance = writer.weight;
+                        estimate.multiplicity = 0;
+                        estimate.information_weight = 0;
+                    }
+                    if writer.weight == estimate.distance {
+                        estimate.multiplicity += 1;
+                        estimate.information_weight += input_weight;
+                    }
+                }
+            }
+        }
+        estimate
+    }
+
+    #[test]
+    fn estimate_matches_encoder() {
+        let cases = [
+            (40, 3, 10, 40),
+            (48, 7, 12, 48),
+            (44, 21, 22, 44),
+            (40, 1, 0, 40),
+            (40, 3, 10, 6),
+            (48, 7, 12, 10),
+        ];
+        for (length, f1, f2, span) in cases {
+            // Given
+            let interleaver = QppInterleaver::new(length, f1, f2);
+
+            // When
+            let estimate = estimate_distance::<catalog::UMTS, 8, 48>(&interleaver, span);
+
+            // Then
+            assert_eq!(encoded_distance(&interleaver, span), estimate);
+        }
+    }
+
+    #[test]
+    fn can_find_low_weight_codewords() {
+        // Given
+        let interleaver = umts::create(64).unwrap();
+        let mut codewords = [LowWeightCodeword::default(); 4];
+
+        // When
+        let count = low_weight_codewords::<catalog::UMTS, 8, 64>(&interleaver, 16, &mut codewords);
+
+        // Then
+        let estimate = estimate_distance::<catalog::UMTS, 8, 64>(&interleaver, 16);
+        assert_eq!(4, count);
+        assert_eq!(estimate.distance, codewords[0].weight);
+        assert!(codewords.windows(2).all(|w| w[0].weight <= w[1].weight));
+        for codeword in codewords {
+            let mut source = [false; 64];
+            for position in &codeword.positions[..codeword.input_weight] {
+                source[*position] = true;
+            }
+            let mut writer = WeightWriter::default();
+            TurboEncoder::<catalog::UMTS>::new().encode(&source, &interleaver, &mut writer);
+            assert_eq!(codeword.weight, writer.weight);
+        }
+    }
+
+    #[test]
+    fn can_search_qpp() {
+        // Given
+        let length = 48;
+
+        // When
+        let (interleaver, estimate) = search_qpp::<catalog::UMTS, 8, 48>(length, 12).unwrap();
+
+        // Then
+        assert!(QppInterleaver::is_permutation(
+            length,
+            interleaver.f1(),
+            interleaver.f2()
+        ));
+        assert!(interleaver.f2() < 24);
+        assert_eq!(
+            estimate,
+            estimate_distance::<catalog::UMTS, 8, 48>(&interleaver, 12)
+        );
+        let linear = estimate_distance::<catalog::UMTS, 8, 48>(&QppInterleaver::new(48, 1, 0), 12);
+        assert!(estimate.is_better_than(&linear));
+    }
+
+    #[test]
+    fn skips_linear_permutations() {
+        // Given
+        // The only quadratic coefficient for 44 is 22, where 22 x^2 = 22 x mod 44
+        let length = 44;
+
+        // When
+        let result = search_qpp::<catalog::UMTS, 8, 44>(length, 8);
+
+        // Then
+        assert!(QppInterleaver::is_permutation(length, 1, 22));
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn search_is_not_worse_than_lte() {
+        // Given
+        let lte = umts::create(40).unwrap();
+
+        // When
+        let (_, estimate) = search_qpp::<catalog::UMTS, 8, 40>(40, 10).unwrap();
+
+        // Then
+        let lte_estimate = estimate_distance::<catalog::UMTS, 8, 40>(&lte, 10);
+        assert!(!lte_estimate.is_better_than(&estimate));
+    }
+
+    #[test]
+    fn has_no_qpp_for_prime_length() {
+        assert_eq!(
+            None,
+            search_qpp::<catalog::UMTS, 8, 43>(43, 8).map(|(_, estimate)| estimate)
+        );
+    }
+}
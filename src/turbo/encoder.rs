@@ -17,8 +17,11 @@ pub trait TurboEncoderOutputWriter {
     /// Write output bits.
     ///
     /// Bit 0 corresponds to the systematic bit,
-    /// bit 1 to the output of the first constituent encoder, and
-    /// bit 2 to the output of the second constituent encoder.
+    /// bits 1 to n-1 to the parity outputs of the first constituent encoder, and
+    /// bits n to 2n-2 to the parity outputs of the second constituent encoder,
+    /// where `n` is the number of outputs of the rate 1/n constituent code.
+    /// E.g. for a rate 1/2 constituent code,
+    /// bit 1 is the output of the first encoder and bit 2 is the output of the second.
     ///
     /// # Arguments
     ///
@@ -28,7 +31,7 @@ pub trait TurboEncoderOutputWriter {
     /// Write termination bits.
     ///
     /// Bit 0 corresponds to the termination input and
-    /// bit 1 to the corresponding parity output.
+    /// bits 1 to n-1 to the corresponding parity outputs.
     ///
     /// # Arguments
     ///
@@ -54,13 +57,16 @@ impl<C: TurboCode> TurboEncoder<C> {
         let mut first_encoder = ConvolutionalEncoder::<C::ConstituentEncoderCode>::default();
         let mut second_encoder = ConvolutionalEncoder::<C::ConstituentEncoderCode>::default();
 
+        // The number of outputs from each constituent encoder
+        let n = C::ConstituentEncoderCode::rate().n as usize;
+
         for InterleaverMapping(i, ii) in interleaver.iter() {
             let input = source.get(i);
             let first_output = first_encoder.get_output(input);
             let second_output = second_encoder.get_output(source.get(ii));
 
-            // Write the parity and systematic output from the first encoder and the parity from the second encoder.
-            writer.write_output(first_output | (second_output & 0x02) << 1);
+            // Write the parity and systematic output from the first encoder and the parities from the second encoder.
+            writer.write_output(first_output | (second_output >> 1) << n);
         }
 
         if C::TERMINATE_FIRST || C::TERMINATE_SECOND {
@@ -79,6 +85,14 @@ impl<C: TurboCode> TurboEncoder<C> {
     }
 }
 
+/// Get the number of encoded bits for a block of `block_bits` source bits before any puncturing,
+/// including the termination bits.
+pub fn encoded_bits<C: TurboCode>(block_bits: usize) -> usize {
+    let n = C::ConstituentEncoderCode::rate().n as usize;
+    let terminations = C::TERMINATE_FIRST as usize + C::TERMINATE_SECOND as usize;
+    block_bits * (2 * n - 1) + terminations * C::ConstituentEncoderCode::mem() * n
+}
+
 impl<C: TurboCode> Default for TurboEncoder<C> {
     fn default() -> Self {
         TurboEncoder::new()
@@ -186,6 +200,7 @@ mod tests {
         ], 1, 4);
     }
 
+    #[allow(clippy::into_iter_on_ref)]
     fn can_encode_case(expected: &[EncoderOutput], input: &[u8], f1: u16, f2: u16) {
         // Given
         let input: Vec<bool> = input.into_iter().map(|b| *b == 1).collect();
@@ -0,0 +1,263 @@
+use bitvec::prelude::*;
+
+use crate::{
+    catalog,
+    convolutional::{bcjr::BcjrSymbol, ConvolutionalCodeExt, EncoderOutput},
+    Llr,
+};
+
+use super::{TurboEncoderOutputWriter, TurboSymbol};
+
+/// The number of termination steps for each constituent encoder.
+const TAIL_STEPS: usize = 3;
+
+/// The number of tail bits in each of the three output streams.
+pub const TAIL_BITS_PER_STREAM: usize = 4;
+
+/// Turbo encoder output writer that writes the LTE output streams `d(0)`, `d(1)` and `d(2)` per 3GPP TS 36.212 §5.1.3.2.
+///
+/// For `k < K` the streams are `d(0) = x`, `d(1) = z` and `d(2) = z'`.
+/// The 12 tail bits are multiplexed into the last four bits of each stream as
+///
+/// | Stream | K     | K + 1 | K + 2 | K + 3  |
+/// |--------|-------|-------|-------|--------|
+/// | d(0)   | x_K   | z_K+1 | x'_K  | z'_K+1 |
+/// | d(1)   | z_K   | x_K+2 | z'_K  | x'_K+2 |
+/// | d(2)   | x_K+1 | z_K+2 | x'_K+1| z'_K+2 |
+///
+/// so each stream has `K + 4` bits.
+pub struct LteStreamWriter<'a> {
+    streams: [&'a mut BitSlice<u8, Msb0>; 3],
+    block_len: usize,
+    written: usize,
+    termination_steps: [usize; 2],
+}
+
+impl<'a> LteStreamWriter<'a> {
+    /// Create a new writer for a block of `block_len` bits,
+    /// where each stream must hold at least `block_len + 4` bits.
+    pub fn new(
+        block_len: usize,
+        d0: &'a mut BitSlice<u8, Msb0>,
+        d1: &'a mut BitSlice<u8, Msb0>,
+        d2: &'a mut BitSlice<u8, Msb0>,
+    ) -> Self {
+        assert_eq!(TAIL_STEPS, catalog::UMTS::mem());
+        let streams = [d0, d1, d2];
+        for stream in streams.iter() {
+            assert!(stream.len() >= block_len + TAIL_BITS_PER_STREAM);
+        }
+
+        Self {
+            streams,
+            block_len,
+            written: 0,
+            termination_steps: [0; 2],
+        }
+    }
+
+    /// Get the total number of bits written across all three streams.
+    pub fn bit_count(&self) -> usize {
+        self.written
+    }
+
+    fn write(&mut self, stream: usize, index: usize, bit: bool) {
+        self.streams[stream].set(index, bit);
+        self.written += 1;
+    }
+}
+
+impl TurboEncoderOutputWriter for LteStreamWriter<'_> {
+    fn write_output(&mut self, output: EncoderOutput) {
+        let index = self.written / 3;
+        assert!(index < self.block_len);
+
+        for stream in 0..3 {
+            self.write(stream, index, output & (1 << stream) != 0);
+        }
+    }
+
+    fn write_termination_output(&mut self, encoder_index: usize, output: EncoderOutput) {
+        let step = self.termination_steps[encoder_index];
+        assert!(step < TAIL_STEPS);
+        self.termination_steps[encoder_index] += 1;
+
+        // The tail bits of each encoder are x_K, z_K, x_K+1, z_K+1, x_K+2, z_K+2,
+        // where tail bit `n` is placed in stream `n mod 3` at `K + 2 encoder_index + n / 3`
+        for (offset, bit) in [output & 1 != 0, output & 2 != 0].into_iter().enumerate() {
+            let n = 2 * step + offset;
+            let index = self.block_len + 2 * encoder_index + n / 3;
+            self.write(n % 3, index, bit);
+        }
+    }
+}
+
+/// Soft reader for the LTE output streams `d(0)`, `d(1)` and `d(2)` per 3GPP TS 36.212 §5.1.3.2.
+///
+/// The reader is the inverse of the [`LteStreamWriter`] and demultiplexes the received stream llrs
+/// into the turbo symbols and the termination symbols for each of the two constituent decoders.
+pub struct LteStreamReader<'a> {
+    streams: [&'a [Llr]; 3],
+}
+
+impl<'a> LteStreamReader<'a> {
+    /// Create a new reader where each stream holds the `K + 4` received llrs.
+    pub fn new(d0: &'a [Llr], d1: &'a [Llr], d2: &'a [Llr]) -> Self {
+        assert!(d0.len() >= TAIL_BITS_PER_STREAM);
+        assert_eq!(d0.len(), d1.len());
+        assert_eq!(d0.len(), d2.len());
+        Self {
+            streams: [d0, d1, d2],
+        }
+    }
+
+    /// The block length `K`.
+    pub fn block_len(&self) -> usize {
+        self.streams[0].len() - TAIL_BITS_PER_STREAM
+    }
+
+    /// Read the turbo symbols and the termination symbols for the first and second constituent decoders.
+    pub fn read(
+        &self,
+        input: &mut [TurboSymbol],
+        first_termination: &mut [BcjrSymbol; TAIL_STEPS],
+        second_termination: &mut [BcjrSymbol; TAIL_STEPS],
+    ) {
+        let block_len = self.block_len();
+        assert!(input.len() >= block_len);
+
+        let [d0, d1, d2] = self.streams;
+        for (index, symbol) in input[..block_len].iter_mut().enumerate() {
+            *symbol = TurboSymbol::new(d0[index], d1[index], d2[index]);
+        }
+
+        for (encoder_index, termination) in [first_termination, second_termination]
+            .into_iter()
+            .enumerate()
+        {
+            let tail = |n: usize| self.streams[n % 3][block_len + 2 * encoder_index + n / 3];
+            for (step, symbol) in termination.iter_mut().enumerate() {
+                *symbol = BcjrSymbol::new(tail(2 * step), tail(2 * step + 1));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        interleaver::qpp::QppInterleaver,
+        turbo::{umts::UmtsTurboDecoder, TurboEncoder},
+        LlrMul,
+    };
+
+    use super::*;
+
+    #[test]
+    fn can_multiplex_tail_bits() {
+        // Given
+        let source: Vec<bool> = (0..40).map(|i| (i * 3 + i / 7) % 5 < 2).collect();
+        let encoder = TurboEncoder::<catalog::UMTS>::default();
+        let interleaver = QppInterleaver::new(40, 3, 10);
+        let mut tails = [[false; 6]; 2];
+        let mut steps = [0; 2];
+        encoder.encode(
+            &source[..],
+            &interleaver,
+            &mut TailWriter(|encoder_index: usize, output: EncoderOutput| {
+                let step = steps[encoder_index];
+                tails[encoder_index][2 * step] = output & 1 != 0;
+                tails[encoder_index][2 * step + 1] = output & 2 != 0;
+                steps[encoder_index] += 1;
+            }),
+        );
+        let [x, z] = [0, 1].map(|offset| [0, 1, 2].map(|step| tails[0][2 * step + offset]));
+        let [xi, zi] = [0, 1].map(|offset| [0, 1, 2].map(|step| tails[1][2 * step + offset]));
+        let mut d = [[0u8; 6]; 3];
+        let [d0, d1, d2] = &mut d;
+
+        // When
+        let mut writer = LteStreamWriter::new(
+            40,
+            d0.view_bits_mut(),
+            d1.view_bits_mut(),
+            d2.view_bits_mut(),
+        );
+        encoder.encode(&source[..], &interleaver, &mut writer);
+
+        // Then
+        assert_eq!(3 * 44, writer.bit_count());
+        let tail = |stream: &[u8; 6]| -> Vec<bool> {
+            stream.view_bits::<Msb0>()[40..44]
+                .iter()
+                .map(|bit| *bit)
+                .collect()
+        };
+        assert_eq!([x[0], z[1], xi[0], zi[1]].to_vec(), tail(&d[0]));
+        assert_eq!([z[0], x[2], zi[0], xi[2]].to_vec(), tail(&d[1]));
+        assert_eq!([x[1], z[2], xi[1], zi[2]].to_vec(), tail(&d[2]));
+        let d0: Vec<bool> = d[0].view_bits::<Msb0>()[..40]
+            .iter()
+            .map(|bit| *bit)
+            .collect();
+        assert_eq!(source, d0);
+    }
+
+    #[test]
+    fn can_read_streams() {
+        // Given
+        let source: Vec<bool> = (0..40).map(|i| (i * 3 + i / 7) % 5 < 2).collect();
+        let encoder = TurboEncoder::<catalog::UMTS>::default();
+        let interleaver = QppInterleaver::new(40, 3, 10);
+        let mut d = [[0u8; 6]; 3];
+        let [d0, d1, d2] = &mut d;
+        let mut writer = LteStreamWriter::new(
+            40,
+            d0.view_bits_mut(),
+            d1.view_bits_mut(),
+            d2.view_bits_mut(),
+        );
+        encoder.encode(&source[..], &interleaver, &mut writer);
+        let [mut d0, d1, d2] = d.map(|stream| -> Vec<Llr> {
+            stream.view_bits::<Msb0>()[..44]
+                .iter()
+                .map(|bit| bit.mul(4))
+                .collect()
+        });
+        d0[42] = -d0[42];
+
+        let reader = LteStreamReader::new(&d0, &d1, &d2);
+        let mut input = [TurboSymbol::new(0, 0, 0); 40];
+        let mut first_termination = [BcjrSymbol::new(0, 0); 3];
+        let mut second_termination = [BcjrSymbol::new(0, 0); 3];
+        let decoder = UmtsTurboDecoder::<catalog::UMTS, 40, 43, 43, 43>::default();
+
+        // When
+        reader.read(&mut input, &mut first_termination, &mut second_termination);
+        let mut decoding = decoder.decode(
+            &input,
+            &interleaver,
+            &first_termination,
+            &second_termination,
+        );
+        for _ in 0..3 {
+            decoding.run_decode_iteration();
+        }
+
+        // Then
+        assert_eq!(40, reader.block_len());
+        for (llr, bit) in decoding.get_result().iter().zip(source) {
+            assert_eq!(bit, *llr > 0);
+        }
+    }
+
+    struct TailWriter<F: FnMut(usize, EncoderOutput)>(F);
+
+    impl<F: FnMut(usize, EncoderOutput)> TurboEncoderOutputWriter for TailWriter<F> {
+        fn write_output(&mut self, _output: EncoderOutput) {}
+
+        fn write_termination_output(&mut self, encoder_index: usize, output: EncoderOutput) {
+            self.0(encoder_index, output)
+        }
+    }
+}
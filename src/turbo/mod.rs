@@ -1,13 +1,17 @@
 mod code;
 mod decoder;
+pub mod distance;
 mod encoder;
+pub mod lte;
 mod symbol;
+mod writer;
 
 pub use code::TurboCode;
 pub use decoder::trellis_bits;
-pub use decoder::TurboDecoder;
-pub use encoder::{TurboEncoder, TurboEncoderOutputWriter};
+pub use decoder::{SisoTurboDecoder, TurboDecoder};
+pub use encoder::{encoded_bits, TurboEncoder, TurboEncoderOutputWriter};
 pub use symbol::TurboSymbol;
+pub use writer::{BitSliceWriter, LlrWriter, StreamsWriter, VecWriter};
 
 pub mod umts {
     pub use super::decoder::UmtsTurboDecoder;
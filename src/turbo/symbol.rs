@@ -1,5 +1,6 @@
 use crate::Llr;
 
+#[derive(Clone, Copy, Debug)]
 pub struct TurboSymbol {
     pub systematic: Llr,
     pub first_parity: Llr,
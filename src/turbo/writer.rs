@@ -0,0 +1,362 @@
+use core::marker::PhantomData;
+
+use bitvec::prelude::*;
+use heapless::Vec;
+
+use crate::{
+    convolutional::{ConvolutionalCodeExt, EncoderOutput},
+    ratematching::Puncturer,
+    Llr, LlrMul,
+};
+
+use super::{TurboCode, TurboEncoderOutputWriter};
+
+/// Serializes the turbo encoder outputs into a single bit stream with optional puncturing.
+///
+/// The bits of each output are serialized in bit order,
+/// e.g. `x z z'` for a rate 1/3 turbo code and `x z` for each termination output.
+struct Serializer<C: TurboCode> {
+    _code: PhantomData<C>,
+    puncturer: Puncturer,
+    written: usize,
+}
+
+impl<C: TurboCode> Serializer<C> {
+    fn new() -> Self {
+        Self {
+            _code: PhantomData,
+            puncturer: Puncturer::default(),
+            written: 0,
+        }
+    }
+
+    /// The number of bits in each constituent encoder output.
+    fn n() -> usize {
+        C::ConstituentEncoderCode::rate().n as usize
+    }
+
+    fn write(&mut self, output: EncoderOutput, bits: usize, mut push: impl FnMut(usize, bool)) {
+        for index in 0..bits {
+            if self.puncturer.read_output() {
+                push(self.written, output & (1 << index) != 0);
+                self.written += 1;
+            }
+        }
+    }
+
+    fn write_output(&mut self, output: EncoderOutput, push: impl FnMut(usize, bool)) {
+        self.write(output, 2 * Self::n() - 1, push);
+    }
+
+    fn write_termination_output(&mut self, output: EncoderOutput, push: impl FnMut(usize, bool)) {
+        self.write(output, Self::n(), push);
+    }
+}
+
+/// Turbo encoder output writer that packs the serialized output bits into a bit slice.
+///
+/// The output bits are serialized in the order they are written by the encoder,
+/// e.g. `x z z'` for each input bit of a rate 1/3 turbo code followed by the termination bits.
+pub struct BitSliceWriter<'a, C: TurboCode> {
+    serializer: Serializer<C>,
+    output: &'a mut BitSlice<u8, Msb0>,
+}
+
+impl<'a, C: TurboCode> BitSliceWriter<'a, C> {
+    pub fn new(output: &'a mut BitSlice<u8, Msb0>) -> Self {
+        Self {
+            serializer: Serializer::new(),
+            output,
+        }
+    }
+
+    /// Puncture the serialized output bits on the fly.
+    pub fn with_puncturer(mut self, puncturer: Puncturer) -> Self {
+        self.serializer.puncturer = puncturer;
+        self
+    }
+
+    /// Get the total number of written bits.
+    pub fn bit_count(&self) -> usize {
+        self.serializer.written
+    }
+}
+
+impl<C: TurboCode> TurboEncoderOutputWriter for BitSliceWriter<'_, C> {
+    fn write_output(&mut self, output: EncoderOutput) {
+        let target = &mut self.output;
+        self.serializer
+            .write_output(output, |index, bit| target.set(index, bit));
+    }
+
+    fn write_termination_output(&mut self, _encoder_index: usize, output: EncoderOutput) {
+        let target = &mut self.output;
+        self.serializer
+            .write_termination_output(output, |index, bit| target.set(index, bit));
+    }
+}
+
+/// Turbo encoder output writer that produces BPSK llrs for the serialized output bits.
+///
+/// A `1` bit is written as `+magnitude` and a `0` bit as `-magnitude`,
+/// so a magnitude of 1 gives `±1` symbols.
+pub struct LlrWriter<'a, C: TurboCode> {
+    serializer: Serializer<C>,
+    output: &'a mut [Llr],
+    magnitude: Llr,
+}
+
+impl<'a, C: TurboCode> LlrWriter<'a, C> {
+    pub fn new(output: &'a mut [Llr], magnitude: Llr) -> Self {
+        Self {
+            serializer: Serializer::new(),
+            output,
+            magnitude,
+        }
+    }
+
+    /// Puncture the serialized output bits on the fly.
+    pub fn with_puncturer(mut self, puncturer: Puncturer) -> Self {
+        self.serializer.puncturer = puncturer;
+        self
+    }
+
+    /// Get the total number of written llrs.
+    pub fn bit_count(&self) -> usize {
+        self.serializer.written
+    }
+}
+
+impl<C: TurboCode> TurboEncoderOutputWriter for LlrWriter<'_, C> {
+    fn write_output(&mut self, output: EncoderOutput) {
+        let (target, magnitude) = (&mut self.output, self.magnitude);
+        self.serializer
+            .write_output(output, |index, bit| target[index] = bit.mul(magnitude));
+    }
+
+    fn write_termination_output(&mut self, _encoder_index: usize, output: EncoderOutput) {
+        let (target, magnitude) = (&mut self.output, self.magnitude);
+        self.serializer
+            .write_termination_output(output, |index, bit| target[index] = bit.mul(magnitude));
+    }
+}
+
+/// Turbo encoder output writer that collects the serialized output bits in a [`heapless::Vec`].
+pub struct VecWriter<C: TurboCode, const N: usize> {
+    serializer: Serializer<C>,
+    output: Vec<bool, N>,
+}
+
+impl<C: TurboCode, const N: usize> VecWriter<C, N> {
+    pub fn new() -> Self {
+        Self {
+            serializer: Serializer::new(),
+            output: Vec::new(),
+        }
+    }
+
+    /// Puncture the serialized output bits on the fly.
+    pub fn with_puncturer(mut self, puncturer: Puncturer) -> Self {
+        self.serializer.puncturer = puncturer;
+        self
+    }
+
+    /// Get the total number of written bits.
+    pub fn bit_count(&self) -> usize {
+        self.serializer.written
+    }
+
+    /// Get the written bits.
+    pub fn as_slice(&self) -> &[bool] {
+        &self.output
+    }
+
+    /// Take the written bits.
+    pub fn into_inner(self) -> Vec<bool, N> {
+        self.output
+    }
+}
+
+impl<C: TurboCode, const N: usize> Default for VecWriter<C, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: TurboCode, const N: usize> TurboEncoderOutputWriter for VecWriter<C, N> {
+    fn write_output(&mut self, output: EncoderOutput) {
+        let target = &mut self.output;
+        self.serializer
+            .write_output(output, |_, bit| target.push(bit).unwrap());
+    }
+
+    fn write_termination_output(&mut self, _encoder_index: usize, output: EncoderOutput) {
+        let target = &mut self.output;
+        self.serializer
+            .write_termination_output(output, |_, bit| target.push(bit).unwrap());
+    }
+}
+
+/// Turbo encoder output writer that packs the output of a rate 1/3 turbo code into three separate streams.
+///
+/// The streams are the systematic bits `x`, the first parity bits `z` and the second parity bits `z'`.
+/// The termination bits of the first encoder are appended to the systematic and first parity streams,
+/// and the termination bits of the second encoder are appended to the systematic and second parity streams,
+/// so the systematic stream holds `K + 2 mem` bits when both encoders are terminated,
+/// and each parity stream holds `K + mem` bits.
+pub struct StreamsWriter<'a, C: TurboCode> {
+    _code: PhantomData<C>,
+    streams: [&'a mut BitSlice<u8, Msb0>; 3],
+    lengths: [usize; 3],
+}
+
+impl<'a, C: TurboCode> StreamsWriter<'a, C> {
+    pub fn new(
+        systematic: &'a mut BitSlice<u8, Msb0>,
+        first_parity: &'a mut BitSlice<u8, Msb0>,
+        second_parity: &'a mut BitSlice<u8, Msb0>,
+    ) -> Self {
+        assert_eq!(2, C::ConstituentEncoderCode::rate().n);
+        Self {
+            _code: PhantomData,
+            streams: [systematic, first_parity, second_parity],
+            lengths: [0; 3],
+        }
+    }
+
+    /// Get the number of bits written to each of the systematic, first parity and second parity streams.
+    pub fn stream_bit_counts(&self) -> [usize; 3] {
+        self.lengths
+    }
+
+    /// Get the total number of written bits.
+    pub fn bit_count(&self) -> usize {
+        self.lengths.iter().sum()
+    }
+
+    fn write(&mut self, stream: usize, bit: bool) {
+        self.streams[stream].set(self.lengths[stream], bit);
+        self.lengths[stream] += 1;
+    }
+}
+
+impl<C: TurboCode> TurboEncoderOutputWriter for StreamsWriter<'_, C> {
+    fn write_output(&mut self, output: EncoderOutput) {
+        for stream in 0..3 {
+            self.write(stream, output & (1 << stream) != 0);
+        }
+    }
+
+    fn write_termination_output(&mut self, encoder_index: usize, output: EncoderOutput) {
+        self.write(0, output & 1 != 0);
+        self.write(1 + encoder_index, output & 2 != 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        catalog,
+        interleaver::qpp::QppInterleaver,
+        turbo::{encoded_bits, TurboEncoder},
+    };
+
+    use super::*;
+
+    type Code = catalog::UMTS;
+
+    fn encode(writer: &mut impl TurboEncoderOutputWriter) -> Vec<bool, 40> {
+        let source: Vec<bool, 40> = (0..40).map(|i| (i * 3 + i / 7) % 5 < 2).collect();
+        let encoder = TurboEncoder::<Code>::default();
+        let interleaver = QppInterleaver::new(40, 3, 10);
+        encoder.encode(&source[..], &interleaver, writer);
+        source
+    }
+
+    #[test]
+    fn can_write_bit_slice() {
+        // Given
+        let mut vec_writer = VecWriter::<Code, 132>::new();
+        encode(&mut vec_writer);
+        let mut output = [0u8; 17];
+
+        // When
+        let mut writer = BitSliceWriter::<Code>::new(output.view_bits_mut());
+        encode(&mut writer);
+
+        // Then
+        assert_eq!(132, encoded_bits::<Code>(40));
+        assert_eq!(132, writer.bit_count());
+        assert_eq!(132, vec_writer.bit_count());
+        let output: Vec<bool, 132> = output.view_bits::<Msb0>()[..132]
+            .iter()
+            .map(|bit| *bit)
+            .collect();
+        assert_eq!(vec_writer.as_slice(), &output[..]);
+    }
+
+    #[test]
+    fn can_write_llrs() {
+        // Given
+        let mut vec_writer = VecWriter::<Code, 132>::new();
+        encode(&mut vec_writer);
+        let mut output = [0; 132];
+
+        // When
+        let mut writer = LlrWriter::<Code>::new(&mut output, 1);
+        encode(&mut writer);
+
+        // Then
+        assert_eq!(132, writer.bit_count());
+        for (llr, bit) in output.iter().zip(vec_writer.as_slice()) {
+            assert_eq!(bit.mul(1), *llr);
+        }
+    }
+
+    #[test]
+    fn can_puncture() {
+        // Given
+        let mut vec_writer = VecWriter::<Code, 132>::new();
+        encode(&mut vec_writer);
+        let mut writer = VecWriter::<Code, 88>::new().with_puncturer(Puncturer::new(3, 0b010));
+
+        // When
+        encode(&mut writer);
+
+        // Then
+        assert_eq!(88, writer.bit_count());
+        let expected: Vec<bool, 88> = vec_writer
+            .as_slice()
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| index % 3 != 1)
+            .map(|(_, bit)| *bit)
+            .collect();
+        assert_eq!(expected, writer.into_inner());
+    }
+
+    #[test]
+    fn can_write_streams() {
+        // Given
+        let mut systematic = [0u8; 6];
+        let mut first_parity = [0u8; 6];
+        let mut second_parity = [0u8; 6];
+        let mut writer = StreamsWriter::<Code>::new(
+            systematic.view_bits_mut(),
+            first_parity.view_bits_mut(),
+            second_parity.view_bits_mut(),
+        );
+
+        // When
+        let source = encode(&mut writer);
+
+        // Then
+        assert_eq!([46, 43, 43], writer.stream_bit_counts());
+        assert_eq!(132, writer.bit_count());
+        let systematic: Vec<bool, 40> = systematic.view_bits::<Msb0>()[..40]
+            .iter()
+            .map(|bit| *bit)
+            .collect();
+        assert_eq!(source, systematic);
+    }
+}